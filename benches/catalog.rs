@@ -0,0 +1,88 @@
+//! Benchmarks for the resolution of symbol names.
+//!
+//! Run with `cargo bench --bench catalog`. This reports the average time per
+//! name to resolve all the names of the catalog, both in their canonical form
+//! and with their modifiers in reverse order, as well as the time to resolve
+//! them by walking the modules manually.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use codex::catalog::{iter, resolve};
+use codex::{Def, ModifierSet, ROOT};
+
+/// Runs `f` on each of `inputs` repeatedly for roughly `budget`, and returns
+/// the average time per input.
+fn bench<T>(inputs: &[T], budget: Duration, mut f: impl FnMut(&T)) -> Duration {
+    let mut iters = 0;
+    let start = Instant::now();
+    while start.elapsed() < budget {
+        for input in inputs {
+            f(black_box(input));
+        }
+        iters += inputs.len();
+    }
+    start.elapsed() / iters as u32
+}
+
+/// Resolves a full name by walking the modules from [`ROOT`].
+fn walk(name: &str) -> Option<&'static str> {
+    let mut module = ROOT;
+    let mut rest = name;
+    loop {
+        let (head, tail) = rest.split_once('.').unwrap_or((rest, ""));
+        match module.get(head)?.def {
+            Def::Module(inner) => module = inner,
+            Def::Symbol(symbol) => {
+                return symbol.get(ModifierSet::from_raw_dotted(tail)).map(|(v, _)| v);
+            }
+        }
+        rest = tail;
+    }
+}
+
+/// Returns the number of parts of a full name that name its symbol.
+fn symbol_parts(name: &str) -> usize {
+    let mut module = ROOT;
+    for (i, part) in name.split('.').enumerate() {
+        match module.get(part).unwrap().def {
+            Def::Module(inner) => module = inner,
+            Def::Symbol(_) => return i + 1,
+        }
+    }
+    unreachable!("{name} is not the name of a variant")
+}
+
+fn main() {
+    let budget = Duration::from_millis(
+        std::env::var("CODEX_BENCH_MILLIS")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(50),
+    );
+
+    let canonical: Vec<&str> = iter().map(|(name, _)| name).collect();
+    // Reversing the modifiers prevents looking the names up directly.
+    let reordered: Vec<String> = canonical
+        .iter()
+        .map(|name| {
+            let mut parts: Vec<&str> = name.split('.').collect();
+            parts[symbol_parts(name)..].reverse();
+            parts.join(".")
+        })
+        .collect();
+
+    println!("{:<24} {:>14}", "names", "time/name");
+    let canonical_time = bench(&canonical, budget, |name| {
+        black_box(resolve(name).unwrap());
+    });
+    println!("{:<24} {:>14.1?}", "canonical", canonical_time);
+    let reordered_time = bench(&reordered, budget, |name| {
+        black_box(resolve(name).unwrap());
+    });
+    println!("{:<24} {:>14.1?}", "reordered modifiers", reordered_time);
+    let walk_time = bench(&canonical, budget, |name| {
+        black_box(walk(name).unwrap());
+    });
+    println!("{:<24} {:>14.1?}", "walking the modules", walk_time);
+}
@@ -0,0 +1,138 @@
+//! Benchmarks for the numeral systems.
+//!
+//! Run with `cargo bench --bench numeral_systems`. For each named numeral
+//! system and a few magnitudes of numbers, this reports the average time and
+//! the number of allocations per represented number, and checks the
+//! allocation guarantees documented on [`NumeralSystem::represent`].
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fmt::Write;
+use std::hint::black_box;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+use codex::numeral_systems::{NamedNumeralSystem, NumeralSystem};
+
+/// An allocator that counts allocations.
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// The measurements of a benchmark.
+struct Measurement {
+    time_per_iter: Duration,
+    allocations_per_iter: f64,
+}
+
+/// Runs `f` on each of `inputs` repeatedly for roughly `budget`.
+fn bench<T: Clone>(inputs: &[T], budget: Duration, mut f: impl FnMut(T)) -> Measurement {
+    let mut iters = 0;
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let start = Instant::now();
+    while start.elapsed() < budget {
+        for input in inputs {
+            f(black_box(input.clone()));
+        }
+        iters += inputs.len();
+    }
+    let elapsed = start.elapsed();
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed) - allocations;
+    Measurement {
+        time_per_iter: elapsed / iters as u32,
+        allocations_per_iter: allocations as f64 / iters as f64,
+    }
+}
+
+/// The magnitudes of numbers that are benchmarked.
+const MAGNITUDES: [(&str, std::ops::Range<u64>); 4] = [
+    ("1-9", 1..10),
+    ("10-99", 10..100),
+    ("1000-1099", 1000..1100),
+    ("10^12", 1_000_000_000_000..1_000_000_000_100),
+];
+
+fn main() {
+    let budget = Duration::from_millis(
+        std::env::var("CODEX_BENCH_MILLIS")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(50),
+    );
+    let mut failed = false;
+
+    println!(
+        "{:<24} {:<12} {:>14} {:>14} {:>14}",
+        "system", "numbers", "represent", "sequence", "allocs/call"
+    );
+    for named in NamedNumeralSystem::iter() {
+        let system = named.system();
+        for (label, range) in MAGNITUDES {
+            let numbers: Vec<u64> = range.clone().collect();
+            if numbers.iter().any(|&n| !is_cheap(system, n)) {
+                continue;
+            }
+
+            // Representing a number into an existing buffer must not allocate
+            // once the buffer is large enough.
+            let mut buf = String::with_capacity(4096);
+            let represent = bench(&numbers, budget, |n| {
+                buf.clear();
+                if let Ok(r) = system.represent(n) {
+                    write!(buf, "{r}").unwrap();
+                }
+                black_box(&buf);
+            });
+            if represent.allocations_per_iter > 0.0 {
+                eprintln!("`{}` allocates when representing {label}", named.name());
+                failed = true;
+            }
+
+            let sequence = bench(&[range], budget, |range| {
+                for r in system.sequence(range) {
+                    black_box(r.ok());
+                }
+            });
+
+            println!(
+                "{:<24} {:<12} {:>11.1?}/n {:>11.1?}/n {:>14.2}",
+                named.name(),
+                label,
+                represent.time_per_iter,
+                sequence.time_per_iter / numbers.len() as u32,
+                represent.allocations_per_iter,
+            );
+        }
+    }
+
+    if failed {
+        std::process::exit(1);
+    }
+}
+
+/// Whether representing `n` produces a reasonably short output, so that
+/// additive systems are not benchmarked on huge numbers.
+fn is_cheap(system: NumeralSystem, n: u64) -> bool {
+    match system {
+        NumeralSystem::Additive(numerals) => n / numerals[0].1 <= 100,
+        _ => true,
+    }
+}
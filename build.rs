@@ -1,4 +1,6 @@
 use self::shared::ModifierSet;
+use std::cell::Cell;
+use std::collections::{HashMap, HashSet};
 use std::fmt::Write;
 use std::iter;
 use std::iter::Peekable;
@@ -6,6 +8,23 @@ use std::path::Path;
 
 type StrResult<T> = Result<T, String>;
 
+/// The character standing for the skin tone of an emoji in a value, which is
+/// written `\tone` in the symbol definitions.
+const SKIN_TONE: char = '\u{E000}';
+
+/// The skin tones of emojis, with the modifiers that select them.
+const SKIN_TONES: [(&str, char); 5] = [
+    ("light", '\u{1F3FB}'),
+    ("mediumlight", '\u{1F3FC}'),
+    ("medium", '\u{1F3FD}'),
+    ("mediumdark", '\u{1F3FE}'),
+    ("dark", '\u{1F3FF}'),
+];
+
+/// The features that gate sections of the modules, which can be excluded to
+/// reduce the size of the catalog.
+const SECTION_FEATURES: [&str; 2] = ["emoji", "math-alphabets"];
+
 #[path = "src/shared.rs"]
 mod shared;
 
@@ -23,6 +42,7 @@ impl<'a> Module<'a> {
 struct Binding<'a> {
     def: Def<'a>,
     deprecation: Option<&'a str>,
+    desc: Option<&'a str>,
 }
 
 /// A definition in a module.
@@ -32,42 +52,126 @@ enum Def<'a> {
 }
 
 /// A symbol, either a leaf or with modifiers with optional deprecation.
+#[derive(Clone)]
 enum Symbol<'a> {
     Single(String),
-    Multi(Vec<(ModifierSet<&'a str>, String, Option<&'a str>)>),
+    Multi(Vec<Variant<'a>>),
 }
 
+/// A variant of a symbol, with its modifiers, value, and deprecation.
+type Variant<'a> = (ModifierSet<&'a str>, String, Option<&'a str>);
+
 /// A single line during parsing.
 #[derive(Debug, Clone)]
 enum Line<'a> {
     Blank,
+    Comment(&'a str),
     Deprecated(&'a str),
+    Alias(&'a str),
+    Feature(&'a str),
     ModuleStart(&'a str),
     ModuleEnd,
-    Symbol(&'a str, Option<String>),
-    Variant(ModifierSet<&'a str>, String),
+    Symbol(&'a str, Option<String>, Option<&'a str>),
+    Variant(ModifierSet<&'a str>, Option<String>),
     Eof,
 }
 
 #[derive(Debug, Clone)]
 enum Declaration<'a> {
-    ModuleStart(&'a str, Option<&'a str>),
+    ModuleStart(&'a str, Option<&'a str>, Option<&'a str>),
     ModuleEnd,
-    Symbol(&'a str, Option<String>, Option<&'a str>),
-    Variant(ModifierSet<&'a str>, String, Option<&'a str>),
+    Symbol(&'a str, Option<Value<'a>>, Option<&'a str>, Option<&'a str>),
+    Variant(ModifierSet<&'a str>, Value<'a>, Option<&'a str>),
+}
+
+/// The value of a declaration.
+#[derive(Debug, Clone)]
+enum Value<'a> {
+    /// The value is given directly.
+    Literal(String),
+    /// The value is that of another symbol or variant, given by its name.
+    Alias(&'a str),
+}
+
+/// A symbol or variant whose value is that of another one.
+struct Alias<'a> {
+    /// The names of the modules containing the symbol, and that of the symbol.
+    path: Vec<&'a str>,
+    /// The modifiers of the variant, or `None` if the whole symbol is an
+    /// alias.
+    modifiers: Option<ModifierSet<&'a str>>,
+    /// The name of the target, relative to the global module.
+    target: &'a str,
 }
 
 fn main() {
     println!("cargo::rerun-if-changed=build.rs");
 
     let mut buf = String::new();
-    process(&mut buf, Path::new("src/modules/sym.txt"), "SYM", "Named general symbols.");
-    process(&mut buf, Path::new("src/modules/emoji.txt"), "EMOJI", "Named emoji.");
+    let mut entries = vec![];
+    let mut aliases = vec![];
+    let sym = Path::new("src/modules/sym.txt");
+    process(
+        &mut buf,
+        &mut entries,
+        &mut aliases,
+        sym,
+        "sym",
+        "SYM",
+        "Named general symbols.",
+    );
+    if is_enabled("emoji") {
+        let emoji = Path::new("src/modules/emoji.txt");
+        process(
+            &mut buf,
+            &mut entries,
+            &mut aliases,
+            emoji,
+            "emoji",
+            "EMOJI",
+            "Named emoji.",
+        );
+    }
 
     let out = std::env::var_os("OUT_DIR").unwrap();
     let dest = Path::new(&out).join("out.rs");
     std::fs::write(&dest, buf).unwrap();
 
+    let mut buf = String::new();
+    entries.sort_by(|e1, e2| e1.name.cmp(&e2.name));
+    buf.push_str("static NAMES: &[(&str, &str, Option<&str>)] = &[");
+    for Entry { name, value, desc } in &entries {
+        write!(buf, "({name:?}, {value:?}, {desc:?}),").unwrap();
+    }
+    buf.push_str("];");
+    process_latex(&mut buf, Path::new("src/data/latex.txt"), &entries);
+    process_html(&mut buf, Path::new("src/data/html.txt"), &entries);
+    process_hints(&mut buf, Path::new("src/data/hints.txt"), &entries);
+    process_confusables(&mut buf, Path::new("src/data/confusables.txt"), &entries);
+    process_accents(&mut buf, Path::new("src/data/accents.txt"), &entries);
+    // The reverse index holds the indices of the entries in the table of
+    // names, sorted by value, and then with shorter names first.
+    assert!(entries.len() <= usize::from(u16::MAX));
+    let mut order: Vec<u16> = (0..entries.len() as u16).collect();
+    order.sort_by(|&i, &j| {
+        let (e1, e2) = (&entries[usize::from(i)], &entries[usize::from(j)]);
+        (e1.value.cmp(&e2.value))
+            .then(e1.name.len().cmp(&e2.name.len()))
+            .then(e1.name.cmp(&e2.name))
+    });
+    write!(buf, "static VALUES: &[u16] = &{order:?};").unwrap();
+    process_decompositions(
+        &mut buf,
+        Path::new("src/data/decompositions.txt"),
+        &entries,
+        &order,
+    );
+    process_cases(&mut buf, &entries);
+    aliases.sort();
+    write!(buf, "static ALIASES: &[(&str, &str)] = &{aliases:?};").unwrap();
+    process_unicode(&mut buf, Path::new("src/data/unicode.txt"), &entries);
+    std::fs::write(Path::new(&out).join("catalog.rs"), buf).unwrap();
+
     #[cfg(feature = "_test-unicode-conformance")]
     {
         let emoji_vs_list = Path::new(&out).join("emoji-variation-sequences.txt");
@@ -84,24 +188,91 @@ fn main() {
                 .unwrap();
             std::fs::write(emoji_vs_list, content).unwrap();
         }
+
+        let unicode_data = Path::new(&out).join("UnicodeData-17.0.0.txt");
+        if !unicode_data.exists() {
+            let content =
+                ureq::get("https://www.unicode.org/Public/17.0.0/ucd/UnicodeData.txt")
+                    .call()
+                    .unwrap()
+                    .body_mut()
+                    .read_to_string()
+                    .unwrap();
+            std::fs::write(unicode_data, content).unwrap();
+        }
+
+        let math_class = Path::new(&out).join("MathClass-15.txt");
+        if !math_class.exists() {
+            let content = ureq::get(
+                "https://www.unicode.org/Public/math/revision-15/MathClass-15.txt",
+            )
+            .call()
+            .unwrap()
+            .body_mut()
+            .read_to_string()
+            .unwrap();
+            std::fs::write(math_class, content).unwrap();
+        }
     }
 }
 
+/// A non-deprecated symbol variant in the catalog.
+struct Entry {
+    name: String,
+    value: String,
+    desc: Option<String>,
+}
+
 /// Processes a single file and turns it into a global module.
-fn process(buf: &mut String, file: &Path, name: &str, desc: &str) {
+///
+/// The non-deprecated variants of the module are added to `entries`, and its
+/// aliases are added to `aliases` alongside their targets.
+fn process(
+    buf: &mut String,
+    entries: &mut Vec<Entry>,
+    aliases: &mut Vec<(String, String)>,
+    file: &Path,
+    path: &str,
+    name: &str,
+    desc: &str,
+) {
     println!("cargo::rerun-if-changed={}", file.display());
 
     let text = std::fs::read_to_string(file).unwrap();
     let mut line_nr = 0;
     let mut deprecation = None;
+    let mut alias = None;
+    let mut feature = None;
+    // Whether the variants being parsed belong to a symbol excluded by its
+    // feature.
+    let excluded = Cell::new(false);
+    // The first line of the last block of comments, which serves as the
+    // description of the following definitions.
+    let mut heading = None;
+    let mut in_comment = false;
     let mut iter = text
         .lines()
         .inspect(|_| line_nr += 1)
         .map(tokenize)
         .chain(iter::once(Ok(Line::Eof)))
+        .inspect(|line| {
+            if let Ok(Line::Symbol(..) | Line::ModuleStart(_) | Line::ModuleEnd) = line {
+                excluded.set(false);
+            }
+        })
         .filter_map(|line| match line {
             Err(message) => Some(Err(message)),
-            Ok(Line::Blank) => None,
+            Ok(Line::Comment(comment)) => {
+                if !in_comment {
+                    heading = Some(comment);
+                }
+                in_comment = true;
+                None
+            }
+            Ok(Line::Blank) => {
+                in_comment = false;
+                None
+            }
             Ok(Line::Deprecated(message)) => {
                 if deprecation.is_some() {
                     Some(Err(String::from("duplicate `@deprecated:`")))
@@ -110,29 +281,93 @@ fn process(buf: &mut String, file: &Path, name: &str, desc: &str) {
                     None
                 }
             }
+            Ok(Line::Alias(target)) => {
+                if alias.is_some() {
+                    Some(Err(String::from("duplicate `@alias:`")))
+                } else {
+                    alias = Some(target);
+                    None
+                }
+            }
+            Ok(Line::Feature(name)) => {
+                if feature.is_some() {
+                    Some(Err(String::from("duplicate `@feature:`")))
+                } else {
+                    feature = Some(name);
+                    None
+                }
+            }
+            Ok(
+                Line::Variant(..) | Line::ModuleStart(_) | Line::ModuleEnd | Line::Eof,
+            ) if feature.is_some() => {
+                Some(Err(String::from("only symbols can be gated by a feature")))
+            }
+            Ok(Line::Variant(..)) if excluded.get() => {
+                deprecation = None;
+                alias = None;
+                None
+            }
+            Ok(Line::Symbol(..)) if feature.take().is_some_and(|f| !is_enabled(f)) => {
+                excluded.set(true);
+                deprecation = None;
+                alias = None;
+                None
+            }
+            Ok(Line::ModuleStart(_)) if alias.is_some() => {
+                Some(Err(String::from("modules cannot be aliases")))
+            }
             Ok(Line::ModuleStart(name)) => {
-                Some(Ok(Declaration::ModuleStart(name, deprecation.take())))
+                Some(Ok(Declaration::ModuleStart(name, deprecation.take(), heading)))
             }
             Ok(Line::ModuleEnd) => {
                 if deprecation.is_some() {
                     Some(Err(String::from("dangling `@deprecated:`")))
+                } else if alias.is_some() {
+                    Some(Err(String::from("dangling `@alias:`")))
                 } else {
                     Some(Ok(Declaration::ModuleEnd))
                 }
             }
-            Ok(Line::Symbol(name, value)) => {
-                Some(Ok(Declaration::Symbol(name, value, deprecation.take())))
+            Ok(Line::Symbol(name, value, comment)) => {
+                let value = match (value, alias.take()) {
+                    (Some(_), Some(_)) => {
+                        return Some(Err(String::from("aliases cannot have a value")));
+                    }
+                    (value, target) => {
+                        value.map(Value::Literal).or(target.map(Value::Alias))
+                    }
+                };
+                let deprecation = deprecation.take();
+                Some(Ok(Declaration::Symbol(
+                    name,
+                    value,
+                    deprecation,
+                    comment.or(heading),
+                )))
             }
             Ok(Line::Variant(modifiers, value)) => {
+                let value = match (value, alias.take()) {
+                    (Some(_), Some(_)) => {
+                        return Some(Err(String::from("aliases cannot have a value")));
+                    }
+                    (Some(value), None) => Value::Literal(value),
+                    (None, Some(target)) => Value::Alias(target),
+                    (None, None) => return Some(Err(String::from("missing char"))),
+                };
                 Some(Ok(Declaration::Variant(modifiers, value, deprecation.take())))
             }
             Ok(Line::Eof) => {
-                deprecation.map(|_| Err(String::from("dangling `@deprecated:`")))
+                if deprecation.is_some() {
+                    Some(Err(String::from("dangling `@deprecated:`")))
+                } else {
+                    alias.map(|_| Err(String::from("dangling `@alias:`")))
+                }
             }
         })
         .peekable();
 
-    let module = match parse(&mut iter) {
+    let mut unresolved = vec![];
+    let mut module = match parse(&mut iter, &[], &mut unresolved) {
         Ok(defs) => Module::new(defs),
         Err(e) => {
             let message = format!("{}:{}: {e}", file.display(), line_nr);
@@ -140,16 +375,755 @@ fn process(buf: &mut String, file: &Path, name: &str, desc: &str) {
             std::process::exit(1);
         }
     };
+    for alias in unresolved {
+        match resolve_alias(&mut module, &alias) {
+            Ok(target) => {
+                let name = alias_name(&alias);
+                aliases.push((format!("{path}.{name}"), format!("{path}.{target}")));
+            }
+            Err(e) => {
+                let name = alias_name(&alias);
+                println!("cargo::warning={}: alias `{name}`: {e}", file.display());
+                std::process::exit(1);
+            }
+        }
+    }
 
     write!(buf, "#[doc = {desc:?}] pub const {name}: Module = ").unwrap();
     encode(buf, &module);
     buf.push(';');
+    collect(entries, &module, path);
+}
+
+/// Returns the name of an alias, relative to its global module.
+fn alias_name(alias: &Alias) -> String {
+    let mut name = alias.path.join(".");
+    if let Some(modifiers) = alias.modifiers.filter(|m| !m.is_empty()) {
+        name.push('.');
+        name.push_str(modifiers.as_str());
+    }
+    name
+}
+
+/// Gives an alias the value of its target and deprecates it, unless it is
+/// already deprecated.
+///
+/// Returns the canonical name of the target, relative to the global module.
+fn resolve_alias(module: &mut Module, alias: &Alias) -> StrResult<String> {
+    // Find the target.
+    let mut current = &*module;
+    let mut symbol_path = vec![];
+    let mut rest = alias.target.split('.');
+    let symbol = loop {
+        let name = rest.next().ok_or("target is a module")?;
+        symbol_path.push(name);
+        let binding = (current.0.iter())
+            .find(|(n, _)| *n == name)
+            .map(|(_, binding)| binding)
+            .ok_or("unknown target")?;
+        if binding.deprecation.is_some() {
+            return Err("target is deprecated".into());
+        }
+        match &binding.def {
+            Def::Module(module) => current = module,
+            Def::Symbol(symbol) => break symbol,
+        }
+    };
+    let modifiers: Vec<&str> = rest.collect();
+    let variant = match symbol {
+        Symbol::Single(value) if modifiers.is_empty() => Some((value, None, "")),
+        Symbol::Single(_) => None,
+        Symbol::Multi(list) => list
+            .iter()
+            .find(|(set, _, _)| {
+                set.iter().count() == modifiers.len()
+                    && modifiers.iter().all(|m| set.contains(m))
+            })
+            .map(|(set, value, deprecation)| (value, *deprecation, set.as_str())),
+    };
+    let (value, deprecation, canonical) = variant.ok_or("unknown target")?;
+    if deprecation.is_some() {
+        return Err("target is deprecated".into());
+    }
+    let mut target = symbol_path.join(".");
+    if !canonical.is_empty() {
+        target.push('.');
+        target.push_str(canonical);
+    }
+    let def = match alias.modifiers {
+        None if modifiers.is_empty() => Def::Symbol(symbol.clone()),
+        _ => Def::Symbol(Symbol::Single(value.clone())),
+    };
+    let value = value.clone();
+
+    // Give the alias the value of its target.
+    let message: &'static str = String::leak(format!(
+        "`{}` is deprecated, use `{}` instead",
+        alias_name(alias),
+        alias.target,
+    ));
+    let (last, modules) = alias.path.split_last().unwrap();
+    let mut current = module;
+    for name in modules {
+        let Some((_, Binding { def: Def::Module(module), .. })) =
+            current.0.iter_mut().find(|(n, _)| n == name)
+        else {
+            unreachable!()
+        };
+        current = module;
+    }
+    let (_, binding) = current.0.iter_mut().find(|(n, _)| n == last).unwrap();
+    match (alias.modifiers, &mut binding.def) {
+        (None, _) => {
+            binding.def = def;
+            binding.deprecation.get_or_insert(message);
+        }
+        (Some(modifiers), Def::Symbol(Symbol::Multi(list))) => {
+            let (_, variant_value, deprecation) = list
+                .iter_mut()
+                .find(|(set, _, _)| set.as_str() == modifiers.as_str())
+                .unwrap();
+            *variant_value = value;
+            deprecation.get_or_insert(message);
+        }
+        _ => unreachable!(),
+    }
+    Ok(target)
+}
+
+/// Processes the LaTeX commands of symbol variants and turns them into tables
+/// sorted by name and by command.
+///
+/// `entries` must be sorted by name.
+fn process_latex(buf: &mut String, file: &Path, entries: &[Entry]) {
+    println!("cargo::rerun-if-changed={}", file.display());
+
+    let text = std::fs::read_to_string(file).unwrap();
+    let mut names = vec![];
+    let mut commands = vec![];
+    for (i, line) in text.lines().enumerate() {
+        if line.is_empty() || line.starts_with("//") {
+            continue;
+        }
+        let fail = |message: String| {
+            println!("cargo::warning={}:{}: {message}", file.display(), i + 1);
+            std::process::exit(1);
+        };
+        let mut parts = line.split(' ');
+        let name = parts.next().unwrap();
+        if entries
+            .binary_search_by(|entry| entry.name.as_str().cmp(name))
+            .is_err()
+        {
+            if is_complete() {
+                fail(format!("unknown or non-canonical name: {name}"));
+            }
+            continue;
+        }
+        let mut first = true;
+        for command in parts {
+            if !command.starts_with('\\') || command.len() < 2 {
+                fail(format!("invalid command: {command}"));
+            }
+            if first {
+                names.push((name, command));
+                first = false;
+            }
+            commands.push((command, name));
+        }
+        if first {
+            fail(format!("missing command for {name}"));
+        }
+    }
+    names.sort();
+    commands.sort();
+    if let Some(pair) = names.windows(2).find(|pair| pair[0].0 == pair[1].0) {
+        println!("cargo::warning={}: duplicate name: {}", file.display(), pair[0].0);
+        std::process::exit(1);
+    }
+    if let Some(pair) = commands.windows(2).find(|pair| pair[0].0 == pair[1].0) {
+        println!("cargo::warning={}: duplicate command: {}", file.display(), pair[0].0);
+        std::process::exit(1);
+    }
+    write!(
+        buf,
+        "#[cfg(feature = \"latex\")] static LATEX: &[(&str, &str)] = &{names:?};"
+    )
+    .unwrap();
+    write!(
+        buf,
+        "#[cfg(feature = \"latex\")] static LATEX_COMMANDS: &[(&str, &str)] = &{commands:?};"
+    )
+    .unwrap();
+}
+
+/// Processes the rendering hints of the symbols and turns them into a table
+/// sorted by name.
+///
+/// `entries` must be sorted by name.
+fn process_hints(buf: &mut String, file: &Path, entries: &[Entry]) {
+    println!("cargo::rerun-if-changed={}", file.display());
+
+    let text = std::fs::read_to_string(file).unwrap();
+    let mut hints = vec![];
+    for (i, line) in text.lines().enumerate() {
+        if line.is_empty() || line.starts_with("//") {
+            continue;
+        }
+        let fail = |message: String| -> ! {
+            println!("cargo::warning={}:{}: {message}", file.display(), i + 1);
+            std::process::exit(1);
+        };
+        let mut parts = line.split(' ');
+        let name = parts.next().unwrap();
+        let mode = match parts.next() {
+            Some("math") => "Mode::Math",
+            Some("text") => "Mode::Text",
+            Some("both") => "Mode::Both",
+            Some(mode) => fail(format!("invalid mode: {mode}")),
+            None => fail(format!("missing mode for {name}")),
+        };
+        let features: Vec<&str> = parts.collect();
+        if let Some(feature) = features
+            .iter()
+            .find(|f| f.len() != 4 || !f.chars().all(|c| c.is_ascii_alphanumeric()))
+        {
+            fail(format!("invalid feature: {feature}"));
+        }
+        if entries
+            .binary_search_by(|entry| entry.name.as_str().cmp(name))
+            .is_err()
+        {
+            if is_complete() {
+                fail(format!("unknown or non-canonical name: {name}"));
+            }
+            continue;
+        }
+        hints.push((name, mode, features));
+    }
+    hints.sort();
+    if let Some(pair) = hints.windows(2).find(|pair| pair[0].0 == pair[1].0) {
+        println!("cargo::warning={}: duplicate name: {}", file.display(), pair[0].0);
+        std::process::exit(1);
+    }
+    buf.push_str("static HINTS: &[(&str, Mode, &[&str])] = &[");
+    for (name, mode, features) in hints {
+        write!(buf, "({name:?}, {mode}, &{features:?}),").unwrap();
+    }
+    buf.push_str("];");
+}
+
+/// Processes the HTML named character references of values and turns them
+/// into tables sorted by value and by reference.
+fn process_html(buf: &mut String, file: &Path, entries: &[Entry]) {
+    println!("cargo::rerun-if-changed={}", file.display());
+
+    let values: HashSet<&str> = entries
+        .iter()
+        .map(|entry| entry.value.trim_end_matches(['\u{FE0E}', '\u{FE0F}']))
+        .collect();
+    let text = std::fs::read_to_string(file).unwrap();
+    let mut by_value = vec![];
+    let mut by_entity = vec![];
+    for (i, line) in text.lines().enumerate() {
+        if line.is_empty() || line.starts_with("//") {
+            continue;
+        }
+        let fail = |message: String| -> ! {
+            println!("cargo::warning={}:{}: {message}", file.display(), i + 1);
+            std::process::exit(1);
+        };
+        let Some((codepoints, entities)) = line.split_once(';') else {
+            fail(String::from("missing field"));
+        };
+        let value = codepoints
+            .split(' ')
+            .map(|code| {
+                u32::from_str_radix(code, 16)
+                    .ok()
+                    .and_then(|n| char::try_from(n).ok())
+                    .unwrap_or_else(|| fail(format!("invalid codepoint: {code}")))
+            })
+            .collect::<String>();
+        if !values.contains(value.as_str()) {
+            if is_complete() {
+                fail(format!("no symbol has the value {value:?}"));
+            }
+            continue;
+        }
+        for (j, entity) in entities.split(' ').enumerate() {
+            if entity.is_empty() || !entity.chars().all(|c| c.is_ascii_alphanumeric()) {
+                fail(format!("invalid entity: {entity}"));
+            }
+            let entity = format!("&{entity};");
+            if j == 0 {
+                by_value.push((value.clone(), entity.clone()));
+            }
+            by_entity.push((entity, value.clone()));
+        }
+    }
+    by_value.sort();
+    by_entity.sort();
+    if let Some(pair) = by_value.windows(2).find(|pair| pair[0].0 == pair[1].0) {
+        println!("cargo::warning={}: duplicate value: {:?}", file.display(), pair[0].0);
+        std::process::exit(1);
+    }
+    if let Some(pair) = by_entity.windows(2).find(|pair| pair[0].0 == pair[1].0) {
+        println!("cargo::warning={}: duplicate entity: {}", file.display(), pair[0].0);
+        std::process::exit(1);
+    }
+    write!(buf, "static HTML_ENTITIES: &[(&str, &str)] = &{by_value:?};").unwrap();
+    write!(buf, "static HTML_VALUES: &[(&str, &str)] = &{by_entity:?};").unwrap();
+}
+
+/// Processes the confusable characters, whose sources must be ASCII or used by
+/// the symbols.
+fn process_confusables(buf: &mut String, file: &Path, entries: &[Entry]) {
+    println!("cargo::rerun-if-changed={}", file.display());
+
+    let used: HashSet<char> =
+        entries.iter().flat_map(|entry| entry.value.chars()).collect();
+    let text = std::fs::read_to_string(file).unwrap();
+    let mut mappings = vec![];
+    for (i, line) in text.lines().enumerate() {
+        if line.is_empty() || line.starts_with("//") {
+            continue;
+        }
+        let fail = |message: String| -> ! {
+            println!("cargo::warning={}:{}: {message}", file.display(), i + 1);
+            std::process::exit(1);
+        };
+        let parse = |code: &str| {
+            u32::from_str_radix(code, 16)
+                .ok()
+                .and_then(|n| char::try_from(n).ok())
+                .unwrap_or_else(|| fail(format!("invalid codepoint: {code}")))
+        };
+        let Some((source, skeleton)) = line.split_once(';') else {
+            fail(String::from("missing field"));
+        };
+        let source = parse(source);
+        if !source.is_ascii() && !used.contains(&source) {
+            if is_complete() {
+                fail(format!("no symbol uses the character {source:?}"));
+            }
+            continue;
+        }
+        let skeleton = skeleton.split(' ').map(parse).collect::<String>();
+        mappings.push((source, skeleton));
+    }
+    mappings.sort();
+    if let Some(pair) = mappings.windows(2).find(|pair| pair[0].0 == pair[1].0) {
+        println!(
+            "cargo::warning={}: duplicate character: {:?}",
+            file.display(),
+            pair[0].0
+        );
+        std::process::exit(1);
+    }
+    for (source, skeleton) in &mappings {
+        if let Some(c) = skeleton.chars().find(|c| mappings.iter().any(|(s, _)| s == c)) {
+            println!(
+                "cargo::warning={}: the skeleton of {source:?} contains the confusable {c:?}",
+                file.display()
+            );
+            std::process::exit(1);
+        }
+    }
+    write!(
+        buf,
+        "#[cfg(feature = \"unicode-data\")] static CONFUSABLES: &[(char, &str)] = &{mappings:?};"
+    )
+    .unwrap();
+}
+
+/// Processes the combining marks of accents and turns them into tables sorted
+/// by accent and by combining mark.
+fn process_accents(buf: &mut String, file: &Path, entries: &[Entry]) {
+    println!("cargo::rerun-if-changed={}", file.display());
+
+    let used: HashSet<char> =
+        entries.iter().flat_map(|entry| entry.value.chars()).collect();
+    let text = std::fs::read_to_string(file).unwrap();
+    let mut accents: Vec<(char, char)> = vec![];
+    let mut combining: Vec<(char, char)> = vec![];
+    for (i, line) in text.lines().enumerate() {
+        if line.is_empty() || line.starts_with("//") {
+            continue;
+        }
+        let fail = |message: String| -> ! {
+            println!("cargo::warning={}:{}: {message}", file.display(), i + 1);
+            std::process::exit(1);
+        };
+        let parse = |code: &str| {
+            u32::from_str_radix(code, 16)
+                .ok()
+                .and_then(|n| char::try_from(n).ok())
+                .unwrap_or_else(|| fail(format!("invalid codepoint: {code}")))
+        };
+        let Some((accent, mark)) = line.split_once(';') else {
+            fail(String::from("missing field"));
+        };
+        let (accent, mark) = (parse(accent), parse(mark));
+        if !used.contains(&accent) {
+            if is_complete() {
+                fail(format!("no symbol uses the character {accent:?}"));
+            }
+            continue;
+        }
+        if accents.iter().any(|&(a, _)| a == accent) {
+            fail(format!("duplicate accent: {accent:?}"));
+        }
+        accents.push((accent, mark));
+        if !combining.iter().any(|&(m, _)| m == mark) {
+            combining.push((mark, accent));
+        }
+    }
+    accents.sort();
+    combining.sort();
+    write!(buf, "static ACCENTS: &[(char, char)] = &{accents:?};").unwrap();
+    write!(buf, "static COMBINING: &[(char, char)] = &{combining:?};").unwrap();
+}
+
+/// Links the variants whose names differ only in the case of the first letter
+/// of their symbol, such as `sym.alpha` and `sym.Alpha`, and whose values are
+/// a lowercase and an uppercase letter. The tables hold indices into `entries`
+/// and are sorted by lowercase and by uppercase variant respectively.
+fn process_cases(buf: &mut String, entries: &[Entry]) {
+    let is_letter = |value: &str, f: fn(&char) -> bool| {
+        let mut chars = value.chars();
+        chars.next().is_some_and(|c| f(&c)) && chars.next().is_none()
+    };
+    let mut uppercase = vec![];
+    for (i, entry) in entries.iter().enumerate() {
+        if !is_letter(&entry.value, |c| c.is_lowercase()) {
+            continue;
+        }
+        let Some((module, symbol)) = entry.name.split_once('.') else { continue };
+        let mut chars = symbol.chars();
+        let Some(first) = chars.next().filter(char::is_ascii_lowercase) else { continue };
+        let upper = format!("{module}.{}{}", first.to_ascii_uppercase(), chars.as_str());
+        if let Ok(j) = entries.binary_search_by(|entry| entry.name.as_str().cmp(&upper))
+            && is_letter(&entries[j].value, |c| c.is_uppercase())
+        {
+            uppercase.push((i as u16, j as u16));
+        }
+    }
+    let mut lowercase: Vec<(u16, u16)> = uppercase.iter().map(|&(i, j)| (j, i)).collect();
+    lowercase.sort();
+    write!(buf, "static UPPERCASE: &[(u16, u16)] = &{uppercase:?};").unwrap();
+    write!(buf, "static LOWERCASE: &[(u16, u16)] = &{lowercase:?};").unwrap();
+}
+
+/// Processes the canonical decompositions and combining classes of characters
+/// and turns them into tables sorted by character.
+///
+/// The decompositions are applied recursively. The indices of the entries
+/// whose value is not in canonical decomposition (NFD) are additionally listed
+/// alongside their decomposed value, sorted by decomposed value and then in
+/// the order of `order`.
+fn process_decompositions(
+    buf: &mut String,
+    file: &Path,
+    entries: &[Entry],
+    order: &[u16],
+) {
+    println!("cargo::rerun-if-changed={}", file.display());
+
+    let text = std::fs::read_to_string(file).unwrap();
+    let mut mappings: HashMap<char, Vec<char>> = HashMap::new();
+    let mut classes: HashMap<char, u8> = HashMap::new();
+    for (i, line) in text.lines().enumerate() {
+        if line.is_empty() || line.starts_with("//") {
+            continue;
+        }
+        let fail = |message: String| -> ! {
+            println!("cargo::warning={}:{}: {message}", file.display(), i + 1);
+            std::process::exit(1);
+        };
+        let parse = |code: &str| {
+            u32::from_str_radix(code, 16)
+                .ok()
+                .and_then(|n| char::try_from(n).ok())
+                .unwrap_or_else(|| fail(format!("invalid codepoint: {code}")))
+        };
+        let [c, mapping, class] = line.split(';').collect::<Vec<_>>()[..] else {
+            fail(String::from("expected three fields"));
+        };
+        let c = parse(c);
+        if mappings.contains_key(&c) || classes.contains_key(&c) {
+            fail(format!("duplicate character: {c:?}"));
+        }
+        if !mapping.is_empty() {
+            mappings.insert(c, mapping.split(' ').map(parse).collect());
+        }
+        match class.parse::<u8>() {
+            Ok(0) => {}
+            Ok(class) => {
+                classes.insert(c, class);
+            }
+            Err(_) => fail(format!("invalid combining class: {class}")),
+        }
+    }
+
+    fn decompose(c: char, mappings: &HashMap<char, Vec<char>>, out: &mut Vec<char>) {
+        match mappings.get(&c) {
+            Some(mapping) => {
+                mapping.iter().for_each(|&c| decompose(c, mappings, out));
+            }
+            None => out.push(c),
+        }
+    }
+
+    let mut decompositions: Vec<(char, String)> = mappings
+        .keys()
+        .map(|&c| {
+            let mut out = vec![];
+            decompose(c, &mappings, &mut out);
+            (c, out.into_iter().collect())
+        })
+        .collect();
+    decompositions.sort();
+    let mut classes: Vec<(char, u8)> = classes.into_iter().collect();
+    classes.sort();
+
+    let mut decomposed = vec![];
+    for &i in order {
+        let value = &entries[usize::from(i)].value;
+        let mut chars = vec![];
+        value.chars().for_each(|c| decompose(c, &mappings, &mut chars));
+        let class = |c: &char| {
+            classes
+                .binary_search_by_key(c, |&(c, _)| c)
+                .map_or(0, |j| classes[j].1)
+        };
+        for run in chars.split_mut(|c| class(c) == 0) {
+            run.sort_by_key(class);
+        }
+        let nfd: String = chars.into_iter().collect();
+        if nfd != *value {
+            decomposed.push((nfd, i));
+        }
+    }
+    decomposed.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    write!(
+        buf,
+        "#[cfg(feature = \"unicode-data\")] static DECOMPOSITIONS: &[(char, &str)] = &{decompositions:?};"
+    )
+    .unwrap();
+    write!(
+        buf,
+        "#[cfg(feature = \"unicode-data\")] static COMBINING_CLASSES: &[(char, u8)] = &{classes:?};"
+    )
+    .unwrap();
+    write!(
+        buf,
+        "#[cfg(feature = \"unicode-data\")] static DECOMPOSED: &[(&str, u16)] = &{decomposed:?};"
+    )
+    .unwrap();
+}
+
+/// The Unicode metadata of a character.
+struct CharEntry<'a> {
+    c: char,
+    name: &'a str,
+    category: &'a str,
+    block: &'a str,
+    age: (u8, u8),
+    math_class: Option<&'static str>,
+    presentation: Option<&'static str>,
+}
+
+/// Processes the Unicode metadata of characters and turns it into a table
+/// sorted by character.
+///
+/// The names of the characters are concatenated, and their categories and
+/// blocks are stored as indices into tables, which keeps the table compact.
+/// When sections of the modules are excluded, only the characters used by
+/// `entries` are kept.
+fn process_unicode(buf: &mut String, file: &Path, entries: &[Entry]) {
+    println!("cargo::rerun-if-changed={}", file.display());
+
+    let used: HashSet<char> =
+        entries.iter().flat_map(|entry| entry.value.chars()).collect();
+    let text = std::fs::read_to_string(file).unwrap();
+    let mut chars = vec![];
+    for (i, line) in text.lines().enumerate() {
+        if line.is_empty() || line.starts_with("//") {
+            continue;
+        }
+        match parse_unicode_line(line) {
+            Ok(entry) if is_complete() || used.contains(&entry.c) => chars.push(entry),
+            Ok(_) => {}
+            Err(e) => {
+                println!("cargo::warning={}:{}: {e}", file.display(), i + 1);
+                std::process::exit(1);
+            }
+        }
+    }
+    chars.sort_by_key(|entry| entry.c);
+
+    let mut names = String::new();
+    let mut categories: Vec<&str> = chars.iter().map(|entry| entry.category).collect();
+    let mut blocks: Vec<&str> = chars.iter().map(|entry| entry.block).collect();
+    categories.sort();
+    categories.dedup();
+    blocks.sort();
+    blocks.dedup();
+    assert!(blocks.len() <= 256);
+
+    buf.push_str("#[cfg(feature = \"unicode-data\")] static CHARS: &[CharData] = &[");
+    for CharEntry {
+        c,
+        name,
+        category,
+        block,
+        age,
+        math_class,
+        presentation,
+    } in chars
+    {
+        let start = names.len();
+        names.push_str(name);
+        let category = categories.binary_search(&category).unwrap();
+        let block = blocks.binary_search(&block).unwrap();
+        write!(
+            buf,
+            "CharData {{ c: {c:?}, name: ({start}, {}), category: {category}, \
+             block: {block}, age: {age:?}, math_class: ",
+            name.len(),
+        )
+        .unwrap();
+        match math_class {
+            Some(class) => write!(buf, "Some(MathClass::{class}), ").unwrap(),
+            None => buf.push_str("None, "),
+        }
+        match presentation {
+            Some(presentation) => {
+                write!(buf, "presentation: Some(Presentation::{presentation}) }},")
+                    .unwrap()
+            }
+            None => buf.push_str("presentation: None },"),
+        }
+    }
+    buf.push_str("];");
+    write!(
+        buf,
+        "#[cfg(feature = \"unicode-data\")] static CHAR_NAMES: &str = {names:?};"
+    )
+    .unwrap();
+    write!(
+        buf,
+        "#[cfg(feature = \"unicode-data\")] static CATEGORIES: &[&str] = &{categories:?};"
+    )
+    .unwrap();
+    write!(
+        buf,
+        "#[cfg(feature = \"unicode-data\")] static BLOCKS: &[&str] = &{blocks:?};"
+    )
+    .unwrap();
+}
+
+/// Parses a line of Unicode metadata.
+fn parse_unicode_line(line: &str) -> StrResult<CharEntry<'_>> {
+    let mut fields = line.split(';');
+    let mut next = || fields.next().ok_or("missing field");
+    let code = next()?;
+    let c = u32::from_str_radix(code, 16)
+        .ok()
+        .and_then(|n| char::try_from(n).ok())
+        .ok_or_else(|| format!("invalid codepoint: {code}"))?;
+    let (name, category, block, age) = (next()?, next()?, next()?, next()?);
+    let age = age
+        .split_once('.')
+        .and_then(|(major, minor)| Some((major.parse().ok()?, minor.parse().ok()?)))
+        .ok_or_else(|| format!("invalid age: {age}"))?;
+    let math_class = match next()? {
+        "" => None,
+        "N" => Some("Normal"),
+        "A" => Some("Alphabetic"),
+        "B" => Some("Binary"),
+        "C" => Some("Closing"),
+        "D" => Some("Diacritic"),
+        "F" => Some("Fence"),
+        "G" => Some("GlyphPart"),
+        "L" => Some("Large"),
+        "O" => Some("Opening"),
+        "P" => Some("Punctuation"),
+        "R" => Some("Relation"),
+        "S" => Some("Space"),
+        "U" => Some("Unary"),
+        "V" => Some("Vary"),
+        "X" => Some("Special"),
+        class => return Err(format!("invalid math class: {class}")),
+    };
+    let presentation = match next()? {
+        "" => None,
+        "T" => Some("Text"),
+        "E" => Some("Emoji"),
+        presentation => return Err(format!("invalid presentation: {presentation}")),
+    };
+    Ok(CharEntry {
+        c,
+        name,
+        category,
+        block,
+        age,
+        math_class,
+        presentation,
+    })
+}
+
+/// Collects the non-deprecated variants in a module.
+fn collect(entries: &mut Vec<Entry>, module: &Module, path: &str) {
+    for (name, binding) in &module.0 {
+        if binding.deprecation.is_some() {
+            continue;
+        }
+        let path = format!("{path}.{name}");
+        let desc = binding.desc.map(str::to_string);
+        match &binding.def {
+            Def::Module(module) => collect(entries, module, &path),
+            Def::Symbol(Symbol::Single(value)) => {
+                entries.push(Entry { name: path, value: value.clone(), desc })
+            }
+            Def::Symbol(Symbol::Multi(list)) => {
+                for (modifiers, value, deprecation) in list {
+                    if deprecation.is_some() {
+                        continue;
+                    }
+                    let name = if modifiers.is_empty() {
+                        path.clone()
+                    } else {
+                        format!("{path}.{}", modifiers.as_str())
+                    };
+                    entries.push(Entry {
+                        name,
+                        value: value.clone(),
+                        desc: desc.clone(),
+                    });
+                }
+            }
+        }
+    }
 }
 
 /// Tokenizes and classifies a line.
 fn tokenize(line: &str) -> StrResult<Line<'_>> {
     // Strip comments.
-    let line = line.split_once("//").map_or(line, |(head, _)| head);
+    let (line, comment) = match line.split_once("//") {
+        Some((head, comment)) => (head, Some(comment.trim())),
+        None => (line, None),
+    };
+
+    // Keep lines consisting of a comment, which describe the following
+    // definitions.
+    if let Some(comment) = comment
+        && line.trim().is_empty()
+    {
+        return Ok(Line::Comment(comment));
+    }
 
     // Ignore empty lines.
     let line = line.trim();
@@ -164,6 +1138,18 @@ fn tokenize(line: &str) -> StrResult<Line<'_>> {
 
     Ok(if head == "@deprecated:" {
         Line::Deprecated(tail.ok_or("missing deprecation message")?.trim())
+    } else if head == "@alias:" {
+        let target = tail.ok_or("missing alias target")?.trim();
+        for part in target.split('.') {
+            validate_ident(part)?;
+        }
+        Line::Alias(target)
+    } else if head == "@feature:" {
+        let feature = tail.ok_or("missing feature")?.trim();
+        if !SECTION_FEATURES.contains(&feature) {
+            return Err(format!("unknown feature: {feature}"));
+        }
+        Line::Feature(feature)
     } else if tail == Some("{") {
         validate_ident(head)?;
         Line::ModuleStart(head)
@@ -173,15 +1159,27 @@ fn tokenize(line: &str) -> StrResult<Line<'_>> {
         for part in rest.split('.') {
             validate_ident(part)?;
         }
-        let value = decode_value(tail.ok_or("missing char")?)?;
+        let value = tail.map(decode_value).transpose()?;
         Line::Variant(ModifierSet::from_raw_dotted(rest), value)
     } else {
         validate_ident(head)?;
         let value = tail.map(decode_value).transpose()?;
-        Line::Symbol(head, value)
+        Line::Symbol(head, value, comment)
     })
 }
 
+/// Whether a Cargo feature of the crate is enabled.
+fn is_enabled(feature: &str) -> bool {
+    let var = format!("CARGO_FEATURE_{}", feature.to_uppercase().replace('-', "_"));
+    std::env::var_os(var).is_some()
+}
+
+/// Whether all the sections of the modules are included, in which case the
+/// data files are checked against the complete catalog.
+fn is_complete() -> bool {
+    SECTION_FEATURES.into_iter().all(is_enabled)
+}
+
 /// Ensures that a string is a valid identifier. In `codex`, we use very strict
 /// rules and allow only alphabetic ASCII chars.
 fn validate_ident(string: &str) -> StrResult<()> {
@@ -234,6 +1232,12 @@ fn decode_value(mut text: &str) -> StrResult<String> {
             };
             result.push(vs);
             text = tail;
+        } else if let Some(tail) = text.strip_prefix("\\zwj") {
+            result.push('\u{200D}');
+            text = tail;
+        } else if let Some(tail) = text.strip_prefix("\\tone") {
+            result.push(SKIN_TONE);
+            text = tail;
         } else if let Some((prefix, tail)) = text.find('\\').map(|i| text.split_at(i)) {
             if prefix.is_empty() {
                 return Err(format!("invalid escape sequence: {tail}"));
@@ -248,8 +1252,13 @@ fn decode_value(mut text: &str) -> StrResult<String> {
 }
 
 /// Turns a stream of lines into a list of definitions.
+///
+/// `path` contains the names of the modules being parsed. The values of
+/// aliases are left empty, and the aliases are added to `aliases`.
 fn parse<'a>(
     p: &mut Peekable<impl Iterator<Item = StrResult<Declaration<'a>>>>,
+    path: &[&'a str],
+    aliases: &mut Vec<Alias<'a>>,
 ) -> StrResult<Vec<(&'a str, Binding<'a>)>> {
     let mut defs = vec![];
     loop {
@@ -257,34 +1266,55 @@ fn parse<'a>(
             None | Some(Declaration::ModuleEnd) => {
                 break;
             }
-            Some(Declaration::Symbol(name, value, deprecation)) => {
+            Some(Declaration::Symbol(name, value, deprecation, desc)) => {
+                let path = [path, &[name]].concat();
+                let mut literal = |value, modifiers| match value {
+                    Value::Literal(value) => value,
+                    Value::Alias(target) => {
+                        aliases.push(Alias { path: path.clone(), modifiers, target });
+                        String::new()
+                    }
+                };
+
                 let mut variants = vec![];
                 while let Some(Declaration::Variant(name, value, deprecation)) =
                     p.peek().cloned().transpose()?
                 {
-                    variants.push((name, value, deprecation));
+                    variants.push((name, literal(value, Some(name)), deprecation));
                     p.next();
                 }
 
-                let symbol = if !variants.is_empty() {
+                let has_skin_tones = matches!(
+                    &value,
+                    Some(Value::Literal(value)) if value.contains(SKIN_TONE),
+                );
+                let symbol = if !variants.is_empty() || has_skin_tones {
                     if let Some(value) = value {
-                        variants.insert(0, (ModifierSet::default(), value, None));
+                        let modifiers = ModifierSet::default();
+                        variants.insert(
+                            0,
+                            (modifiers, literal(value, Some(modifiers)), None),
+                        );
                     }
-                    Symbol::Multi(variants)
+                    Symbol::Multi(expand_skin_tones(variants)?)
                 } else {
                     let value = value.ok_or("symbol needs char or variants")?;
-                    Symbol::Single(value)
+                    Symbol::Single(literal(value, None))
                 };
 
-                defs.push((name, Binding { def: Def::Symbol(symbol), deprecation }));
+                defs.push((
+                    name,
+                    Binding { def: Def::Symbol(symbol), deprecation, desc },
+                ));
             }
-            Some(Declaration::ModuleStart(name, deprecation)) => {
-                let module_defs = parse(p)?;
+            Some(Declaration::ModuleStart(name, deprecation, desc)) => {
+                let module_defs = parse(p, &[path, &[name]].concat(), aliases)?;
                 defs.push((
                     name,
                     Binding {
                         def: Def::Module(Module::new(module_defs)),
                         deprecation,
+                        desc,
                     },
                 ));
             }
@@ -294,6 +1324,48 @@ fn parse<'a>(
     Ok(defs)
 }
 
+/// Replaces each variant whose value has a `\tone` by a variant without skin
+/// tone, followed by one variant per skin tone.
+///
+/// The modifiers of the skin tones are added to those of the variant. An emoji
+/// presentation selector before a skin tone is removed, since skin tones
+/// already imply the emoji presentation.
+fn expand_skin_tones(variants: Vec<Variant>) -> StrResult<Vec<Variant>> {
+    let mut expanded = vec![];
+    for (modifiers, value, deprecation) in variants {
+        if !value.contains(SKIN_TONE) {
+            expanded.push((modifiers, value, deprecation));
+            continue;
+        }
+        expanded.push((modifiers, value.replace(SKIN_TONE, ""), deprecation));
+        for (name, tone) in SKIN_TONES {
+            if modifiers.contains(name) {
+                return Err(format!("skin tone `{name}` conflicts with a modifier"));
+            }
+            let mut toned = ModifierSet::from_raw_dotted(modifiers.as_str().to_owned());
+            toned.insert_raw(name);
+            let toned = ModifierSet::from_raw_dotted(&*String::leak(toned.0));
+            let value = value
+                .replace(&format!("\u{FE0F}{SKIN_TONE}"), &tone.to_string())
+                .replace(SKIN_TONE, &tone.to_string());
+            expanded.push((toned, value, deprecation));
+        }
+    }
+    let mut sets: Vec<_> = expanded
+        .iter()
+        .map(|(modifiers, _, _)| {
+            let mut set: Vec<_> = modifiers.iter().collect();
+            set.sort();
+            set
+        })
+        .collect();
+    sets.sort();
+    if let Some(pair) = sets.windows(2).find(|pair| pair[0] == pair[1]) {
+        return Err(format!("duplicate variant `{}`", pair[0].join(".")));
+    }
+    Ok(expanded)
+}
+
 /// Encodes a `Module` into Rust code.
 fn encode(buf: &mut String, module: &Module) {
     buf.push_str("Module(&[");
@@ -0,0 +1,14 @@
+//! Parsing any string in any numeral system must not panic, and must only
+//! accept representations of the parsed number.
+
+#![no_main]
+
+use codex::numeral_systems::NumeralSystem;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: (NumeralSystem<'static>, &str)| {
+    let (system, s) = input;
+    if let Ok(n) = system.parse(s) {
+        assert_eq!(system.represent(n).unwrap().to_string(), s);
+    }
+});
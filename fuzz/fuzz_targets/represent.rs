@@ -0,0 +1,30 @@
+//! Representing any number in any numeral system must neither panic nor
+//! write a huge string, and all the ways of representing it must agree.
+
+#![no_main]
+
+use codex::numeral_systems::{InlineRepresentationError, NumeralSystem};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: (NumeralSystem<'static>, u64)| {
+    let (system, n) = input;
+    let represented = system.represent(n).map(|r| r.to_string());
+    if let Ok(s) = &represented {
+        assert!(s.len() <= 4 * NumeralSystem::MAX_REPETITIONS as usize);
+    }
+
+    match system.represent_inline::<64>(n) {
+        Ok(s) => assert_eq!(Ok(s.as_str()), represented.as_deref()),
+        Err(InlineRepresentationError::Unrepresentable(err)) => {
+            assert_eq!(Err(err), represented)
+        }
+        Err(InlineRepresentationError::Overflow) => {
+            assert!(represented.as_ref().unwrap().len() > 64)
+        }
+        Err(InlineRepresentationError::Unsupported) => unreachable!(),
+    }
+
+    let mut sequence = system.sequence(n..=n);
+    assert_eq!(sequence.next(), Some(represented));
+    assert_eq!(sequence.next(), None);
+});
@@ -0,0 +1,130 @@
+//! Formats and parses numbers in the numeral systems of codex.
+
+use std::process::ExitCode;
+
+use codex::numeral_systems::{
+    self, NameError, NamedNumeralSystem, ParseError, RepresentationError,
+};
+
+const USAGE: &str = "\
+Usage:
+  codex-num --system <NAME> <NUMBER>...      Represent numbers in a numeral system
+  codex-num --system <NAME> --parse <S>...   Parse numerals in a numeral system
+  codex-num --detect <S>...                  List the numeral systems that can parse numerals
+  codex-num --list                           List the numeral systems
+  codex-num --dump-json                      Export the numeral systems and samples as JSON
+
+Numeral systems can be referred to by name or by shorthand (like `I` for `Roman`).";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    let result = match args.as_slice() {
+        ["--list"] => {
+            list();
+            Ok(())
+        }
+        ["--dump-json"] => {
+            print!("{}", numeral_systems::export_json());
+            Ok(())
+        }
+        ["--detect", numerals @ ..] if !numerals.is_empty() => detect(numerals),
+        ["--system", name, "--parse", numerals @ ..] if !numerals.is_empty() => {
+            find(name).and_then(|system| parse(system, numerals))
+        }
+        ["--system", name, numbers @ ..] if !numbers.is_empty() => {
+            find(name).and_then(|system| represent(system, numbers))
+        }
+        ["--help" | "-h"] => {
+            println!("{USAGE}");
+            Ok(())
+        }
+        _ => {
+            eprintln!("{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("error: {message}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Prints the names and shorthands of the numeral systems.
+fn list() {
+    for system in NamedNumeralSystem::iter() {
+        match system.shorthand() {
+            Some(shorthand) => println!("{:<24} {shorthand}", system.name()),
+            None => println!("{}", system.name()),
+        }
+    }
+}
+
+/// Returns the numeral system with the given name or shorthand.
+fn find(name: &str) -> Result<NamedNumeralSystem, String> {
+    match NamedNumeralSystem::lookup(name) {
+        Ok(system) => Ok(system),
+        Err(NameError::Unknown) => NamedNumeralSystem::from_shorthand(name)
+            .ok_or_else(|| format!("unknown numeral system `{name}`")),
+        Err(NameError::Disabled { feature }) => {
+            Err(format!("the numeral system `{name}` requires the `{feature}` feature"))
+        }
+    }
+}
+
+/// Prints the representations of numbers.
+fn represent(system: NamedNumeralSystem, numbers: &[&str]) -> Result<(), String> {
+    for number in numbers {
+        let n: u64 = number.parse().map_err(|_| format!("invalid number `{number}`"))?;
+        match system.system().represent(n) {
+            Ok(r) => println!("{r}"),
+            Err(RepresentationError::Zero) => {
+                return Err(format!("`{}` cannot represent zero", system.name()));
+            }
+            Err(RepresentationError::TooLarge) => {
+                return Err(format!("{n} is too large for `{}`", system.name()));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Prints the values of numerals.
+fn parse(system: NamedNumeralSystem, numerals: &[&str]) -> Result<(), String> {
+    for numeral in numerals {
+        match system.system().parse(numeral) {
+            Ok(n) => println!("{n}"),
+            Err(ParseError::Invalid) => {
+                return Err(format!(
+                    "`{numeral}` is not a valid `{}` numeral",
+                    system.name()
+                ));
+            }
+            Err(ParseError::TooLarge) => return Err(format!("`{numeral}` is too large")),
+        }
+    }
+    Ok(())
+}
+
+/// Prints the numeral systems that can parse each numeral, and the
+/// corresponding values.
+fn detect(numerals: &[&str]) -> Result<(), String> {
+    for numeral in numerals {
+        let matches: Vec<_> = NamedNumeralSystem::iter()
+            .filter_map(|system| Some((system, system.system().parse(numeral).ok()?)))
+            .collect();
+        if matches.is_empty() {
+            return Err(format!("no numeral system can parse `{numeral}`"));
+        }
+        if numerals.len() > 1 {
+            println!("{numeral}:");
+        }
+        for (system, n) in matches {
+            println!("{:<24} {n}", system.name());
+        }
+    }
+    Ok(())
+}
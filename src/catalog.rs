@@ -0,0 +1,1988 @@
+//! Queries over the whole catalog of symbols, that is, the modules of
+//! [`ROOT`].
+//!
+//! Symbols are referred to by their full names, which are relative to
+//! [`ROOT`], such as `sym.arrow.r` or `emoji.rocket`.
+
+use std::ops::RangeBounds;
+
+use crate::{Binding, Def, ModifierSet, Module, ROOT, Symbol};
+
+mod export;
+#[cfg(feature = "numeral-systems")]
+mod numerals;
+mod registry;
+mod stats;
+
+pub use export::{export_csv, export_json};
+#[cfg(feature = "numeral-systems")]
+pub use numerals::{numeral, numerals};
+pub use registry::{DefineError, Registry};
+pub use stats::{Diff, Statistics, diff, statistics};
+
+include!(concat!(env!("OUT_DIR"), "/catalog.rs"));
+
+/// Resolves a full name into the value of a symbol variant, alongside an
+/// optional deprecation message.
+///
+/// The modifiers can be given in any order, and need not all be specified:
+/// they are matched like in [`Symbol::get`](crate::Symbol::get). Use
+/// [`canonicalize`] to get the full name of the resolved variant.
+///
+/// With the `numeral-systems` feature, the numerals of the named numeral
+/// systems are resolved as well, such as `digit.devanagari.3`. See `numeral`.
+///
+/// ## Example
+///
+/// ```
+/// use codex::catalog::{ResolveError, resolve};
+///
+/// assert_eq!(resolve("sym.arrow.r.long"), Ok(("⟶", None)));
+/// assert_eq!(resolve("sym.arrow.long.r"), Ok(("⟶", None)));
+/// assert_eq!(
+///     resolve("sym.arow.r"),
+///     Err(ResolveError::UnknownName { suggestions: vec!["sym.arrow.r".into()] }),
+/// );
+/// ```
+pub fn resolve(name: &str) -> Result<(&'static str, Option<&'static str>), ResolveError> {
+    if let Some((_, value, _)) = find_canonical(name) {
+        return Ok((value, None));
+    }
+    #[cfg(feature = "numeral-systems")]
+    if let Some(value) = numeral(name) {
+        return Ok((value, None));
+    }
+    let (_, (_, value, deprecation)) = lookup(name)?;
+    Ok((value, deprecation))
+}
+
+/// Returns the canonical full name of the symbol variant a full name resolves
+/// to.
+///
+/// The canonical name lists all the modifiers of the variant, in the order in
+/// which they are defined. This allows normalizing names given by users.
+///
+/// ## Example
+///
+/// ```
+/// use codex::catalog::canonicalize;
+///
+/// assert_eq!(canonicalize("sym.arrow.long.r").as_deref(), Ok("sym.arrow.r.long"));
+/// assert_eq!(canonicalize("sym.arrow").as_deref(), Ok("sym.arrow.r"));
+/// assert!(canonicalize("sym.arrow.unknown").is_err());
+/// ```
+pub fn canonicalize(name: &str) -> Result<String, ResolveError> {
+    if let Some((name, _, _)) = find_canonical(name) {
+        return Ok(name.to_string());
+    }
+    let (symbol, (modifiers, _, _)) = lookup(name)?;
+    Ok(canonical_name(symbol, modifiers))
+}
+
+/// Returns the deprecation of a full name, or `None` if the name is not
+/// deprecated.
+///
+/// Renamed symbols and variants keep resolving under their old names, which
+/// are deprecated aliases of the new ones.
+///
+/// ## Example
+///
+/// ```
+/// use codex::catalog::deprecation;
+///
+/// let deprecation = deprecation("sym.prec.curly.eq").unwrap().unwrap();
+/// assert_eq!(deprecation.replacement.as_deref(), Some("sym.prec.eq.slant"));
+/// assert_eq!(codex::catalog::deprecation("sym.prec.eq.slant"), Ok(None));
+/// ```
+pub fn deprecation(name: &str) -> Result<Option<Deprecation>, ResolveError> {
+    let (symbol, (modifiers, _, deprecation)) = lookup(name)?;
+    let Some(message) = deprecation else { return Ok(None) };
+    let canonical = canonical_name(symbol, modifiers);
+    let find = |name: &str| {
+        let i = ALIASES.binary_search_by_key(&name, |&(alias, _)| alias).ok()?;
+        Some(ALIASES[i].1)
+    };
+    // Either the variant or the whole symbol can be an alias.
+    let replacement = find(&canonical)
+        .map(str::to_string)
+        .or_else(|| Some(format!("{}{}", find(symbol)?, &canonical[symbol.len()..])));
+    Ok(Some(Deprecation { message, replacement }))
+}
+
+/// The deprecation of a full name.
+///
+/// Values of this type are returned by [`deprecation`].
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub struct Deprecation {
+    /// The deprecation message.
+    pub message: &'static str,
+    /// The canonical full name replacing the deprecated one, if the latter is
+    /// an alias.
+    pub replacement: Option<String>,
+}
+
+/// A reason why a full name cannot be resolved.
+///
+/// Values of this type are returned by [`resolve`] and [`canonicalize`]. They
+/// carry the closest full names, so that diagnostics can suggest them.
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub enum ResolveError {
+    /// No symbol has this name.
+    UnknownName {
+        /// The closest canonical full names, from the closest to the
+        /// farthest.
+        suggestions: Vec<String>,
+    },
+    /// The symbol has no variant with these modifiers.
+    UnknownModifiers {
+        /// The closest canonical full names of variants of the symbol, from
+        /// the closest to the farthest.
+        suggestions: Vec<String>,
+    },
+}
+
+impl ResolveError {
+    /// Returns the closest canonical full names, from the closest to the
+    /// farthest.
+    pub fn suggestions(&self) -> &[String] {
+        match self {
+            Self::UnknownName { suggestions }
+            | Self::UnknownModifiers { suggestions } => suggestions,
+        }
+    }
+}
+
+/// The maximum number of suggestions in a [`ResolveError`].
+const MAX_SUGGESTIONS: usize = 5;
+
+/// A symbol variant, represented by its modifiers, its value, and an optional
+/// deprecation message.
+type Variant = (ModifierSet<&'static str>, &'static str, Option<&'static str>);
+
+/// Finds a canonical full name of a non-deprecated variant in the sorted
+/// table of names, with its value and description.
+///
+/// Most names given by users are canonical, so this avoids walking the
+/// modules and matching the modifiers of the variants.
+fn find_canonical(
+    name: &str,
+) -> Option<(&'static str, &'static str, Option<&'static str>)> {
+    let i = NAMES.binary_search_by_key(&name, |&(name, _, _)| name).ok()?;
+    Some(NAMES[i])
+}
+
+/// Resolves a full name into the prefix naming its symbol and a variant of the
+/// symbol.
+fn lookup(name: &str) -> Result<(&str, Variant), ResolveError> {
+    let mut module = ROOT;
+    let mut rest = name;
+    let mut deprecation = None;
+    let (symbol, modifiers) = loop {
+        let (head, tail) = match rest.split_once('.') {
+            Some((head, tail)) => (head, Some(tail)),
+            None => (rest, None),
+        };
+        let Some(binding) = module.get(head) else {
+            let prefix = &name[..name.len() - rest.len()];
+            let suggestions = suggest_names(module, prefix, head, tail);
+            return Err(ResolveError::UnknownName { suggestions });
+        };
+        deprecation = deprecation.or(binding.deprecation);
+        match binding.def {
+            Def::Module(inner) => {
+                module = inner;
+                rest = tail.ok_or(ResolveError::UnknownName { suggestions: vec![] })?;
+            }
+            Def::Symbol(symbol) => break (symbol, tail),
+        }
+    };
+
+    let prefix = &name[..name.len() - modifiers.map_or(0, |m| m.len() + 1)];
+    let variant = match modifiers {
+        Some(modifiers) if modifiers.split('.').any(str::is_empty) => None,
+        _ => ModifierSet::from_raw_dotted(modifiers.unwrap_or_default())
+            .best_match_in(symbol.variants().map(|variant| (variant.0, variant))),
+    };
+    let Some((modifiers, value, variant_deprecation)) = variant else {
+        let modifiers = modifiers.unwrap_or_default().split('.');
+        let modifiers: Vec<&str> = modifiers.filter(|m| !m.is_empty()).collect();
+        let suggestions = suggest_modifiers(prefix, symbol, &modifiers);
+        return Err(ResolveError::UnknownModifiers { suggestions });
+    };
+    Ok((prefix, (modifiers, value, deprecation.or(variant_deprecation))))
+}
+
+/// Returns the binding of a module or symbol with the given full name, if
+/// any.
+///
+/// The deprecation of the binding includes that of the enclosing modules.
+fn find(name: &str) -> Option<Binding> {
+    let mut module = ROOT;
+    let mut deprecation = None;
+    let mut parts = name.split('.').peekable();
+    loop {
+        let binding = module.get(parts.next()?)?;
+        deprecation = deprecation.or(binding.deprecation);
+        if parts.peek().is_none() {
+            return Some(Binding { def: binding.def, deprecation });
+        }
+        match binding.def {
+            Def::Module(inner) => module = inner,
+            Def::Symbol(_) => return None,
+        }
+    }
+}
+
+/// Suggests the closest names for a name that is not bound in a module.
+///
+/// `prefix` is the full name of the module, including a trailing dot, and
+/// `tail` contains the rest of the name after `head`.
+fn suggest_names(
+    module: Module,
+    prefix: &str,
+    head: &str,
+    tail: Option<&str>,
+) -> Vec<String> {
+    let mut candidates: Vec<(usize, &str)> = module
+        .iter()
+        .filter(|(_, binding)| binding.deprecation.is_none())
+        .map(|(name, _)| (distance(head, name), name))
+        .filter(|&(distance, _)| distance <= max_distance(head))
+        .collect();
+    candidates.sort();
+    candidates
+        .into_iter()
+        .take(MAX_SUGGESTIONS)
+        .map(|(_, name)| {
+            let name = format!("{prefix}{name}");
+            // Keep the rest of the name if the result can be resolved.
+            tail.and_then(|tail| canonicalize(&format!("{name}.{tail}")).ok())
+                .unwrap_or(name)
+        })
+        .collect()
+}
+
+/// Suggests the closest variants of a symbol for modifiers that do not match
+/// any variant.
+///
+/// `prefix` is the full name of the symbol.
+fn suggest_modifiers(prefix: &str, symbol: Symbol, modifiers: &[&str]) -> Vec<String> {
+    let max = max_distance(&modifiers.join("."));
+    let mut candidates: Vec<(usize, usize, ModifierSet<&str>)> = symbol
+        .variants()
+        .filter(|&(_, _, deprecation)| deprecation.is_none())
+        .map(|(set, _, _)| {
+            // Each given modifier is compared with the closest modifier of the
+            // variant.
+            let distance = modifiers
+                .iter()
+                .map(|m| set.iter().map(|c| distance(m, c)).min().unwrap_or(m.len()))
+                .sum();
+            (distance, set.iter().count(), set)
+        })
+        .filter(|&(distance, _, _)| distance <= max)
+        .collect();
+    candidates.sort_by_key(|&(distance, count, _)| (distance, count));
+    candidates
+        .into_iter()
+        .take(MAX_SUGGESTIONS)
+        .map(|(_, _, set)| canonical_name(prefix, set))
+        .collect()
+}
+
+/// Returns the full name of the variant of a symbol with the given modifiers.
+fn canonical_name(symbol: &str, modifiers: ModifierSet<&str>) -> String {
+    let mut name = symbol.to_string();
+    for modifier in modifiers {
+        name.push('.');
+        name.push_str(modifier);
+    }
+    name
+}
+
+/// Returns the maximum edit distance for a name to be suggested instead of
+/// `name`.
+fn max_distance(name: &str) -> usize {
+    (name.chars().count() / 3).max(1)
+}
+
+/// Computes the edit distance between two strings, where the transposition
+/// of two adjacent characters counts as a single edit.
+fn distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    // The rows of the distance matrix for the two previous prefixes of `a`
+    // and the current one.
+    let mut before: Vec<usize> = vec![0; b.len() + 1];
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+    for i in 1..=a.len() {
+        current[0] = i;
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            current[j] =
+                (previous[j] + 1).min(current[j - 1] + 1).min(previous[j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                current[j] = current[j].min(before[j - 2] + 1);
+            }
+        }
+        std::mem::swap(&mut before, &mut previous);
+        std::mem::swap(&mut previous, &mut current);
+    }
+    previous[b.len()]
+}
+
+/// Returns an iterator over the full names and values of all the symbol
+/// variants of the catalog, in lexicographic order of the names.
+///
+/// Deprecated names are excluded. This allows enumerating the complete set of
+/// symbols without walking the modules.
+///
+/// ## Example
+///
+/// ```
+/// let mut variants = codex::catalog::iter();
+/// assert!(variants.any(|(name, value)| name == "sym.arrow.r.long" && value == "⟶"));
+/// ```
+pub fn iter() -> impl ExactSizeIterator<Item = (&'static str, &'static str)> {
+    NAMES.iter().map(|&(name, value, _)| (name, value))
+}
+
+/// Returns an iterator over the full names and values of all the symbol
+/// variants of the catalog, in order of code points of the values.
+///
+/// Variants with the same value are ordered with shorter names first, and then
+/// in lexicographic order of the names. Deprecated names are excluded. See
+/// [`iter`] for the lexicographic order of the names.
+///
+/// ## Example
+///
+/// ```
+/// let mut variants = codex::catalog::iter_by_value();
+/// assert_eq!(variants.next(), Some(("sym.space", " ")));
+/// ```
+pub fn iter_by_value() -> impl ExactSizeIterator<Item = (&'static str, &'static str)> {
+    VALUES.iter().map(|&i| {
+        let (name, value, _) = NAMES[usize::from(i)];
+        (name, value)
+    })
+}
+
+/// The kind of content a symbol variant is intended for.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Mode {
+    /// The variant is intended for formulas, such as large operators.
+    Math,
+    /// The variant is intended for running text, such as quotation marks.
+    Text,
+    /// The variant is intended for both formulas and running text.
+    Both,
+}
+
+/// Hints on how to render a symbol variant.
+///
+/// Values of this type are returned by [`rendering_hints`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub struct RenderingHints {
+    /// The kind of content the variant is intended for.
+    pub mode: Mode,
+    /// The tags of the OpenType features the variant typically requires, such
+    /// as `ssty` for primes, which are drawn for the script size.
+    pub features: &'static [&'static str],
+}
+
+/// Returns hints on how to render a symbol variant, given by its full name.
+///
+/// This allows layout engines to pick fonts and features without external
+/// tables. Variants without specific hints are intended for both modes,
+/// except emoji, which are intended for text, and require no feature.
+///
+/// The name is resolved like in [`resolve`]. Returns `None` if the name
+/// cannot be resolved.
+///
+/// ## Example
+///
+/// ```
+/// use codex::catalog::{Mode, rendering_hints};
+///
+/// let hints = rendering_hints("sym.prime").unwrap();
+/// assert_eq!(hints.mode, Mode::Math);
+/// assert_eq!(hints.features, ["ssty"]);
+/// assert_eq!(rendering_hints("sym.quote.l.double").unwrap().mode, Mode::Text);
+/// assert_eq!(rendering_hints("sym.arrow.r").unwrap().mode, Mode::Both);
+/// ```
+pub fn rendering_hints(name: &str) -> Option<RenderingHints> {
+    let name = canonicalize(name).ok()?;
+    Some(match HINTS.binary_search_by_key(&name.as_str(), |&(name, _, _)| name) {
+        Ok(i) => RenderingHints { mode: HINTS[i].1, features: HINTS[i].2 },
+        Err(_) if name.starts_with("emoji.") => {
+            RenderingHints { mode: Mode::Text, features: &[] }
+        }
+        Err(_) => RenderingHints { mode: Mode::Both, features: &[] },
+    })
+}
+
+/// Returns the LaTeX math command of a symbol variant, given by its full
+/// name, such as `\\rightarrow` for `sym.arrow.r`.
+///
+/// The name is resolved like in [`resolve`]. Returns `None` if the name
+/// cannot be resolved or if the variant has no command in LaTeX and the
+/// `amssymb` package.
+///
+/// ## Example
+///
+/// ```
+/// use codex::catalog::to_latex;
+///
+/// assert_eq!(to_latex("sym.arrow.r"), Some("\\rightarrow"));
+/// assert_eq!(to_latex("sym.arrow.double.r"), Some("\\Rightarrow"));
+/// assert_eq!(to_latex("emoji.rocket"), None);
+/// ```
+#[cfg(feature = "latex")]
+pub fn to_latex(name: &str) -> Option<&'static str> {
+    let name = canonicalize(name).ok()?;
+    let i = LATEX.binary_search_by_key(&name.as_str(), |&(name, _)| name).ok()?;
+    Some(LATEX[i].1)
+}
+
+/// Returns the canonical full name of the symbol variant of a LaTeX math
+/// command, such as `sym.arrow.r` for `\\rightarrow` or `\\to`.
+///
+/// ## Example
+///
+/// ```
+/// use codex::catalog::from_latex;
+///
+/// assert_eq!(from_latex("\\to"), Some("sym.arrow.r"));
+/// assert_eq!(from_latex("\\leq"), Some("sym.lt.eq"));
+/// assert_eq!(from_latex("\\frac"), None);
+/// ```
+#[cfg(feature = "latex")]
+pub fn from_latex(command: &str) -> Option<&'static str> {
+    let i = LATEX_COMMANDS
+        .binary_search_by_key(&command, |&(command, _)| command)
+        .ok()?;
+    Some(LATEX_COMMANDS[i].1)
+}
+
+/// Returns the HTML named character reference of the value of a symbol, such
+/// as `&rarr;` for `→`.
+///
+/// A trailing variation selector of the value is ignored, since the named
+/// character references do not include them. Returns `None` if the value has
+/// no named character reference or is not that of a symbol.
+///
+/// ## Example
+///
+/// ```
+/// use codex::catalog::to_html_entity;
+///
+/// assert_eq!(to_html_entity("→"), Some("&rarr;"));
+/// assert_eq!(to_html_entity("≤"), Some("&le;"));
+/// assert_eq!(to_html_entity("🚀"), None);
+/// ```
+pub fn to_html_entity(value: &str) -> Option<&'static str> {
+    let value = value.trim_end_matches(['\u{FE0E}', '\u{FE0F}']);
+    let i = HTML_ENTITIES.binary_search_by_key(&value, |&(value, _)| value).ok()?;
+    Some(HTML_ENTITIES[i].1)
+}
+
+/// Returns the value of an HTML named character reference, such as `→` for
+/// `&rarr;` or `&rightarrow;`, if it is that of a symbol.
+///
+/// The reference must include the leading `&` and the trailing `;`. Use
+/// [`names_for`] to find the names of the symbols with this value.
+///
+/// ## Example
+///
+/// ```
+/// use codex::catalog::from_html_entity;
+///
+/// assert_eq!(from_html_entity("&rightarrow;"), Some("→"));
+/// assert_eq!(from_html_entity("&alpha;"), Some("α"));
+/// assert_eq!(from_html_entity("alpha"), None);
+/// ```
+pub fn from_html_entity(entity: &str) -> Option<&'static str> {
+    let i = HTML_VALUES
+        .binary_search_by_key(&entity, |&(entity, _)| entity)
+        .ok()?;
+    Some(HTML_VALUES[i].1)
+}
+
+/// A child of a module or symbol of the catalog.
+///
+/// Values of this type are returned by [`children`].
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum Child {
+    /// A nested module, with its full name.
+    Module(String),
+    /// A symbol, with its full name.
+    Symbol(String),
+    /// A variant of a symbol, with its full name and value.
+    Variant(String, &'static str),
+}
+
+impl Child {
+    /// Returns the full name of the child.
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Module(name) | Self::Symbol(name) | Self::Variant(name, _) => name,
+        }
+    }
+}
+
+/// Returns the children of a module or symbol, given by its full name, or
+/// `None` if there is no such module or symbol.
+///
+/// The children of a module are its modules and symbols, and those of a symbol
+/// are its variants. The empty name refers to [`ROOT`]. Deprecated children
+/// are excluded. This allows building hierarchical symbol pickers.
+///
+/// ## Example
+///
+/// ```
+/// use codex::catalog::{Child, children};
+///
+/// let modules = children("").unwrap();
+/// assert!(modules.contains(&Child::Module("sym".into())));
+/// let variants = children("sym.arrow").unwrap();
+/// assert!(variants.contains(&Child::Variant("sym.arrow.r.long".into(), "⟶")));
+/// ```
+pub fn children(name: &str) -> Option<Vec<Child>> {
+    let def = if name.is_empty() { Def::Module(ROOT) } else { find(name)?.def };
+    let prefix = if name.is_empty() { String::new() } else { format!("{name}.") };
+    Some(match def {
+        Def::Module(module) => module
+            .iter()
+            .filter(|(_, binding)| binding.deprecation.is_none())
+            .map(|(child, binding)| match binding.def {
+                Def::Module(_) => Child::Module(format!("{prefix}{child}")),
+                Def::Symbol(_) => Child::Symbol(format!("{prefix}{child}")),
+            })
+            .collect(),
+        Def::Symbol(symbol) => symbol
+            .variants()
+            .filter(|(_, _, deprecation)| deprecation.is_none())
+            .map(|(modifiers, value, _)| {
+                Child::Variant(canonical_name(name, modifiers), value)
+            })
+            .collect(),
+    })
+}
+
+/// Returns the full name of the module or symbol containing a module, symbol,
+/// or variant, given by its full name.
+///
+/// The parent of a variant is its symbol, and that of a module or symbol is
+/// the enclosing module. Since a variant without modifiers has the same name as
+/// its symbol, its parent is that of the symbol. The empty name refers to
+/// [`ROOT`]. Returns `None` if
+/// the name cannot be resolved or refers to [`ROOT`].
+///
+/// ## Example
+///
+/// ```
+/// use codex::catalog::parent;
+///
+/// assert_eq!(parent("sym.arrow.long.r"), Some("sym.arrow"));
+/// assert_eq!(parent("sym.arrow"), Some("sym"));
+/// assert_eq!(parent("sym.chess.king"), Some("sym.chess"));
+/// assert_eq!(parent("sym"), Some(""));
+/// ```
+pub fn parent(name: &str) -> Option<&str> {
+    if name.is_empty() {
+        return None;
+    }
+    if find(name).is_some() {
+        return Some(name.rsplit_once('.').map_or("", |(parent, _)| parent));
+    }
+    lookup(name).ok().map(|(symbol, _)| symbol)
+}
+
+/// Returns the full names of the symbol variants whose value is `value`.
+///
+/// Deprecated names are excluded, and shorter names come first. This allows
+/// converting pasted text into codex notation.
+///
+/// With the `unicode-data` feature, values are compared up to canonical
+/// equivalence (see `is_equivalent`), so that a decomposed value such as
+/// `=\u{338}` yields the names of `≠`. The names of the variants whose value
+/// is identical to `value` come first.
+///
+/// ## Example
+///
+/// ```
+/// let names: Vec<_> = codex::catalog::names_for("→").collect();
+/// assert_eq!(names[0], "sym.arrow.r");
+///
+/// # #[cfg(feature = "unicode-data")]
+/// # {
+/// let names: Vec<_> = codex::catalog::names_for("=\u{338}").collect();
+/// assert_eq!(names[0], "sym.eq.not");
+/// # }
+/// ```
+pub fn names_for(value: &str) -> impl Iterator<Item = &'static str> {
+    let indices: Vec<u16> = with_value(value).collect();
+    #[cfg(feature = "unicode-data")]
+    let indices = [indices, equivalent_to(value)].concat();
+    indices.into_iter().map(|i| NAMES[usize::from(i)].0)
+}
+
+/// Returns the indices in [`NAMES`] of the variants whose value is
+/// canonically equivalent to `value` without being identical to it.
+#[cfg(feature = "unicode-data")]
+fn equivalent_to(value: &str) -> Vec<u16> {
+    let decomposed = decompose(value);
+    let mut indices = vec![];
+    if decomposed != value {
+        indices.extend(with_value(&decomposed));
+    }
+    let start = DECOMPOSED.partition_point(|&(v, _)| v < decomposed.as_str());
+    indices.extend(
+        DECOMPOSED[start..]
+            .iter()
+            .take_while(|&&(v, _)| v == decomposed)
+            .map(|&(_, i)| i)
+            .filter(|&i| NAMES[usize::from(i)].1 != value),
+    );
+    indices
+}
+
+/// Returns the indices in [`NAMES`] of the variants whose value is `value`,
+/// with shorter names first.
+fn with_value(value: &str) -> impl Iterator<Item = u16> {
+    let start = VALUES.partition_point(|&i| NAMES[usize::from(i)].1 < value);
+    VALUES[start..]
+        .iter()
+        .copied()
+        .take_while(move |&i| NAMES[usize::from(i)].1 == value)
+}
+
+/// Whether two values are canonically equivalent, that is, whether they have
+/// the same canonical decomposition (NFD).
+///
+/// Canonically equivalent values represent the same text, such as `≠` and
+/// `=\u{338}`, or `é` and `e\u{301}`. Decompositions are only known for the
+/// characters that fully decompose into characters used by the symbols,
+/// characters of the Basic Latin block, and combining marks. Other characters
+/// are left as is.
+///
+/// ## Example
+///
+/// ```
+/// use codex::catalog::is_equivalent;
+///
+/// assert!(is_equivalent("≠", "=\u{338}"));
+/// assert!(is_equivalent("e\u{301}", "é"));
+/// assert!(is_equivalent("a\u{301}\u{323}", "a\u{323}\u{301}"));
+/// assert!(!is_equivalent("≠", "="));
+/// ```
+#[cfg(feature = "unicode-data")]
+pub fn is_equivalent(a: &str, b: &str) -> bool {
+    a == b || decompose(a) == decompose(b)
+}
+
+/// Returns the canonical decomposition (NFD) of a string.
+///
+/// The characters are decomposed, and runs of combining marks are then sorted
+/// by combining class.
+#[cfg(feature = "unicode-data")]
+fn decompose(s: &str) -> String {
+    let mut chars = vec![];
+    for c in s.chars() {
+        match DECOMPOSITIONS.binary_search_by_key(&c, |&(c, _)| c) {
+            Ok(i) => chars.extend(DECOMPOSITIONS[i].1.chars()),
+            Err(_) => chars.push(c),
+        }
+    }
+    for run in chars.split_mut(|&c| combining_class(c) == 0) {
+        run.sort_by_key(|&c| combining_class(c));
+    }
+    chars.into_iter().collect()
+}
+
+/// Returns the canonical combining class of a character.
+#[cfg(feature = "unicode-data")]
+fn combining_class(c: char) -> u8 {
+    COMBINING_CLASSES
+        .binary_search_by_key(&c, |&(c, _)| c)
+        .map_or(0, |i| COMBINING_CLASSES[i].1)
+}
+
+/// Returns the full names of the symbol variants starting with a prefix, in
+/// lexicographic order.
+///
+/// Deprecated names are excluded. This is meant for completion in editors.
+///
+/// ## Example
+///
+/// ```
+/// let names: Vec<_> = codex::catalog::complete("sym.arrow.r.").collect();
+/// assert!(names.contains(&"sym.arrow.r.long"));
+/// assert!(names.contains(&"sym.arrow.r.double"));
+/// ```
+pub fn complete(prefix: &str) -> impl Iterator<Item = &'static str> {
+    let start = NAMES.partition_point(|&(name, _, _)| name < prefix);
+    NAMES[start..]
+        .iter()
+        .map(|&(name, _, _)| name)
+        .take_while(move |name| name.starts_with(prefix))
+}
+
+/// A symbol variant matching a query of [`search`] or [`Registry::search`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub struct Match<'a> {
+    /// The full name of the variant, such as `sym.integral.double`.
+    pub name: &'a str,
+    /// The value of the variant.
+    pub value: &'a str,
+    /// The description of the symbol, if any, such as `Calculus.`.
+    pub description: Option<&'a str>,
+    /// The relevance of the match. Higher scores are more relevant.
+    pub score: u32,
+}
+
+/// Searches the symbol variants whose names or descriptions match a query.
+///
+/// The query consists of terms separated by whitespace or dots, all of which
+/// must match. Matching is case-insensitive, and a term matches if it is
+/// contained in the name of the symbol, in one of its modifiers, or in its
+/// description, or if its characters appear in order in the name. Exact
+/// matches of the symbol name and modifiers are the most relevant, and shorter
+/// names are preferred.
+///
+/// The matches are sorted by decreasing relevance, and deprecated names are
+/// excluded.
+///
+/// ## Example
+///
+/// ```
+/// let matches = codex::catalog::search("integral");
+/// assert_eq!(matches[0].name, "sym.integral");
+/// assert_eq!(matches[0].value, "∫");
+/// assert!(matches.iter().any(|m| m.name == "sym.integral.double"));
+/// ```
+pub fn search(query: &str) -> Vec<Match<'static>> {
+    search_in(query, NAMES.iter().copied())
+}
+
+/// Searches the given symbol variants, which are represented by their full
+/// names, values, and descriptions. See [`search`].
+fn search_in<'a>(
+    query: &str,
+    variants: impl Iterator<Item = (&'a str, &'a str, Option<&'a str>)>,
+) -> Vec<Match<'a>> {
+    let query = query.to_lowercase();
+    let terms: Vec<&str> = query
+        .split(|c: char| c.is_whitespace() || c == '.')
+        .filter(|term| !term.is_empty())
+        .collect();
+    if terms.is_empty() {
+        return vec![];
+    }
+
+    let mut matches: Vec<Match> = variants
+        .filter_map(|(name, value, description)| {
+            let score = score(name, description, &terms)?;
+            Some(Match { name, value, description, score })
+        })
+        .collect();
+    matches.sort_by(|a, b| b.score.cmp(&a.score).then(a.name.cmp(b.name)));
+    matches
+}
+
+/// Computes the relevance of a symbol variant for the terms of a query, if
+/// all of them match.
+fn score(name: &str, description: Option<&str>, terms: &[&str]) -> Option<u32> {
+    // Skip the name of the global module, such as `sym`.
+    let relative = name.split_once('.').map_or(name, |(_, rest)| rest).to_lowercase();
+    let mut parts = relative.split('.');
+    let symbol = parts.next().unwrap_or_default();
+    let modifiers: Vec<&str> = parts.collect();
+    let description = description.map(str::to_lowercase);
+
+    let mut total: u32 = 0;
+    for (i, &term) in terms.iter().enumerate() {
+        total += if i == 0 && symbol == term {
+            1000
+        } else if symbol == term || modifiers.contains(&term) {
+            600
+        } else if symbol.starts_with(term) {
+            500
+        } else if modifiers.iter().any(|m| m.starts_with(term)) {
+            400
+        } else if relative.contains(term) {
+            300
+        } else if description.as_ref().is_some_and(|d| d.contains(term)) {
+            200
+        } else if is_subsequence(term, &relative) {
+            100
+        } else {
+            return None;
+        };
+    }
+
+    // Prefer variants with fewer modifiers, and then shorter names.
+    let penalty = 10 * modifiers.len() + relative.len();
+    Some(total.saturating_sub(penalty as u32))
+}
+
+/// Whether the characters of `needle` appear in order in `haystack`.
+fn is_subsequence(needle: &str, haystack: &str) -> bool {
+    let mut chars = haystack.chars();
+    needle.chars().all(|c| chars.any(|h| h == c))
+}
+
+/// The version of the Unicode Character Database from which the metadata of
+/// [`char_info`] comes.
+#[cfg(feature = "unicode-data")]
+pub const UNICODE_VERSION: (u8, u8, u8) = (17, 0, 0);
+
+/// Unicode metadata of a character.
+///
+/// Values of this type are returned by [`char_info`].
+#[cfg(feature = "unicode-data")]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub struct CharInfo {
+    /// The name of the character, such as `RIGHTWARDS ARROW`.
+    pub name: &'static str,
+    /// The abbreviation of the general category of the character, such as
+    /// `Sm` for mathematical symbols.
+    pub category: &'static str,
+    /// The name of the block containing the character, such as `Arrows`.
+    pub block: &'static str,
+    /// The version of Unicode in which the character was introduced, as a
+    /// major and a minor version.
+    pub age: (u8, u8),
+    /// The class of the character in mathematical formulas, if it is used by
+    /// a symbol of `sym`.
+    pub math_class: Option<MathClass>,
+    /// How the character is displayed by default, if it can be displayed
+    /// both as text and as an emoji.
+    pub presentation: Option<Presentation>,
+}
+
+/// The class of a character in mathematical formulas, which determines how
+/// it is spaced.
+///
+/// The classes are those of [Unicode Technical Report #25][tr25].
+///
+/// [tr25]: https://www.unicode.org/reports/tr25/
+#[cfg(feature = "unicode-data")]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum MathClass {
+    /// An ordinary symbol, such as a digit or `∞`.
+    Normal,
+    /// A letter, such as `α`.
+    Alphabetic,
+    /// A binary operator, such as `×`.
+    Binary,
+    /// A closing delimiter, such as `)`.
+    Closing,
+    /// A diacritic, that is, an accent, such as `´`.
+    Diacritic,
+    /// A fence, which can be used both as an opening and a closing
+    /// delimiter, such as `|`.
+    Fence,
+    /// A piece of a larger glyph, such as `⎛`.
+    GlyphPart,
+    /// A large operator, such as `∑`.
+    Large,
+    /// An opening delimiter, such as `(`.
+    Opening,
+    /// A punctuation mark, such as `,`.
+    Punctuation,
+    /// A relation, such as `=` or `→`.
+    Relation,
+    /// A space.
+    Space,
+    /// A unary operator, such as `¬`.
+    Unary,
+    /// An operator that is either unary or binary depending on its context,
+    /// such as `+`.
+    Vary,
+    /// A character that requires special handling.
+    Special,
+}
+
+/// How a character is displayed.
+///
+/// Some characters can be displayed both as text, that is, as a monochrome
+/// glyph of the font, and as a colorful emoji. Their presentation is selected
+/// by appending a variation selector to them, and otherwise depends on the
+/// character. See [Unicode Technical Standard #51][tr51].
+///
+/// [tr51]: https://www.unicode.org/reports/tr51/#Emoji_Variation_Sequences
+#[cfg(feature = "unicode-data")]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Presentation {
+    /// The character is displayed as text. This is selected by `U+FE0E`.
+    Text,
+    /// The character is displayed as an emoji. This is selected by `U+FE0F`.
+    Emoji,
+}
+
+#[cfg(feature = "unicode-data")]
+impl Presentation {
+    /// The variation selector that selects this presentation.
+    pub const fn selector(self) -> char {
+        match self {
+            Self::Text => '\u{FE0E}',
+            Self::Emoji => '\u{FE0F}',
+        }
+    }
+}
+
+/// The Unicode metadata of a character, in the compact form in which it is
+/// stored.
+#[cfg(feature = "unicode-data")]
+struct CharData {
+    c: char,
+    /// The start and the length of the name in [`CHAR_NAMES`].
+    name: (u32, u8),
+    /// The index of the category in [`CATEGORIES`].
+    category: u8,
+    /// The index of the block in [`BLOCKS`].
+    block: u8,
+    age: (u8, u8),
+    math_class: Option<MathClass>,
+    presentation: Option<Presentation>,
+}
+
+#[cfg(feature = "unicode-data")]
+impl CharData {
+    /// Expands the metadata.
+    fn info(&self) -> CharInfo {
+        let (start, len) = (self.name.0 as usize, usize::from(self.name.1));
+        CharInfo {
+            name: &CHAR_NAMES[start..start + len],
+            category: CATEGORIES[usize::from(self.category)],
+            block: BLOCKS[usize::from(self.block)],
+            age: self.age,
+            math_class: self.math_class,
+            presentation: self.presentation,
+        }
+    }
+}
+
+/// Returns the Unicode metadata of a character used by a symbol.
+///
+/// Only the characters used by the symbols of the catalog are covered, and
+/// only those defined by [`UNICODE_VERSION`].
+///
+/// ## Example
+///
+/// ```
+/// let info = codex::catalog::char_info('→').unwrap();
+/// assert_eq!(info.name, "RIGHTWARDS ARROW");
+/// assert_eq!(info.category, "Sm");
+/// assert_eq!(info.block, "Arrows");
+/// assert_eq!(info.age, (1, 1));
+/// ```
+#[cfg(feature = "unicode-data")]
+pub fn char_info(c: char) -> Option<CharInfo> {
+    let i = CHARS.binary_search_by_key(&c, |data| data.c).ok()?;
+    Some(CHARS[i].info())
+}
+
+/// Returns the class of a value in mathematical formulas.
+///
+/// This is the class of the first character of the value, which may be
+/// followed by variation selectors and combining marks, such as in `≲\u{FE00}`
+/// or `≡\u{338}`. Returns `None` if the value is not of this form or if the
+/// class of the character is unknown. See [`char_info`].
+///
+/// ## Example
+///
+/// ```
+/// use codex::catalog::{MathClass, math_class};
+///
+/// assert_eq!(math_class("→"), Some(MathClass::Relation));
+/// assert_eq!(math_class("∑"), Some(MathClass::Large));
+/// assert_eq!(math_class("⟨"), Some(MathClass::Opening));
+/// assert_eq!(math_class("≲\u{FE00}"), Some(MathClass::Relation));
+/// ```
+#[cfg(feature = "unicode-data")]
+pub fn math_class(value: &str) -> Option<MathClass> {
+    char_info(base(value)?)?.math_class
+}
+
+/// Returns the first character of a value, if all the following ones are
+/// variation selectors or combining marks, which are displayed with it.
+///
+/// Without the `unicode-data` feature, only the combining marks of the
+/// diacritical marks blocks are recognized.
+#[cfg(any(feature = "unicode-data", feature = "numeral-systems"))]
+fn base(value: &str) -> Option<char> {
+    let mut chars = value.chars();
+    let c = chars.next()?;
+    chars
+        .all(|mark| {
+            let known = matches!(mark, '\u{300}'..='\u{36F}' | '\u{20D0}'..='\u{20F0}' | '\u{FE00}'..='\u{FE0F}');
+            #[cfg(feature = "unicode-data")]
+            let known = known
+                || char_info(mark).is_some_and(|info| matches!(info.category, "Mn" | "Me"));
+            known
+        })
+        .then_some(c)
+}
+
+/// Whether a value is a combining mark, which is displayed on the preceding
+/// character instead of on its own, such as `U+20DB` for `sym.dot.triple`.
+///
+/// Returns `false` if the value does not consist of a single character whose
+/// metadata is known. See [`char_info`].
+///
+/// ## Example
+///
+/// ```
+/// use codex::catalog::is_combining;
+///
+/// assert!(is_combining("\u{20DB}"));
+/// assert!(!is_combining("´"));
+/// ```
+#[cfg(feature = "unicode-data")]
+pub fn is_combining(value: &str) -> bool {
+    let mut chars = value.chars();
+    let (Some(c), None) = (chars.next(), chars.next()) else { return false };
+    char_info(c).is_some_and(|info| matches!(info.category, "Mn" | "Me"))
+}
+
+/// Returns the combining mark placing an accent on a base character, such as
+/// `U+0301` for the acute accent `´`.
+///
+/// The accent is a spacing character, such as the value of `sym.acute`, or a
+/// symbol used as an accent in formulas, such as the arrow `→`. With the
+/// `unicode-data` feature, combining marks are returned as is. Returns `None`
+/// if the value does not consist of a single such character.
+///
+/// ## Example
+///
+/// ```
+/// use codex::catalog::to_combining;
+///
+/// assert_eq!(to_combining("´"), Some('\u{301}'));
+/// assert_eq!(to_combining("→"), Some('\u{20D7}'));
+/// # #[cfg(feature = "unicode-data")]
+/// assert_eq!(to_combining("\u{20DB}"), Some('\u{20DB}'));
+/// assert_eq!(to_combining("+"), None);
+/// ```
+pub fn to_combining(value: &str) -> Option<char> {
+    let mut chars = value.chars();
+    let (Some(c), None) = (chars.next(), chars.next()) else { return None };
+    match ACCENTS.binary_search_by_key(&c, |&(accent, _)| accent) {
+        Ok(i) => Some(ACCENTS[i].1),
+        #[cfg(feature = "unicode-data")]
+        Err(_) => is_combining(value).then_some(c),
+        #[cfg(not(feature = "unicode-data"))]
+        Err(_) => None,
+    }
+}
+
+/// Returns the accent corresponding to a combining mark, such as the acute
+/// accent `´` for `U+0301`.
+///
+/// When several accents correspond to the combining mark, the one used by
+/// the default variant of its symbol is returned, such as `∼` rather than `~`
+/// for `U+0303`. See [`to_combining`].
+///
+/// ## Example
+///
+/// ```
+/// use codex::catalog::from_combining;
+///
+/// assert_eq!(from_combining('\u{301}'), Some('´'));
+/// assert_eq!(from_combining('\u{303}'), Some('∼'));
+/// assert_eq!(from_combining('a'), None);
+/// ```
+pub fn from_combining(c: char) -> Option<char> {
+    let i = COMBINING.binary_search_by_key(&c, |&(mark, _)| mark).ok()?;
+    Some(COMBINING[i].1)
+}
+
+/// Returns how a value is displayed, if it can be displayed both as text and
+/// as an emoji.
+///
+/// This is the presentation selected by the variation selector following the
+/// character of the value, or the default presentation of the character if
+/// there is none. Returns `None` if the value does not consist of a single
+/// such character, optionally followed by a variation selector.
+///
+/// ## Example
+///
+/// ```
+/// use codex::catalog::{Presentation, presentation};
+///
+/// assert_eq!(presentation("♀"), Some(Presentation::Text));
+/// assert_eq!(presentation("♀\u{FE0F}"), Some(Presentation::Emoji));
+/// assert_eq!(presentation("→"), None);
+/// ```
+#[cfg(feature = "unicode-data")]
+pub fn presentation(value: &str) -> Option<Presentation> {
+    let (c, selected) = split_presentation(value)?;
+    selected.or(char_info(c)?.presentation)
+}
+
+/// Returns a value with the given presentation, by replacing or appending
+/// its variation selector.
+///
+/// Returns `None` if the value does not consist of a single character that can
+/// be displayed both as text and as an emoji, optionally followed by a
+/// variation selector. See [`presentation`].
+///
+/// ## Example
+///
+/// ```
+/// use codex::catalog::{Presentation, resolve, with_presentation};
+///
+/// let (venus, _) = resolve("sym.venus").unwrap();
+/// assert_eq!(venus, "♀\u{FE0E}");
+/// assert_eq!(with_presentation(venus, Presentation::Emoji).as_deref(), Some("♀\u{FE0F}"));
+/// assert_eq!(with_presentation("→", Presentation::Emoji), None);
+/// ```
+#[cfg(feature = "unicode-data")]
+pub fn with_presentation(value: &str, presentation: Presentation) -> Option<String> {
+    let (c, _) = split_presentation(value)?;
+    char_info(c)?.presentation?;
+    Some([c, presentation.selector()].into_iter().collect())
+}
+
+/// Splits a value into its single character and the presentation selected by
+/// the variation selector following it, if any.
+#[cfg(feature = "unicode-data")]
+fn split_presentation(value: &str) -> Option<(char, Option<Presentation>)> {
+    let mut chars = value.chars();
+    let c = chars.next()?;
+    let selected = match chars.next() {
+        None => None,
+        Some('\u{FE0E}') => Some(Presentation::Text),
+        Some('\u{FE0F}') => Some(Presentation::Emoji),
+        Some(_) => return None,
+    };
+    if chars.next().is_some() {
+        return None;
+    }
+    Some((c, selected))
+}
+
+/// Returns the version of Unicode in which all the characters of a value were
+/// introduced, that is, the oldest version of Unicode in which the value can
+/// be displayed.
+///
+/// Returns `None` if the metadata of one of the characters is unknown. See
+/// [`char_info`].
+///
+/// ## Example
+///
+/// ```
+/// use codex::catalog::unicode_version;
+///
+/// assert_eq!(unicode_version("→"), Some((1, 1)));
+/// assert_eq!(unicode_version("⃀"), Some((14, 0)));
+/// ```
+#[cfg(feature = "unicode-data")]
+pub fn unicode_version(value: &str) -> Option<(u8, u8)> {
+    let mut version = None;
+    for c in value.chars() {
+        version = version.max(Some(char_info(c)?.age));
+    }
+    version
+}
+
+/// Returns the names and values of the variants whose characters all lie in a
+/// range of code points, in lexicographic order of the names.
+///
+/// Variation selectors are ignored, as they do not need to be rendered by
+/// fonts. Deprecated names are excluded. This allows auditing which symbols a
+/// font covering a range can render.
+///
+/// ## Example
+///
+/// ```
+/// let arrows: Vec<_> = codex::catalog::in_range('←'..='⇿').collect();
+/// assert!(arrows.contains(&("sym.arrow.r", "→")));
+/// assert!(!arrows.contains(&("sym.arrow.r.long", "⟶")));
+/// ```
+pub fn in_range(
+    range: impl RangeBounds<char>,
+) -> impl Iterator<Item = (&'static str, &'static str)> {
+    iter().filter(move |&(_, value)| covers(value, |c| range.contains(&c)))
+}
+
+/// Returns the names and values of the variants whose characters all belong
+/// to a Unicode block, such as `Arrows`, in lexicographic order of the names.
+///
+/// Variation selectors are ignored, and deprecated names are excluded. See
+/// [`in_range`] and [`blocks`].
+///
+/// ## Example
+///
+/// ```
+/// let arrows: Vec<_> = codex::catalog::in_block("Supplemental Arrows-A").collect();
+/// assert!(arrows.contains(&("sym.arrow.r.long", "⟶")));
+/// assert!(!arrows.contains(&("sym.arrow.r", "→")));
+/// ```
+#[cfg(feature = "unicode-data")]
+pub fn in_block(block: &str) -> impl Iterator<Item = (&'static str, &'static str)> {
+    iter().filter(move |&(_, value)| {
+        covers(value, |c| char_info(c).is_some_and(|info| info.block == block))
+    })
+}
+
+/// Returns the names of the Unicode blocks containing the characters used by
+/// the symbols, in lexicographic order.
+///
+/// ## Example
+///
+/// ```
+/// assert!(codex::catalog::blocks().any(|block| block == "Mathematical Operators"));
+/// ```
+#[cfg(feature = "unicode-data")]
+pub fn blocks() -> impl ExactSizeIterator<Item = &'static str> {
+    BLOCKS.iter().copied()
+}
+
+/// Whether all the characters of a value satisfy a predicate, ignoring
+/// variation selectors.
+fn covers(value: &str, f: impl FnMut(char) -> bool) -> bool {
+    !value.is_empty()
+        && (value.chars())
+            .filter(|c| !matches!(c, '\u{FE0E}' | '\u{FE0F}'))
+            .all(f)
+}
+
+/// Returns the confusable skeleton of a string, as defined by [Unicode
+/// Technical Standard #39][tr39].
+///
+/// Each character is replaced by the prototype it can be confused with, such
+/// as `A` for the Greek capital letter `Α`, and variation selectors are
+/// removed. Two strings whose skeletons are equal look alike. Only the
+/// characters used by the symbols and those of the Basic Latin block are
+/// mapped. As specified, the string is put in canonical decomposition before
+/// and after the mapping, so canonically equivalent strings have the same
+/// skeleton. See [`is_equivalent`].
+///
+/// [tr39]: https://www.unicode.org/reports/tr39/#Confusable_Detection
+///
+/// ## Example
+///
+/// ```
+/// use codex::catalog::skeleton;
+///
+/// assert_eq!(skeleton("Α"), "A");
+/// assert_eq!(skeleton("x−1"), "x-l");
+/// ```
+#[cfg(feature = "unicode-data")]
+pub fn skeleton(s: &str) -> String {
+    let mut skeleton = String::with_capacity(s.len());
+    for c in decompose(s).chars() {
+        match CONFUSABLES.binary_search_by_key(&c, |&(c, _)| c) {
+            Ok(i) => skeleton.push_str(CONFUSABLES[i].1),
+            Err(_) if matches!(c, '\u{FE0E}' | '\u{FE0F}') => {}
+            Err(_) => skeleton.push(c),
+        }
+    }
+    decompose(&skeleton)
+}
+
+/// Whether two different strings look alike, that is, have the same
+/// [`skeleton`].
+///
+/// ## Example
+///
+/// ```
+/// use codex::catalog::is_confusable;
+///
+/// assert!(is_confusable("Α", "A"));
+/// assert!(is_confusable("∣", "|"));
+/// assert!(!is_confusable("A", "A"));
+/// assert!(!is_confusable("α", "β"));
+/// ```
+#[cfg(feature = "unicode-data")]
+pub fn is_confusable(a: &str, b: &str) -> bool {
+    a != b && skeleton(a) == skeleton(b)
+}
+
+/// Returns the full names of the variants whose values look like a character
+/// but are different from it, in lexicographic order.
+///
+/// This allows linters to warn when a document mixes characters that look
+/// alike, such as the Greek capital letter `Α` and the Latin letter `A`.
+/// Deprecated names are excluded. See [`skeleton`].
+///
+/// ## Example
+///
+/// ```
+/// use codex::catalog::confusables;
+///
+/// assert_eq!(confusables('A'), ["sym.Alpha"]);
+/// assert!(confusables('-').contains(&"sym.minus"));
+/// ```
+#[cfg(feature = "unicode-data")]
+pub fn confusables(c: char) -> Vec<&'static str> {
+    let mut buf = [0; 4];
+    let c = c.encode_utf8(&mut buf);
+    let skeleton = skeleton(c);
+    NAMES
+        .iter()
+        .filter(|&&(_, value, _)| {
+            value.trim_end_matches(['\u{FE0E}', '\u{FE0F}']) != c
+                && self::skeleton(value) == skeleton
+        })
+        .map(|&(name, _, _)| name)
+        .collect()
+}
+
+/// Returns the full name of the uppercase counterpart of a variant, such as
+/// `sym.Alpha` for `sym.alpha`.
+///
+/// Variants are linked when their names only differ in the case of the first
+/// letter of their symbol, and their values are a lowercase and an uppercase
+/// letter. The returned name is canonical. With the `numeral-systems`
+/// feature, the numerals of systems that exist in both cases are linked as
+/// well, such as `roman.upper.hundred` for `roman.lower.hundred`. See
+/// `numeral`.
+///
+/// Returns `None` if the name cannot be resolved or the variant has no
+/// uppercase counterpart.
+///
+/// ## Example
+///
+/// ```
+/// use codex::catalog::to_uppercase;
+///
+/// assert_eq!(to_uppercase("sym.alpha"), Some("sym.Alpha"));
+/// assert_eq!(to_uppercase("sym.theta.alt"), Some("sym.Theta.alt"));
+/// assert_eq!(to_uppercase("sym.Alpha"), None);
+/// assert_eq!(to_uppercase("sym.arrow.r"), None);
+/// ```
+pub fn to_uppercase(name: &str) -> Option<&'static str> {
+    #[cfg(feature = "numeral-systems")]
+    if let Some(numeral) = numerals::with_case(name, ("lower", "upper")) {
+        return Some(numeral);
+    }
+    linked(name, UPPERCASE)
+}
+
+/// Returns the full name of the lowercase counterpart of a variant, such as
+/// `sym.alpha` for `sym.Alpha`.
+///
+/// See [`to_uppercase`] for which variants are linked.
+///
+/// ## Example
+///
+/// ```
+/// use codex::catalog::to_lowercase;
+///
+/// assert_eq!(to_lowercase("sym.Omega"), Some("sym.omega"));
+/// assert_eq!(to_lowercase("sym.omega"), None);
+/// ```
+pub fn to_lowercase(name: &str) -> Option<&'static str> {
+    #[cfg(feature = "numeral-systems")]
+    if let Some(numeral) = numerals::with_case(name, ("upper", "lower")) {
+        return Some(numeral);
+    }
+    linked(name, LOWERCASE)
+}
+
+/// Looks the variant linked to a variant up in a table of indices into
+/// [`NAMES`], sorted by their first element.
+fn linked(name: &str, table: &[(u16, u16)]) -> Option<&'static str> {
+    let name = canonicalize(name).ok()?;
+    let i = NAMES
+        .binary_search_by_key(&name.as_str(), |&(name, _, _)| name)
+        .ok()?;
+    let j = table.binary_search_by_key(&(i as u16), |&(i, _)| i).ok()?;
+    Some(NAMES[usize::from(table[j].1)].0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        ACCENTS, ALIASES, COMBINING, Child, HINTS, HTML_ENTITIES, HTML_VALUES, LOWERCASE,
+        Mode, NAMES, ResolveError, UPPERCASE, canonical_name, canonicalize, children,
+        complete, deprecation, distance, from_combining, from_html_entity, in_range,
+        iter, iter_by_value, lookup, names_for, parent, rendering_hints, resolve, search,
+        to_combining, to_html_entity, to_lowercase, to_uppercase,
+    };
+    #[cfg(feature = "unicode-data")]
+    use super::{
+        CHARS, COMBINING_CLASSES, CONFUSABLES, DECOMPOSED, DECOMPOSITIONS, MathClass,
+        Presentation, blocks, char_info, confusables, decompose, in_block, is_combining,
+        is_confusable, is_equivalent, math_class, presentation, skeleton,
+        unicode_version, with_presentation,
+    };
+    use crate::{Def, Module, ROOT};
+
+    #[test]
+    fn test_iter() {
+        fn walk(module: Module, path: &str, variants: &mut Vec<(String, &str)>) {
+            for (name, binding) in module.iter() {
+                if binding.deprecation.is_some() {
+                    continue;
+                }
+                let path = format!("{path}{name}");
+                match binding.def {
+                    Def::Module(inner) => walk(inner, &format!("{path}."), variants),
+                    Def::Symbol(symbol) => {
+                        for (modifiers, value, deprecation) in symbol.variants() {
+                            if deprecation.is_none() {
+                                variants.push((canonical_name(&path, modifiers), value));
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        let mut expected = vec![];
+        walk(ROOT, "", &mut expected);
+        expected.sort();
+        let variants: Vec<_> =
+            iter().map(|(name, value)| (name.to_string(), value)).collect();
+        assert_eq!(variants, expected);
+    }
+
+    #[cfg(feature = "latex")]
+    #[test]
+    fn test_latex() {
+        use super::{LATEX, LATEX_COMMANDS, from_latex, to_latex};
+
+        for &(name, command) in LATEX {
+            assert_eq!(from_latex(command), Some(name));
+        }
+        for &(command, name) in LATEX_COMMANDS {
+            assert!(to_latex(name).is_some(), "{command}");
+        }
+        assert_eq!(to_latex("sym.lt.eq"), Some("\\leq"));
+        assert_eq!(to_latex("sym.arrow.long.l"), Some("\\longleftarrow"));
+        assert_eq!(from_latex("\\le"), Some("sym.lt.eq"));
+        assert_eq!(from_latex("\\{"), Some("sym.brace.l"));
+        assert_eq!(from_latex("\\alpha"), Some("sym.alpha"));
+        assert_eq!(from_latex("alpha"), None);
+        assert_eq!(to_latex("sym.unknown"), None);
+    }
+
+    #[test]
+    fn test_html_entity() {
+        for &(value, entity) in HTML_ENTITIES {
+            assert_eq!(from_html_entity(entity), Some(value));
+        }
+        for &(entity, value) in HTML_VALUES {
+            assert!(to_html_entity(value).is_some(), "{entity}");
+            assert!(
+                names_for(value)
+                    .chain(names_for(&format!("{value}\u{FE0E}")))
+                    .chain(names_for(&format!("{value}\u{FE0F}")))
+                    .next()
+                    .is_some(),
+                "{entity}"
+            );
+        }
+        assert_eq!(to_html_entity("&"), Some("&amp;"));
+        assert_eq!(to_html_entity("\u{A0}"), Some("&nbsp;"));
+        assert_eq!(to_html_entity("↔\u{FE0E}"), Some("&harr;"));
+        assert_eq!(from_html_entity("&LeftArrow;"), Some("←"));
+        assert_eq!(from_html_entity("&larr"), None);
+        assert_eq!(from_html_entity("&unknown;"), None);
+    }
+
+    #[cfg(feature = "unicode-data")]
+    #[test]
+    fn test_confusables() {
+        for &(c, prototype) in CONFUSABLES {
+            assert_eq!(skeleton(&c.to_string()), prototype);
+            assert_eq!(skeleton(prototype), prototype);
+        }
+        assert_eq!(skeleton("♀\u{FE0E}"), "♀");
+        assert!(is_confusable("∑", "Σ"));
+        assert!(is_confusable("ℓ", "1"));
+        assert!(!is_confusable("→", "→"));
+        assert!(confusables('|').contains(&"sym.divides"));
+        assert!(!confusables('|').contains(&"sym.bar.v"));
+        assert!(confusables('→').is_empty());
+        for name in confusables('l') {
+            assert_eq!(skeleton(resolve(name).unwrap().0), "l", "{name}");
+        }
+    }
+
+    #[test]
+    fn test_children() {
+        let modules = children("").unwrap();
+        let expected: Vec<_> =
+            ROOT.iter().map(|(name, _)| Child::Module(name.into())).collect();
+        assert_eq!(modules, expected);
+        let sym = children("sym").unwrap();
+        assert!(sym.contains(&Child::Module("sym.chess".into())));
+        assert!(sym.contains(&Child::Symbol("sym.alpha".into())));
+        assert_eq!(
+            children("sym.wj").unwrap(),
+            [Child::Variant("sym.wj".into(), "\u{2060}")]
+        );
+        // Deprecated variants are excluded.
+        assert!(
+            !children("sym.prec")
+                .unwrap()
+                .iter()
+                .any(|c| c.name() == "sym.prec.curly.eq")
+        );
+        assert_eq!(children("sym.arrow.r"), None);
+        assert_eq!(children("sym.unknown"), None);
+        // The children of all modules and symbols cover the whole catalog.
+        let mut stack = vec![String::new()];
+        let mut variants = vec![];
+        while let Some(name) = stack.pop() {
+            for child in children(&name).unwrap() {
+                if child.name() != name {
+                    assert_eq!(parent(child.name()), Some(name.as_str()));
+                }
+                match child {
+                    Child::Module(name) | Child::Symbol(name) => stack.push(name),
+                    Child::Variant(name, value) => variants.push((name, value)),
+                }
+            }
+        }
+        variants.sort();
+        let expected: Vec<_> = iter().map(|(n, v)| (n.to_string(), v)).collect();
+        assert_eq!(variants, expected);
+    }
+
+    #[test]
+    fn test_parent() {
+        assert_eq!(parent("sym.arrow.r.long"), Some("sym.arrow"));
+        assert_eq!(parent("sym.wj"), Some("sym"));
+        assert_eq!(parent("sym"), Some(""));
+        assert_eq!(parent(""), None);
+        assert_eq!(parent("sym.arrow.unknown"), None);
+        assert_eq!(parent("unknown"), None);
+    }
+
+    #[test]
+    fn test_names_for() {
+        assert!(names_for("→").any(|name| name == "sym.arrow.r"));
+        assert!(names_for("⟦").any(|name| name == "sym.bracket.l.stroked"));
+        #[cfg(feature = "emoji")]
+        assert!(names_for("🚀").any(|name| name == "emoji.rocket"));
+        assert_eq!(names_for("not a symbol").count(), 0);
+        // Deprecated names are excluded.
+        assert!(names_for("≼").all(|name| name != "sym.prec.curly.eq"));
+        // Values can consist of several code points.
+        for &(name, value, _) in NAMES {
+            if value.chars().nth(1).is_some() {
+                assert!(names_for(value).any(|n| n == name), "{name}");
+                assert_eq!(resolve(name), Ok((value, None)));
+            }
+        }
+        assert!(names_for("≲\u{FE00}").any(|name| name == "sym.lt.tilde.slant"));
+        // Values are compared up to canonical equivalence.
+        #[cfg(feature = "unicode-data")]
+        {
+            assert_eq!(
+                names_for("=\u{338}").collect::<Vec<_>>(),
+                names_for("≠").collect::<Vec<_>>()
+            );
+            assert!(names_for("A\u{30A}").any(|name| name == "sym.angstrom"));
+        }
+    }
+
+    #[test]
+    fn test_rendering_hints() {
+        assert!(HINTS.is_sorted_by_key(|&(name, _, _)| name));
+        for &(name, mode, features) in HINTS {
+            let hints = rendering_hints(name).unwrap();
+            assert_eq!((hints.mode, hints.features), (mode, features));
+        }
+        assert_eq!(rendering_hints("sym.integral.double").unwrap().mode, Mode::Math);
+        assert_eq!(rendering_hints("sym.prime.rev.double").unwrap().features, ["ssty"]);
+        assert!(rendering_hints("sym.arrow.r").unwrap().features.is_empty());
+        #[cfg(feature = "emoji")]
+        assert_eq!(rendering_hints("emoji.rocket").unwrap().mode, Mode::Text);
+        assert_eq!(rendering_hints("sym.unknown"), None);
+    }
+
+    #[test]
+    fn test_iter_by_value() {
+        assert_eq!(iter_by_value().len(), iter().len());
+        let variants: Vec<_> = iter_by_value().collect();
+        assert!(variants.is_sorted_by(|(n1, v1), (n2, v2)| {
+            let chars = |v: &str| v.chars().collect::<Vec<_>>();
+            (chars(v1), n1.len(), n1) <= (chars(v2), n2.len(), n2)
+        }));
+    }
+
+    #[test]
+    fn test_case() {
+        assert!(UPPERCASE.is_sorted() && LOWERCASE.is_sorted());
+        assert_eq!(UPPERCASE.len(), LOWERCASE.len());
+        for &(i, j) in UPPERCASE {
+            let (lower, upper) = (NAMES[usize::from(i)].0, NAMES[usize::from(j)].0);
+            assert!(lower.eq_ignore_ascii_case(upper));
+            assert_eq!(to_uppercase(lower), Some(upper));
+            assert_eq!(to_lowercase(upper), Some(lower));
+        }
+        assert_eq!(to_uppercase("sym.theta.alt"), Some("sym.Theta.alt"));
+        assert_eq!(to_uppercase("sym.Im"), None);
+        assert_eq!(to_lowercase("sym.CC"), None);
+        #[cfg(feature = "emoji")]
+        assert_eq!(to_uppercase("emoji.abcd"), None);
+        #[cfg(feature = "numeral-systems")]
+        assert_eq!(to_lowercase("letter.latin.upper.1"), Some("letter.latin.lower.1"));
+    }
+
+    #[cfg(feature = "unicode-data")]
+    #[test]
+    fn test_is_equivalent() {
+        assert!(DECOMPOSITIONS.is_sorted() && COMBINING_CLASSES.is_sorted());
+        assert!(DECOMPOSED.is_sorted_by_key(|&(value, _)| value));
+        for &(value, i) in DECOMPOSED {
+            assert!(is_equivalent(value, NAMES[usize::from(i)].1));
+            assert_eq!(decompose(value), value);
+        }
+        assert!(is_equivalent("Å", "Å"));
+        assert!(is_equivalent("ǻ", "a\u{30A}\u{301}"));
+        assert!(!is_equivalent("a\u{301}\u{30A}", "a\u{30A}\u{301}"));
+        assert_eq!(skeleton("Å"), skeleton("A\u{30A}"));
+    }
+
+    #[test]
+    fn test_search() {
+        let matches = search("integral");
+        assert_eq!(matches[0].name, "sym.integral");
+        assert_eq!(matches[0].description, Some("Calculus."));
+        assert!(matches.iter().any(|m| m.name == "sym.integral.double"));
+        assert!(matches.windows(2).all(|w| w[0].score >= w[1].score));
+
+        assert_eq!(search("arrow.r")[0].name, "sym.arrow.r");
+        assert_eq!(search("Arrow Double R")[0].name, "sym.arrow.r.double");
+        assert_eq!(search("intgrl")[0].name, "sym.integral");
+        assert!(search("calculus").iter().any(|m| m.name == "sym.integral"));
+        assert!(search("line feed").iter().any(|m| m.name == "sym.control.lf"));
+        assert!(search("alpha").iter().any(|m| m.name == "sym.Alpha"));
+        #[cfg(feature = "emoji")]
+        assert!(search("rocket").iter().any(|m| m.name == "emoji.rocket"));
+        assert!(search("").is_empty());
+        assert!(search("zzzzzz").is_empty());
+    }
+
+    #[test]
+    fn test_complete() {
+        let names: Vec<_> = complete("sym.arrow.r.").collect();
+        assert!(names.contains(&"sym.arrow.r.long"));
+        assert!(!names.contains(&"sym.arrow.r"));
+        assert!(names.is_sorted());
+        assert!(complete("sym.control.").any(|name| name == "sym.control.dc.one"));
+        #[cfg(feature = "emoji")]
+        assert_eq!(complete("emoji.rocke").collect::<Vec<_>>(), ["emoji.rocket"]);
+        assert_eq!(complete("sym.zzz").count(), 0);
+        assert_eq!(complete("").count(), super::NAMES.len());
+    }
+
+    #[test]
+    fn test_resolve() {
+        assert_eq!(
+            resolve("sym.arrow.r.long.double"),
+            resolve("sym.arrow.double.long.r")
+        );
+        assert_eq!(resolve("sym.wj"), Ok(("\u{2060}", None)));
+        assert_eq!(resolve("sym.control.dc.one"), Ok(("␑", None)));
+        assert!(resolve("sym.prec.eq.curly").unwrap().1.is_some());
+        assert!(resolve("sym.wj.long").is_err());
+        assert!(resolve("sym.arrow..r").is_err());
+        assert!(resolve("sym.arrow.").is_err());
+        assert!(resolve("sym.control").is_err());
+        assert!(resolve("sym").is_err());
+        assert!(resolve("").is_err());
+    }
+
+    #[test]
+    fn test_canonicalize() {
+        assert_eq!(canonicalize("sym.arrow.long.r").as_deref(), Ok("sym.arrow.r.long"));
+        assert_eq!(canonicalize("sym.wj").as_deref(), Ok("sym.wj"));
+        assert_eq!(canonicalize("sym.space").as_deref(), Ok("sym.space"));
+        assert_eq!(
+            canonicalize("sym.control.dc.one").as_deref(),
+            Ok("sym.control.dc.one")
+        );
+        // Every name in the catalog is canonical, and the table of names
+        // agrees with the modules.
+        for &(name, value, _) in NAMES {
+            let (symbol, (modifiers, found, deprecation)) = lookup(name).unwrap();
+            assert_eq!(canonical_name(symbol, modifiers), name);
+            assert_eq!((found, deprecation), (value, None));
+        }
+    }
+
+    #[test]
+    fn test_deprecation() {
+        assert!(ALIASES.is_sorted());
+        for &(alias, target) in ALIASES {
+            assert_eq!(resolve(alias).unwrap().0, resolve(target).unwrap().0);
+            let deprecation = deprecation(alias).unwrap().unwrap();
+            assert_eq!(deprecation.replacement.as_deref(), Some(target));
+            assert_eq!(super::deprecation(target), Ok(None));
+        }
+        let deprecation = deprecation("sym.prec.not.eq.curly").unwrap().unwrap();
+        assert_eq!(deprecation.replacement.as_deref(), Some("sym.prec.eq.slant.not"));
+        assert_eq!(super::deprecation("sym.arrow.r"), Ok(None));
+        assert!(super::deprecation("sym.arow").is_err());
+    }
+
+    #[test]
+    fn test_suggestions() {
+        let unknown_name = |suggestions: &[&str]| ResolveError::UnknownName {
+            suggestions: suggestions.iter().map(|s| s.to_string()).collect(),
+        };
+        let unknown_modifiers = |suggestions: &[&str]| ResolveError::UnknownModifiers {
+            suggestions: suggestions.iter().map(|s| s.to_string()).collect(),
+        };
+        assert_eq!(resolve("sym.arow.r"), Err(unknown_name(&["sym.arrow.r"])));
+        assert_eq!(resolve("sym.arow"), Err(unknown_name(&["sym.arrow"])));
+        assert_eq!(resolve("sym.contrl.nul"), Err(unknown_name(&["sym.control.nul"])));
+        assert_eq!(resolve("syn.wj"), Err(unknown_name(&["sym.wj"])));
+        assert_eq!(resolve("sym.qwertyuiop"), Err(unknown_name(&[])));
+        assert_eq!(
+            resolve("sym.arrow.r.lnog").unwrap_err().suggestions()[0],
+            "sym.arrow.r.long"
+        );
+        assert_eq!(resolve("sym.arrow..r").unwrap_err().suggestions()[0], "sym.arrow.r");
+        assert_eq!(resolve("sym.wj.x"), Err(unknown_modifiers(&["sym.wj"])));
+        assert_eq!(resolve("sym.arrow.qwertyuiop"), Err(unknown_modifiers(&[])));
+    }
+
+    #[test]
+    fn test_distance() {
+        assert_eq!(distance("", ""), 0);
+        assert_eq!(distance("arrow", "arrow"), 0);
+        assert_eq!(distance("arow", "arrow"), 1);
+        assert_eq!(distance("lnog", "long"), 1);
+        assert_eq!(distance("kitten", "sitting"), 3);
+        assert_eq!(distance("", "abc"), 3);
+    }
+
+    #[test]
+    fn test_in_range() {
+        let latin: Vec<_> = in_range('\0'..='\u{7F}').collect();
+        assert!(latin.contains(&("sym.excl", "!")));
+        assert!(latin.iter().all(|(_, value)| {
+            value.trim_end_matches(['\u{FE0E}', '\u{FE0F}']).is_ascii()
+        }));
+        assert_eq!(in_range('a'..'a').count(), 0);
+        assert!(in_range('♀'..='♀').any(|(name, _)| name == "sym.venus"));
+    }
+
+    #[cfg(feature = "unicode-data")]
+    #[test]
+    fn test_in_block() {
+        assert!(blocks().is_sorted());
+        for block in blocks() {
+            for (name, value) in in_block(block) {
+                assert!(
+                    value.chars().any(|c| char_info(c).unwrap().block == block),
+                    "{name}"
+                );
+            }
+        }
+        assert_eq!(in_block("Unknown").count(), 0);
+    }
+
+    #[test]
+    fn test_accents() {
+        assert!(ACCENTS.is_sorted() && COMBINING.is_sorted());
+        for &(accent, mark) in ACCENTS {
+            let accent = accent.to_string();
+            #[cfg(feature = "unicode-data")]
+            assert!(!is_combining(&accent), "{accent}");
+            assert_eq!(to_combining(&accent), Some(mark));
+            let preferred = from_combining(mark).unwrap();
+            assert_eq!(to_combining(&preferred.to_string()), Some(mark));
+        }
+        assert_eq!(from_combining('\u{307}'), Some('⋅'));
+        assert_eq!(from_combining('\u{20DC}'), None);
+        assert_eq!(to_combining("´´"), None);
+        #[cfg(feature = "unicode-data")]
+        {
+            assert_eq!(to_combining("\u{20DC}"), Some('\u{20DC}'));
+            assert!(!is_combining(""));
+        }
+    }
+
+    #[cfg(feature = "unicode-data")]
+    #[test]
+    fn test_char_info() {
+        assert!(CHARS.is_sorted_by_key(|data| data.c));
+        let info = char_info('∫').unwrap();
+        assert_eq!(
+            (info.name, info.category, info.block),
+            ("INTEGRAL", "Sm", "Mathematical Operators")
+        );
+        assert_eq!(char_info('\u{FE0E}').unwrap().name, "VARIATION SELECTOR-15");
+        assert_eq!(char_info('a'), None);
+        assert_eq!(unicode_version("⊭\u{FE0E}"), Some((3, 2)));
+        assert_eq!(unicode_version(""), None);
+        assert_eq!(char_info('\u{20C1}').unwrap().age, (17, 0));
+    }
+
+    #[cfg(feature = "unicode-data")]
+    #[test]
+    fn test_char_info_coverage() {
+        for (name, value) in iter() {
+            for c in value.chars() {
+                assert!(char_info(c).is_some(), "{name}: U+{:04X}", c as u32);
+            }
+        }
+    }
+
+    #[cfg(feature = "unicode-data")]
+    #[test]
+    fn test_math_class() {
+        for (value, class) in [
+            ("+", MathClass::Vary),
+            ("×", MathClass::Binary),
+            ("=", MathClass::Relation),
+            ("⇒", MathClass::Relation),
+            ("∫", MathClass::Large),
+            ("(", MathClass::Opening),
+            ("⌋", MathClass::Closing),
+            ("|", MathClass::Fence),
+            ("¬", MathClass::Unary),
+            ("α", MathClass::Alphabetic),
+            ("∞", MathClass::Normal),
+            (",", MathClass::Punctuation),
+            ("\u{20DB}", MathClass::Diacritic),
+        ] {
+            assert_eq!(math_class(value), Some(class), "{value}");
+        }
+        assert_eq!(math_class("🚀"), None);
+        assert_eq!(math_class("=="), None);
+        assert_eq!(math_class("≡\u{338}"), Some(MathClass::Relation));
+        assert_eq!(math_class("\u{338}"), None);
+        assert_eq!(math_class(""), None);
+    }
+
+    #[cfg(feature = "unicode-data")]
+    #[test]
+    fn test_presentation() {
+        assert_eq!(presentation("♀"), Some(Presentation::Text));
+        assert_eq!(presentation("♀\u{FE0F}"), Some(Presentation::Emoji));
+        assert_eq!(presentation("→"), None);
+        assert_eq!(presentation("♀♀"), None);
+        assert_eq!(presentation(""), None);
+        assert_eq!(
+            with_presentation("♀", Presentation::Text).as_deref(),
+            Some("♀\u{FE0E}")
+        );
+        #[cfg(feature = "emoji")]
+        {
+            assert_eq!(presentation("⌚"), Some(Presentation::Emoji));
+            assert_eq!(
+                with_presentation("⌚\u{FE0F}", Presentation::Text).as_deref(),
+                Some("⌚\u{FE0E}"),
+            );
+        }
+        assert_eq!(with_presentation("→", Presentation::Text), None);
+        assert_eq!(with_presentation("→\u{FE0E}", Presentation::Emoji), None);
+        for &(name, value, _) in NAMES {
+            if let Some(presentation) = presentation(value) {
+                let other = match presentation {
+                    Presentation::Text => Presentation::Emoji,
+                    Presentation::Emoji => Presentation::Text,
+                };
+                let swapped = with_presentation(value, other).unwrap();
+                assert_eq!(super::presentation(&swapped), Some(other), "{name}");
+            }
+        }
+    }
+
+    /// Checks the metadata against the latest version of the Unicode
+    /// Character Database.
+    #[cfg(feature = "_test-unicode-conformance")]
+    #[test]
+    fn char_info_conforms() {
+        let data = include_str!(concat!(env!("OUT_DIR"), "/UnicodeData-17.0.0.txt"));
+        let mut entries = std::collections::HashMap::new();
+        for line in data.lines() {
+            let fields: Vec<&str> = line.split(';').collect();
+            entries.insert(
+                u32::from_str_radix(fields[0], 16).unwrap(),
+                (fields[1], fields[2]),
+            );
+        }
+        for data in CHARS {
+            let (c, super::CharInfo { name, category, .. }) = (data.c, data.info());
+            let (expected_name, expected_category) = entries[&(c as u32)];
+            if !expected_name.starts_with('<') {
+                assert_eq!(name, expected_name, "name of U+{:04X}", c as u32);
+            }
+            assert_eq!(category, expected_category, "category of U+{:04X}", c as u32);
+        }
+    }
+
+    /// Checks the math classes against the latest version of Unicode
+    /// Technical Report #25.
+    #[cfg(feature = "_test-unicode-conformance")]
+    #[test]
+    fn math_class_conforms() {
+        let data = include_str!(concat!(env!("OUT_DIR"), "/MathClass-15.txt"));
+        let mut classes = std::collections::HashMap::new();
+        for line in data.lines() {
+            let line = line.split('#').next().unwrap().trim();
+            let Some((range, class)) = line.split_once(';') else { continue };
+            let (start, end) = range.split_once("..").unwrap_or((range, range));
+            let start = u32::from_str_radix(start, 16).unwrap();
+            let end = u32::from_str_radix(end, 16).unwrap();
+            for code in start..=end {
+                classes.insert(code, class.trim());
+            }
+        }
+        for data in CHARS {
+            let (c, info) = (data.c, data.info());
+            let Some(class) = info.math_class else { continue };
+            let expected = match classes.get(&(c as u32)) {
+                Some(&"N") | None => MathClass::Normal,
+                Some(&"A") => MathClass::Alphabetic,
+                Some(&"B") => MathClass::Binary,
+                Some(&"C") => MathClass::Closing,
+                Some(&"D") => MathClass::Diacritic,
+                Some(&"F") => MathClass::Fence,
+                Some(&"G") => MathClass::GlyphPart,
+                Some(&"L") => MathClass::Large,
+                Some(&"O") => MathClass::Opening,
+                Some(&"P") => MathClass::Punctuation,
+                Some(&"R") => MathClass::Relation,
+                Some(&"S") => MathClass::Space,
+                Some(&"U") => MathClass::Unary,
+                Some(&"V") => MathClass::Vary,
+                Some(_) => MathClass::Special,
+            };
+            assert_eq!(class, expected, "math class of U+{:04X}", c as u32);
+        }
+    }
+
+    /// Checks the presentations against the latest list of emoji variation
+    /// sequences.
+    #[cfg(feature = "_test-unicode-conformance")]
+    #[test]
+    fn presentation_conforms() {
+        let data =
+            include_str!(concat!(env!("OUT_DIR"), "/emoji-variation-sequences.txt"));
+        let mut expected = std::collections::HashSet::new();
+        for line in data.lines() {
+            let line = line.split('#').next().unwrap().trim();
+            let Some(code) = line.split_whitespace().next() else { continue };
+            expected.insert(u32::from_str_radix(code, 16).unwrap());
+        }
+        for data in CHARS {
+            let (c, info) = (data.c, data.info());
+            assert_eq!(
+                info.presentation.is_some(),
+                expected.contains(&(c as u32)),
+                "presentation of U+{:04X}",
+                c as u32,
+            );
+        }
+    }
+}
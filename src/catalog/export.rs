@@ -0,0 +1,297 @@
+//! Export of the catalog as JSON and CSV.
+
+use std::fmt::Write;
+
+use super::{ALIASES, NAMES, deprecation, to_html_entity};
+#[cfg(feature = "unicode-data")]
+use super::{MathClass, Presentation, math_class, presentation, unicode_version};
+
+/// The fields of an exported variant, in order.
+const FIELDS: [&str; 10] = [
+    "name",
+    "value",
+    "codepoints",
+    "description",
+    "aliases",
+    "math_class",
+    "unicode_version",
+    "presentation",
+    "latex",
+    "html_entity",
+];
+
+/// An exported variant of the catalog.
+struct Record {
+    name: &'static str,
+    value: &'static str,
+    description: Option<&'static str>,
+    aliases: Vec<String>,
+}
+
+impl Record {
+    /// Returns the fields that are not lists, from `math_class` onwards.
+    fn metadata(&self) -> [Option<String>; 5] {
+        #[cfg(feature = "latex")]
+        let latex = super::to_latex(self.name);
+        #[cfg(not(feature = "latex"))]
+        let latex: Option<&str> = None;
+        #[cfg(feature = "unicode-data")]
+        let (class, version, presentation) = (
+            math_class(self.value).map(class_name),
+            unicode_version(self.value),
+            presentation(self.value).map(presentation_name),
+        );
+        #[cfg(not(feature = "unicode-data"))]
+        let (class, version, presentation): (Option<&str>, Option<(u8, u8)>, _) =
+            (None, None, None);
+        [
+            class.map(str::to_string),
+            version.map(|(major, minor)| format!("{major}.{minor}")),
+            presentation.map(str::to_string),
+            latex.map(str::to_string),
+            to_html_entity(self.value).map(str::to_string),
+        ]
+    }
+}
+
+/// Returns the canonical variants of the catalog, in lexicographic order of
+/// their names.
+fn records() -> impl Iterator<Item = Record> {
+    NAMES.iter().map(|&(name, value, description)| Record {
+        name,
+        value,
+        description,
+        aliases: aliases_of(name),
+    })
+}
+
+/// Returns the deprecated aliases of a canonical full name.
+///
+/// Either the variant itself or its whole symbol can be aliased, so the
+/// candidates are checked against their [`deprecation`].
+fn aliases_of(name: &str) -> Vec<String> {
+    let mut aliases: Vec<String> = vec![];
+    for &(alias, target) in ALIASES {
+        let Some(rest) = name.strip_prefix(target) else { continue };
+        let candidate = format!("{alias}{rest}");
+        let replaced = deprecation(&candidate)
+            .is_ok_and(|d| d.and_then(|d| d.replacement).as_deref() == Some(name));
+        if (rest.is_empty() || rest.starts_with('.'))
+            && replaced
+            && !aliases.contains(&candidate)
+        {
+            aliases.push(candidate);
+        }
+    }
+    aliases
+}
+
+/// Exports the canonical variants of the catalog as a JSON array.
+///
+/// Each variant is described by an object with the following fields:
+/// - `name`: its canonical full name, such as `"sym.arrow.r"`.
+/// - `value`: its value, such as `"→"`.
+/// - `codepoints`: the code points of its value, such as `["U+2192"]`.
+/// - `description`: the description of the symbol, or `null`.
+/// - `aliases`: the deprecated full names resolving to the variant.
+/// - `math_class`: its class in formulas (see `math_class`), such as
+///   `"relation"`, or `null`.
+/// - `unicode_version`: the version of Unicode introducing its characters
+///   (see `unicode_version`), such as `"1.1"`, or `null`.
+/// - `presentation`: its presentation (see `presentation`), `"text"` or
+///   `"emoji"`, or `null`.
+/// - `latex`: its LaTeX command, such as `"\\rightarrow"`, or `null`. This is
+///   always `null` without the `latex` feature.
+/// - `html_entity`: its [HTML entity](super::to_html_entity), such as
+///   `"&rarr;"`, or `null`.
+///
+/// The `math_class`, `unicode_version`, and `presentation` fields are always
+/// `null` without the `unicode-data` feature.
+///
+/// This allows websites, cheatsheets, and tools in other languages to be
+/// generated from the data of this crate.
+pub fn export_json() -> String {
+    let mut out = String::from("[");
+    for (i, record) in records().enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        out.push_str("\n  {\"name\": ");
+        write_string(&mut out, record.name);
+        out.push_str(", \"value\": ");
+        write_string(&mut out, record.value);
+        out.push_str(", \"codepoints\": [");
+        for (j, c) in record.value.chars().enumerate() {
+            if j > 0 {
+                out.push_str(", ");
+            }
+            write_string(&mut out, &codepoint(c));
+        }
+        out.push_str("], \"description\": ");
+        write_optional(&mut out, record.description);
+        out.push_str(", \"aliases\": [");
+        for (j, alias) in record.aliases.iter().enumerate() {
+            if j > 0 {
+                out.push_str(", ");
+            }
+            write_string(&mut out, alias);
+        }
+        out.push(']');
+        for (field, value) in FIELDS[5..].iter().zip(record.metadata()) {
+            write!(out, ", \"{field}\": ").unwrap();
+            write_optional(&mut out, value.as_deref());
+        }
+        out.push('}');
+    }
+    out.push_str("\n]\n");
+    out
+}
+
+/// Exports the canonical variants of the catalog as CSV, with a header row.
+///
+/// The columns are the fields described in [`export_json`]. Lists are
+/// separated by spaces, and missing values are empty. Fields are quoted as
+/// specified by [RFC 4180][rfc], and lines end with `\r\n`.
+///
+/// [rfc]: https://www.rfc-editor.org/rfc/rfc4180
+pub fn export_csv() -> String {
+    let mut out = FIELDS.join(",");
+    out.push_str("\r\n");
+    for record in records() {
+        let codepoints: Vec<String> = record.value.chars().map(codepoint).collect();
+        let fields = [
+            Some(record.name.to_string()),
+            Some(record.value.to_string()),
+            Some(codepoints.join(" ")),
+            record.description.map(str::to_string),
+            Some(record.aliases.join(" ")),
+        ];
+        for (i, field) in fields.into_iter().chain(record.metadata()).enumerate() {
+            if i > 0 {
+                out.push(',');
+            }
+            write_field(&mut out, field.as_deref().unwrap_or(""));
+        }
+        out.push_str("\r\n");
+    }
+    out
+}
+
+/// Returns the name of a class in formulas.
+#[cfg(feature = "unicode-data")]
+fn class_name(class: MathClass) -> &'static str {
+    match class {
+        MathClass::Normal => "normal",
+        MathClass::Alphabetic => "alphabetic",
+        MathClass::Binary => "binary",
+        MathClass::Closing => "closing",
+        MathClass::Diacritic => "diacritic",
+        MathClass::Fence => "fence",
+        MathClass::GlyphPart => "glyph-part",
+        MathClass::Large => "large",
+        MathClass::Opening => "opening",
+        MathClass::Punctuation => "punctuation",
+        MathClass::Relation => "relation",
+        MathClass::Space => "space",
+        MathClass::Unary => "unary",
+        MathClass::Vary => "vary",
+        MathClass::Special => "special",
+    }
+}
+
+/// Returns the name of a presentation.
+#[cfg(feature = "unicode-data")]
+fn presentation_name(presentation: Presentation) -> &'static str {
+    match presentation {
+        Presentation::Text => "text",
+        Presentation::Emoji => "emoji",
+    }
+}
+
+/// Formats a code point as `U+XXXX`.
+fn codepoint(c: char) -> String {
+    format!("U+{:04X}", c as u32)
+}
+
+/// Writes an optional string as a JSON string, or `null`.
+fn write_optional(out: &mut String, s: Option<&str>) {
+    match s {
+        Some(s) => write_string(out, s),
+        None => out.push_str("null"),
+    }
+}
+
+/// Writes a JSON string literal.
+fn write_string(out: &mut String, s: &str) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            c if c.is_control() => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+/// Writes a CSV field, quoting it if needed.
+fn write_field(out: &mut String, s: &str) {
+    if s.contains([',', '"', '\n', '\r']) || s.starts_with(char::is_whitespace) {
+        out.push('"');
+        out.push_str(&s.replace('"', "\"\""));
+        out.push('"');
+    } else {
+        out.push_str(s);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{aliases_of, export_csv, export_json, write_field};
+    use crate::catalog::NAMES;
+
+    #[test]
+    fn test_export_json() {
+        let json = export_json();
+        assert!(json.contains(
+            "{\"name\": \"sym.arrow.r\", \"value\": \"→\", \"codepoints\": [\"U+2192\"], "
+        ));
+        #[cfg(feature = "unicode-data")]
+        assert!(json.contains(
+            "\"aliases\": [], \"math_class\": \"relation\", \"unicode_version\": \"1.1\", \
+             \"presentation\": null"
+        ));
+        assert!(json.contains("\"html_entity\": \"&rarr;\"}"));
+        assert_eq!(json.matches("{\"name\"").count(), NAMES.len());
+    }
+
+    #[test]
+    fn test_export_csv() {
+        let csv = export_csv();
+        assert!(csv.starts_with(
+            "name,value,codepoints,description,aliases,math_class,unicode_version,\
+             presentation,latex,html_entity\r\n"
+        ));
+        assert!(csv.contains("\r\nsym.comma,\",\",U+002C,"));
+        assert!(csv.contains("\r\nsym.quote.double,\"\"\"\",U+0022,"));
+        assert_eq!(csv.matches("\r\n").count(), NAMES.len() + 1);
+    }
+
+    #[test]
+    fn test_aliases_of() {
+        assert_eq!(aliases_of("sym.prec.eq.slant"), ["sym.prec.curly.eq"]);
+        assert_eq!(aliases_of("sym.prec.eq.slant.not"), ["sym.prec.curly.eq.not"]);
+        assert!(aliases_of("sym.arrow.r").is_empty());
+    }
+
+    #[test]
+    fn test_write_field() {
+        let mut out = String::new();
+        write_field(&mut out, "a\"b,c");
+        write_field(&mut out, " ");
+        write_field(&mut out, "→");
+        assert_eq!(out, "\"a\"\"b,c\"\" \"→");
+    }
+}
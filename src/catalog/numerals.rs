@@ -0,0 +1,261 @@
+//! The digits and letters of the named numeral systems, as a namespace of the
+//! catalog.
+
+use std::sync::OnceLock;
+
+use super::base;
+use crate::numeral_systems::{NamedNumeralSystem, NumeralSystem};
+
+/// Returns the value of a numeral of a named numeral system from its full
+/// name.
+///
+/// The names are generated from the tables of the
+/// [named numeral systems](NamedNumeralSystem), so they always agree with
+/// their representations of numbers. The path of a system is its name, with
+/// `.lower` or `.upper` appended for the systems that exist in both cases,
+/// such as `roman.upper` for `Roman`. Numerals are then named as follows:
+/// - The digits of positional systems are named `digit.<path>.<digit>`, such as
+///   `digit.devanagari.3`.
+/// - The numbers of fixed systems are named `number.<path>.<number>`, such as
+///   `number.arabic.o.12`.
+/// - The letters of bijective systems are named `letter.<path>.<position>`,
+///   counting from one, such as `letter.latin.upper.3`.
+/// - The numerals of additive systems that consist of a single character,
+///   possibly followed by combining marks, are named `<path>.<value>`, where
+///   the value is spelled out in English without spaces, such as
+///   `roman.upper.hundred` or `greek.lower.ninety`.
+/// - The symbols of symbolic systems are named `<path>.<position>`, counting
+///   from one, such as `symbol.2`.
+///
+/// ## Example
+///
+/// ```
+/// use codex::catalog::numeral;
+///
+/// # #[cfg(feature = "indic")]
+/// assert_eq!(numeral("digit.devanagari.3"), Some("३"));
+/// assert_eq!(numeral("roman.upper.hundred"), Some("C"));
+/// assert_eq!(numeral("roman.upper.fivethousand"), Some("V̅"));
+/// assert_eq!(numeral("roman.upper.ninehundred"), None);
+/// ```
+pub fn numeral(name: &str) -> Option<&'static str> {
+    let table = table();
+    let i = table.binary_search_by(|(n, _)| n.as_str().cmp(name)).ok()?;
+    Some(table[i].1)
+}
+
+/// Returns an iterator over the full names and values of the numerals of the
+/// named numeral systems, in lexicographic order of their names.
+///
+/// See [`numeral`] for how they are named.
+pub fn numerals() -> impl ExactSizeIterator<Item = (&'static str, &'static str)> {
+    table().iter().map(|(name, value)| (name.as_str(), *value))
+}
+
+/// Returns the full name of the counterpart of a numeral in the other case,
+/// such as `roman.upper.hundred` for `roman.lower.hundred`.
+///
+/// The cases are the parts of the path to replace, such as
+/// `("lower", "upper")`.
+pub(super) fn with_case(name: &str, (from, to): (&str, &str)) -> Option<&'static str> {
+    numeral(name)?;
+    let mut parts: Vec<&str> = name.split('.').collect();
+    let i = parts.iter().position(|&part| part == from)?;
+    parts[i] = to;
+    let other = parts.join(".");
+    let table = table();
+    let j = table.binary_search_by(|(n, _)| n.as_str().cmp(&other)).ok()?;
+    Some(table[j].0.as_str())
+}
+
+/// Returns the numerals of all the named numeral systems, sorted by name.
+fn table() -> &'static [(String, &'static str)] {
+    static TABLE: OnceLock<Vec<(String, &'static str)>> = OnceLock::new();
+    TABLE.get_or_init(|| {
+        let mut table = vec![];
+        for named in NamedNumeralSystem::iter() {
+            collect(&mut table, &path(named), named.system());
+        }
+        table.sort();
+        table.dedup_by(|(a, _), (b, _)| a == b);
+        table
+    })
+}
+
+/// Adds the numerals of a numeral system to a table.
+fn collect(
+    table: &mut Vec<(String, &'static str)>,
+    path: &str,
+    system: NumeralSystem<'static>,
+) {
+    let numbered =
+        |prefix: &'static str, symbols: &'static [&'static str], start: usize| {
+            symbols
+                .iter()
+                .enumerate()
+                .map(move |(i, &value)| (format!("{prefix}{path}.{}", i + start), value))
+        };
+    match system {
+        NumeralSystem::Positional(digits) => table.extend(numbered("digit.", digits, 0)),
+        NumeralSystem::Fixed(numbers) => table.extend(numbered("number.", numbers, 0)),
+        NumeralSystem::ZerolessFixed(numbers) => {
+            table.extend(numbered("number.", numbers, 1))
+        }
+        NumeralSystem::Bijective(letters) => {
+            table.extend(numbered("letter.", letters, 1))
+        }
+        NumeralSystem::Symbolic(symbols) => table.extend(numbered("", symbols, 1)),
+        NumeralSystem::Additive(numerals) => {
+            for &(value, number) in numerals {
+                if let (Some(_), Some(word)) = (base(value), spell(number)) {
+                    table.push((format!("{path}.{word}"), value));
+                }
+            }
+        }
+        #[cfg(feature = "cjk")]
+        NumeralSystem::Chinese(_, _) => {}
+    }
+}
+
+/// Returns the path of a named numeral system in the namespace.
+fn path(named: NamedNumeralSystem) -> String {
+    let name = named.name();
+    let mut chars = name.chars();
+    let Some(first) = chars.next() else { return String::new() };
+    let lower: String = first.to_lowercase().chain(chars.clone()).collect();
+    let upper: String = first.to_uppercase().chain(chars).collect();
+    let exists = |name: &str| NamedNumeralSystem::from_name(name).is_some();
+    if lower == upper || !exists(&lower) || !exists(&upper) {
+        name.into()
+    } else if name == lower {
+        format!("{lower}.lower")
+    } else {
+        format!("{lower}.upper")
+    }
+}
+
+/// Spells a number out in English, without spaces, such as `fivehundred`.
+///
+/// Returns `None` for numbers that are neither below one hundred nor a
+/// multiple of a power of ten.
+fn spell(number: u64) -> Option<String> {
+    const ONES: [&str; 20] = [
+        "zero",
+        "one",
+        "two",
+        "three",
+        "four",
+        "five",
+        "six",
+        "seven",
+        "eight",
+        "nine",
+        "ten",
+        "eleven",
+        "twelve",
+        "thirteen",
+        "fourteen",
+        "fifteen",
+        "sixteen",
+        "seventeen",
+        "eighteen",
+        "nineteen",
+    ];
+    const TENS: [&str; 10] = [
+        "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty",
+        "ninety",
+    ];
+    const SCALES: [(u64, &str); 3] =
+        [(1_000_000, "million"), (1000, "thousand"), (100, "hundred")];
+
+    if let Some(&(scale, word)) = SCALES
+        .iter()
+        .find(|&&(scale, _)| number >= scale && number.is_multiple_of(scale))
+    {
+        let count = number / scale;
+        let count = if count == 1 { String::new() } else { spell(count)? };
+        return Some(count + word);
+    }
+    match number {
+        0..20 => Some(ONES[number as usize].into()),
+        20..100 if number.is_multiple_of(10) => Some(TENS[number as usize / 10].into()),
+        20..100 => {
+            Some(format!("{}{}", TENS[number as usize / 10], ONES[number as usize % 10]))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{numeral, numerals, spell, with_case};
+    use crate::numeral_systems::NamedNumeralSystem;
+
+    #[test]
+    fn test_numerals() {
+        assert!(numerals().is_sorted());
+        assert_eq!(numeral("digit.arabic.0"), Some("0"));
+        assert_eq!(numeral("letter.latin.lower.26"), Some("z"));
+        assert_eq!(numeral("roman.lower.thousand"), Some("m"));
+        assert_eq!(numeral("roman.upper.zero"), Some("N"));
+        assert_eq!(numeral("roman.upper.fourthousand"), None);
+        #[cfg(feature = "indic")]
+        assert_eq!(numeral("digit.devanagari.3"), Some("३"));
+        #[cfg(feature = "historic")]
+        {
+            assert_eq!(numeral("greek.lower.ninety"), Some("ϟ"));
+            assert_eq!(numeral("greek.upper.six"), None);
+            assert_eq!(numeral("armenian.upper.ninethousand"), Some("Ք"));
+        }
+        #[cfg(feature = "rtl")]
+        {
+            assert_eq!(numeral("hebrew.fifteen"), None);
+            assert_eq!(numeral("digit.arabic.eastern.3"), Some("٣"));
+        }
+        #[cfg(feature = "symbols")]
+        {
+            assert_eq!(numeral("number.arabic.o.12"), Some("⑫"));
+            assert_eq!(numeral("number.arabic.oo.1"), Some("⓵"));
+            assert_eq!(numeral("symbol.2"), Some("†"));
+            assert_eq!(numeral("symbol.fr.2"), Some("⁑"));
+        }
+    }
+
+    #[test]
+    fn test_with_case() {
+        let upper = ("lower", "upper");
+        assert_eq!(with_case("roman.lower.hundred", upper), Some("roman.upper.hundred"));
+        assert_eq!(
+            with_case("letter.latin.lower.3", upper),
+            Some("letter.latin.upper.3")
+        );
+        assert_eq!(with_case("roman.upper.hundred", upper), None);
+        assert_eq!(with_case("digit.arabic.3", upper), None);
+        assert_eq!(with_case("sym.lower", upper), None);
+    }
+
+    #[test]
+    fn test_numerals_agree_with_systems() {
+        let system = NamedNumeralSystem::UpperRoman;
+        for (name, value) in
+            numerals().filter(|(name, _)| name.starts_with("roman.upper."))
+        {
+            let number = system.system().parse(value).unwrap();
+            assert_eq!(spell(number).as_deref(), name.strip_prefix("roman.upper."));
+        }
+        for n in 0..10 {
+            let digit = numeral(&format!("digit.arabic.{n}")).unwrap();
+            assert_eq!(NamedNumeralSystem::Arabic.represent_cached(n).unwrap(), digit);
+        }
+    }
+
+    #[test]
+    fn test_spell() {
+        assert_eq!(spell(0).as_deref(), Some("zero"));
+        assert_eq!(spell(42).as_deref(), Some("fortytwo"));
+        assert_eq!(spell(100).as_deref(), Some("hundred"));
+        assert_eq!(spell(500_000).as_deref(), Some("fivehundredthousand"));
+        assert_eq!(spell(1_000_000).as_deref(), Some("million"));
+        assert_eq!(spell(101), None);
+    }
+}
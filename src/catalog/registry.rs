@@ -0,0 +1,390 @@
+//! Symbols defined at runtime on top of the catalog.
+
+use std::collections::BTreeMap;
+use std::ops::Bound;
+
+use super::{Match, NAMES, ResolveError, Variant, complete, find, resolve, search_in};
+use crate::{Def, ModifierSet, Module};
+
+/// A layer of user-defined symbols and variants on top of the catalog.
+///
+/// The queries of a registry behave like those of the catalog, except that
+/// the variants defined in the registry are added to the symbols of the
+/// catalog, or override the variants with the same modifiers. New symbols can
+/// be defined in any module, including modules that are not in the catalog,
+/// such as `user`. This allows applications to expose project-specific
+/// notation.
+///
+/// ## Example
+///
+/// ```
+/// use codex::catalog::Registry;
+///
+/// let mut registry = Registry::new();
+/// registry.define("sym.arrow", "r.fancy", "➠").unwrap();
+/// registry.define("sym.qed", "", "□").unwrap();
+/// registry.define("user.ring", "", "⊚").unwrap();
+///
+/// assert_eq!(registry.resolve("sym.arrow.fancy.r"), Ok(("➠", None)));
+/// assert_eq!(registry.resolve("sym.arrow.r"), Ok(("→", None)));
+/// assert_eq!(registry.resolve("sym.qed"), Ok(("□", None)));
+/// assert_eq!(registry.resolve("user.ring"), Ok(("⊚", None)));
+/// assert!(registry.complete("user.").eq(["user.ring"]));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Registry {
+    /// The defined variants by full name, with the length of the full name of
+    /// their symbol and their value.
+    variants: BTreeMap<String, (usize, String)>,
+}
+
+impl Registry {
+    /// Creates a registry without any definition.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Defines a variant of a symbol, given by the full name of the symbol and
+    /// the modifiers of the variant separated by dots, and returns the value
+    /// it previously had in the registry, if any.
+    ///
+    /// The modifiers can be given in any order, and are empty for the default
+    /// variant. If the symbol is in the catalog, the variant overrides that
+    /// with the same modifiers, or is added to the symbol otherwise.
+    pub fn define(
+        &mut self,
+        symbol: &str,
+        modifiers: &str,
+        value: impl Into<String>,
+    ) -> Result<Option<String>, DefineError> {
+        if symbol.split('.').any(str::is_empty)
+            || (!modifiers.is_empty() && modifiers.split('.').any(str::is_empty))
+        {
+            return Err(DefineError::Empty);
+        }
+        let catalog = find(symbol);
+        if matches!(catalog, Some(binding) if matches!(binding.def, Def::Module(_)))
+            || symbol.match_indices('.').any(|(i, _)| self.is_symbol(&symbol[..i]))
+            || self
+                .symbols()
+                .any(|s| s.len() > symbol.len() && s.starts_with(symbol))
+        {
+            return Err(DefineError::Conflict);
+        }
+
+        // Use the order of the modifiers in the catalog, if the variant is
+        // there, so that the name is canonical.
+        let set = ModifierSet::from_raw_dotted(modifiers);
+        let modifiers = catalog
+            .and_then(|binding| match binding.def {
+                Def::Symbol(symbol) => {
+                    symbol.variants().find(|&(other, _, _)| same_modifiers(other, set))
+                }
+                Def::Module(_) => None,
+            })
+            .map_or(modifiers, |(other, _, _)| other.0);
+        let existing = self
+            .variants_of(symbol)
+            .into_iter()
+            .find(|&(other, _)| same_modifiers(other, set))
+            .map(|(other, _)| name(symbol, other.as_str()));
+        let name = existing.unwrap_or_else(|| name(symbol, modifiers));
+        let previous = self.variants.insert(name, (symbol.len(), value.into()));
+        Ok(previous.map(|(_, value)| value))
+    }
+
+    /// Defines all the variants of a module under a path, such as one
+    /// declared with the [`module!`](crate::module) macro.
+    ///
+    /// The symbols of the module are defined as with [`define`](Self::define),
+    /// in order, and the nested modules are defined recursively. Since the
+    /// variants of a registry are never deprecated, the deprecated definitions
+    /// and variants of the module are skipped. If a variant cannot be defined,
+    /// the error is returned and the following variants are not defined.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use codex::catalog::Registry;
+    ///
+    /// codex::module! {
+    ///     const USER = {
+    ///         arrow: ["➠", long: "⟹"],
+    ///     };
+    /// }
+    ///
+    /// let mut registry = Registry::new();
+    /// registry.define_module("user", USER).unwrap();
+    /// assert_eq!(registry.resolve("user.arrow.long"), Ok(("⟹", None)));
+    /// ```
+    pub fn define_module(
+        &mut self,
+        path: &str,
+        module: Module,
+    ) -> Result<(), DefineError> {
+        for (name, binding) in module.iter() {
+            if binding.deprecation.is_some() {
+                continue;
+            }
+            let path = format!("{path}.{name}");
+            match binding.def {
+                Def::Module(module) => self.define_module(&path, module)?,
+                Def::Symbol(symbol) => {
+                    for (modifiers, value, deprecation) in symbol.variants() {
+                        if deprecation.is_none() {
+                            self.define(&path, modifiers.as_str(), value)?;
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Resolves a full name into the value of a symbol variant, alongside an
+    /// optional deprecation message.
+    ///
+    /// Variants defined in the registry are never deprecated. See
+    /// [`resolve`](super::resolve).
+    pub fn resolve(&self, name: &str) -> Result<(&str, Option<&str>), ResolveError> {
+        let Some(symbol) = self.symbol_of(name) else { return resolve(name) };
+        let rest = &name[symbol.len()..];
+        let modifiers = rest.strip_prefix('.').unwrap_or_default();
+        if !rest.is_empty() && modifiers.split('.').any(str::is_empty) {
+            return resolve(name);
+        }
+        let variants = self.merged_variants(symbol);
+        match ModifierSet::from_raw_dotted(modifiers).best_match_in(
+            variants
+                .into_iter()
+                .map(|(set, value, deprecation)| (set, (value, deprecation))),
+        ) {
+            Some(resolved) => Ok(resolved),
+            None if find(symbol).is_some() => resolve(name),
+            None => Err(ResolveError::UnknownModifiers { suggestions: vec![] }),
+        }
+    }
+
+    /// Returns the full names of the symbol variants starting with a prefix,
+    /// in lexicographic order, including those defined in the registry. See
+    /// [`complete`](super::complete).
+    pub fn complete<'a>(&'a self, prefix: &'a str) -> impl Iterator<Item = &'a str> {
+        let mut names: Vec<&str> = complete(prefix).collect();
+        names.extend(
+            self.variants
+                .range::<str, _>((Bound::Included(prefix), Bound::Unbounded))
+                .map(|(name, _)| name.as_str())
+                .take_while(|name| name.starts_with(prefix)),
+        );
+        names.sort();
+        names.dedup();
+        names.into_iter()
+    }
+
+    /// Searches the symbol variants whose names or descriptions match a query,
+    /// including those defined in the registry. See [`search`](super::search).
+    pub fn search(&self, query: &str) -> Vec<Match<'_>> {
+        let catalog = NAMES.iter().map(|&(name, value, description)| {
+            let value = self.variants.get(name).map_or(value, |(_, value)| value);
+            (name, value, description)
+        });
+        let defined = self
+            .variants
+            .iter()
+            .filter(|(name, _)| {
+                NAMES
+                    .binary_search_by_key(&name.as_str(), |&(name, _, _)| name)
+                    .is_err()
+            })
+            .map(|(name, (_, value))| (name.as_str(), value.as_str(), None));
+        search_in(query, catalog.chain(defined))
+    }
+
+    /// Returns the full name of the symbol of the registry that a full name
+    /// refers to, if any.
+    fn symbol_of<'a>(&self, name: &'a str) -> Option<&'a str> {
+        let ends = name.match_indices('.').map(|(i, _)| i).chain([name.len()]);
+        for end in ends {
+            let prefix = &name[..end];
+            if !self.variants_of(prefix).is_empty() {
+                return Some(prefix);
+            }
+            if find(prefix).is_some_and(|binding| matches!(binding.def, Def::Symbol(_))) {
+                return None;
+            }
+        }
+        None
+    }
+
+    /// Whether a full name is that of a symbol of the catalog or the
+    /// registry.
+    fn is_symbol(&self, name: &str) -> bool {
+        !self.variants_of(name).is_empty()
+            || find(name).is_some_and(|binding| matches!(binding.def, Def::Symbol(_)))
+    }
+
+    /// Returns the full names of the symbols of the registry.
+    fn symbols(&self) -> impl Iterator<Item = &str> {
+        self.variants.iter().map(|(name, &(len, _))| &name[..len])
+    }
+
+    /// Returns the modifiers and values of the variants of a symbol defined
+    /// in the registry.
+    fn variants_of(&self, symbol: &str) -> Vec<(ModifierSet<&str>, &str)> {
+        self.variants
+            .range::<str, _>((Bound::Included(symbol), Bound::Unbounded))
+            .take_while(|(name, _)| name.starts_with(symbol))
+            .filter(|(_, (len, _))| *len == symbol.len())
+            .map(|(name, (len, value))| {
+                let modifiers = name[*len..].strip_prefix('.').unwrap_or_default();
+                (ModifierSet::from_raw_dotted(modifiers), value.as_str())
+            })
+            .collect()
+    }
+
+    /// Returns the variants of a symbol of the catalog, overridden or
+    /// completed by those of the registry.
+    fn merged_variants(
+        &self,
+        symbol: &str,
+    ) -> Vec<(ModifierSet<&str>, &str, Option<&str>)> {
+        let mut variants: Vec<_> = match find(symbol) {
+            Some(binding) => match binding.def {
+                Def::Symbol(symbol) => symbol
+                    .variants()
+                    .map(|(set, value, deprecation): Variant| {
+                        (set, value, binding.deprecation.or(deprecation))
+                    })
+                    .collect(),
+                Def::Module(_) => vec![],
+            },
+            None => vec![],
+        };
+        for (set, value) in self.variants_of(symbol) {
+            match variants.iter_mut().find(|(other, _, _)| same_modifiers(*other, set)) {
+                Some(variant) => *variant = (variant.0, value, None),
+                None => variants.push((set, value, None)),
+            }
+        }
+        variants
+    }
+}
+
+/// A reason why a variant cannot be defined in a [`Registry`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum DefineError {
+    /// The name of the symbol or one of the modifiers is empty.
+    Empty,
+    /// The name of the symbol is that of a module, or the symbol would be
+    /// nested in another symbol, or contain one.
+    Conflict,
+}
+
+/// Whether two sets of modifiers contain the same modifiers, in any order.
+fn same_modifiers(a: ModifierSet<&str>, b: ModifierSet<&str>) -> bool {
+    a.iter().count() == b.iter().count() && a.iter().all(|m| b.contains(m))
+}
+
+/// Returns the full name of a variant of a symbol.
+fn name(symbol: &str, modifiers: &str) -> String {
+    if modifiers.is_empty() {
+        symbol.to_string()
+    } else {
+        format!("{symbol}.{modifiers}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DefineError, Registry};
+
+    #[test]
+    fn test_define() {
+        let mut registry = Registry::new();
+        assert_eq!(registry.define("user.ring", "", "⊚"), Ok(None));
+        assert_eq!(registry.define("user.ring", "", "◎"), Ok(Some("⊚".into())));
+        assert_eq!(registry.define("user.ring", "big", "◯"), Ok(None));
+        assert_eq!(registry.define("sym.arrow", "long.r", "⟶"), Ok(None));
+        assert_eq!(registry.define("sym", "", "x"), Err(DefineError::Conflict));
+        assert_eq!(registry.define("sym.arrow.r", "", "x"), Err(DefineError::Conflict));
+        assert_eq!(registry.define("user.ring.dot", "", "x"), Err(DefineError::Conflict));
+        assert_eq!(registry.define("user", "", "x"), Err(DefineError::Conflict));
+        assert_eq!(registry.define("user..x", "", "x"), Err(DefineError::Empty));
+        assert_eq!(registry.define("user.x", "a..b", "x"), Err(DefineError::Empty));
+        // The order of the modifiers of the catalog is used.
+        assert!(registry.complete("sym.arrow.r.long").any(|n| n == "sym.arrow.r.long"));
+        assert!(!registry.complete("sym.arrow.long").any(|_| true));
+    }
+
+    #[test]
+    fn test_define_module() {
+        crate::module! {
+            const USER = {
+                #[deprecated = "use `ring` instead"]
+                circle: "○",
+                ring: ["⊚", big: "◯", #[deprecated = "use `big` instead"] large: "◯"],
+                set: {
+                    empty: "∅",
+                },
+            };
+        }
+        let mut registry = Registry::new();
+        assert_eq!(registry.define_module("user", USER), Ok(()));
+        assert_eq!(registry.resolve("user.ring.big"), Ok(("◯", None)));
+        assert_eq!(registry.resolve("user.set.empty"), Ok(("∅", None)));
+        assert!(registry.resolve("user.circle").is_err());
+        assert!(registry.complete("user.ring.").eq(["user.ring.big"]));
+        assert_eq!(registry.define_module("sym", USER), Ok(()));
+        assert_eq!(registry.define_module("sym.arrow", USER), Err(DefineError::Conflict));
+    }
+
+    #[test]
+    fn test_resolve() {
+        let mut registry = Registry::new();
+        registry.define("sym.arrow", "r", "➡").unwrap();
+        registry.define("sym.arrow", "r.fancy", "➠").unwrap();
+        registry.define("user.ring", "", "⊚").unwrap();
+        registry.define("user.ring", "big", "◯").unwrap();
+        assert_eq!(registry.resolve("sym.arrow"), Ok(("➡", None)));
+        assert_eq!(registry.resolve("sym.arrow.r"), Ok(("➡", None)));
+        assert_eq!(registry.resolve("sym.arrow.fancy"), Ok(("➠", None)));
+        assert_eq!(registry.resolve("sym.arrow.l"), Ok(("←", None)));
+        assert_eq!(registry.resolve("sym.arrow.r.long"), Ok(("⟶", None)));
+        assert_eq!(registry.resolve("sym.plus"), Ok(("+", None)));
+        assert_eq!(registry.resolve("user.ring"), Ok(("⊚", None)));
+        assert_eq!(registry.resolve("user.ring.big"), Ok(("◯", None)));
+        assert!(registry.resolve("user.ring.small").is_err());
+        assert!(registry.resolve("user.dot").is_err());
+        assert!(registry.resolve("user.ring.").is_err());
+        assert!(registry.resolve("sym.arrow.unknown").is_err());
+        assert!(Registry::new().resolve("sym.prec.curly.eq").unwrap().1.is_some());
+        registry.define("sym.prec", "curly.eq", "≼").unwrap();
+        assert_eq!(registry.resolve("sym.prec.curly.eq"), Ok(("≼", None)));
+    }
+
+    #[test]
+    fn test_complete() {
+        let mut registry = Registry::new();
+        registry.define("sym.arrow", "r.fancy", "➠").unwrap();
+        registry.define("sym.arrow", "r.long", "⟶").unwrap();
+        let names: Vec<_> = registry.complete("sym.arrow.r.").collect();
+        assert!(names.is_sorted());
+        assert!(names.contains(&"sym.arrow.r.fancy"));
+        assert_eq!(names.iter().filter(|&&n| n == "sym.arrow.r.long").count(), 1);
+    }
+
+    #[test]
+    fn test_search() {
+        let mut registry = Registry::new();
+        registry.define("sym.integral", "", "⨋").unwrap();
+        registry.define("user.integral", "fancy", "⨘").unwrap();
+        let matches = registry.search("integral");
+        assert_eq!(matches[0].name, "sym.integral");
+        assert_eq!(matches[0].value, "⨋");
+        assert!(
+            matches
+                .iter()
+                .any(|m| m.name == "user.integral.fancy" && m.value == "⨘")
+        );
+    }
+}
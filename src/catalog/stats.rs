@@ -0,0 +1,166 @@
+//! Statistics over the catalog and comparison of catalogs.
+
+use std::collections::BTreeMap;
+
+use super::iter;
+#[cfg(feature = "unicode-data")]
+use super::{base, char_info, unicode_version};
+
+/// Counts of the variants of the catalog.
+///
+/// Values of this type are returned by [`statistics`]. Deprecated names are
+/// not counted.
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct Statistics {
+    /// The number of variants.
+    pub variants: usize,
+    /// The number of variants in each top-level module, such as `sym`.
+    pub modules: BTreeMap<&'static str, usize>,
+    /// The number of variants whose value is based on a character of each
+    /// Unicode block. Variants whose value is not a single character,
+    /// optionally followed by variation selectors and combining marks, are not
+    /// counted. This is empty without the `unicode-data` feature.
+    pub blocks: BTreeMap<&'static str, usize>,
+    /// The number of variants whose value can first be displayed in each
+    /// version of Unicode. See `unicode_version`. This is empty without the
+    /// `unicode-data` feature.
+    pub versions: BTreeMap<(u8, u8), usize>,
+}
+
+/// Counts the variants of the catalog per module, Unicode block, and Unicode
+/// version.
+///
+/// This allows release notes and coverage reports to be generated.
+///
+/// ## Example
+///
+/// ```
+/// let statistics = codex::catalog::statistics();
+/// assert_eq!(statistics.variants, codex::catalog::iter().len());
+/// # #[cfg(feature = "unicode-data")]
+/// assert!(statistics.blocks["Arrows"] > 50);
+/// ```
+pub fn statistics() -> Statistics {
+    let mut statistics = Statistics {
+        variants: 0,
+        modules: BTreeMap::new(),
+        blocks: BTreeMap::new(),
+        versions: BTreeMap::new(),
+    };
+    for (name, _) in iter() {
+        statistics.variants += 1;
+        let module = name.split_once('.').map_or(name, |(module, _)| module);
+        *statistics.modules.entry(module).or_default() += 1;
+    }
+    #[cfg(feature = "unicode-data")]
+    for (_, value) in iter() {
+        if let Some(info) = base(value).and_then(char_info) {
+            *statistics.blocks.entry(info.block).or_default() += 1;
+        }
+        if let Some(version) = unicode_version(value) {
+            *statistics.versions.entry(version).or_default() += 1;
+        }
+    }
+    statistics
+}
+
+/// The differences between two catalogs.
+///
+/// Values of this type are returned by [`diff`]. The names are in
+/// lexicographic order.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct Diff<'a> {
+    /// The names of the variants of the new catalog that are not in the old
+    /// one, with their values.
+    pub added: Vec<(&'a str, &'a str)>,
+    /// The names of the variants of the old catalog that are not in the new
+    /// one, with their values.
+    pub removed: Vec<(&'a str, &'a str)>,
+    /// The names of the variants whose value changed, with their old and new
+    /// values.
+    pub changed: Vec<(&'a str, &'a str, &'a str)>,
+}
+
+impl Diff<'_> {
+    /// Whether the catalogs are identical.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// Compares two catalogs, given by the names and values of their variants.
+///
+/// The current catalog is given by [`iter`]. Another one can be that of a
+/// previous version of this crate, for example as loaded from its
+/// [JSON export](super::export_json). This allows compatibility checks to
+/// detect removed and changed symbols.
+///
+/// ## Example
+///
+/// ```
+/// use codex::catalog::{diff, iter};
+///
+/// let old = [("sym.arrow.r", "→"), ("sym.old", "?")];
+/// let diff = diff(old, iter());
+/// assert_eq!(diff.removed, [("sym.old", "?")]);
+/// assert!(diff.added.iter().any(|&(name, _)| name == "sym.arrow.l"));
+/// assert!(diff.changed.is_empty());
+/// ```
+pub fn diff<'a>(
+    old: impl IntoIterator<Item = (&'a str, &'a str)>,
+    new: impl IntoIterator<Item = (&'a str, &'a str)>,
+) -> Diff<'a> {
+    let mut old: BTreeMap<&str, &str> = old.into_iter().collect();
+    let mut diff = Diff::default();
+    let new: BTreeMap<&str, &str> = new.into_iter().collect();
+    for (name, value) in new {
+        match old.remove(name) {
+            None => diff.added.push((name, value)),
+            Some(previous) if previous != value => {
+                diff.changed.push((name, previous, value))
+            }
+            Some(_) => {}
+        }
+    }
+    diff.removed = old.into_iter().collect();
+    diff
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{diff, statistics};
+    use crate::catalog::iter;
+
+    #[test]
+    fn test_statistics() {
+        let statistics = statistics();
+        assert_eq!(statistics.modules.values().sum::<usize>(), statistics.variants);
+        assert!(statistics.modules["sym"] > 0);
+        assert!(statistics.blocks.values().sum::<usize>() <= statistics.variants);
+        assert!(statistics.versions.values().sum::<usize>() <= statistics.variants);
+        #[cfg(feature = "unicode-data")]
+        {
+            use crate::catalog::UNICODE_VERSION;
+            assert!(statistics.blocks.values().sum::<usize>() > 0);
+            assert!(
+                statistics
+                    .versions
+                    .keys()
+                    .all(|&(major, _)| major <= UNICODE_VERSION.0)
+            );
+        }
+    }
+
+    #[test]
+    fn test_diff() {
+        assert!(diff(iter(), iter()).is_empty());
+        let old = [("a", "1"), ("b", "2"), ("c", "3")];
+        let new = [("c", "3"), ("b", "4"), ("d", "5")];
+        let diff = diff(old, new);
+        assert_eq!(diff.added, [("d", "5")]);
+        assert_eq!(diff.removed, [("a", "1")]);
+        assert_eq!(diff.changed, [("b", "2", "4")]);
+    }
+}
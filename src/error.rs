@@ -0,0 +1,74 @@
+//! An error type shared by the whole crate.
+
+use std::fmt::{self, Display, Formatter};
+
+/// An error of any fallible operation of this crate.
+///
+/// The operations of this crate report failures with types specific to them,
+/// such as [`ResolveError`](crate::catalog::ResolveError), or simply return
+/// `None`. Some of them have counterparts returning this type instead, which
+/// implements [`std::error::Error`], so that applications can report precise
+/// diagnostics uniformly. The specific error types of the numeral systems
+/// convert into it with `?`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Error {
+    /// Nothing has this name.
+    UnknownName(String),
+    /// A number cannot be represented, or a parsed number is too large.
+    OutOfRange,
+    /// The operation is not supported, for example because the cargo feature
+    /// it requires is disabled.
+    Unsupported {
+        /// The name of the disabled cargo feature, if that is the reason.
+        feature: Option<&'static str>,
+    },
+    /// A string cannot be parsed.
+    Parse {
+        /// The byte offset of the first character that could not be parsed,
+        /// or zero if the string is malformed as a whole.
+        position: usize,
+    },
+    /// Writing the output failed.
+    Write,
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownName(name) => write!(f, "unknown name `{name}`"),
+            Self::OutOfRange => f.write_str("number out of range"),
+            Self::Unsupported { feature: Some(feature) } => {
+                write!(f, "requires the `{feature}` feature")
+            }
+            Self::Unsupported { feature: None } => f.write_str("unsupported operation"),
+            Self::Parse { position } => write!(f, "invalid syntax at byte {position}"),
+            Self::Write => f.write_str("failed to write the output"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<fmt::Error> for Error {
+    fn from(_: fmt::Error) -> Self {
+        Self::Write
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Error;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Error::UnknownName("x".into()).to_string(), "unknown name `x`");
+        assert_eq!(
+            Error::Unsupported { feature: Some("cjk") }.to_string(),
+            "requires the `cjk` feature",
+        );
+        assert_eq!(Error::Parse { position: 3 }.to_string(), "invalid syntax at byte 3");
+        assert_eq!(Error::from(std::fmt::Error), Error::Write);
+        let _: &dyn std::error::Error = &Error::OutOfRange;
+    }
+}
@@ -7,21 +7,50 @@
 //! that don't contain the character `.`, but codex only defines ones that are
 //! entirely made of ASCII alphabetical characters.
 
+pub use self::error::Error;
+pub use self::name::{NameError, NamePart, ParsedName, is_valid_symbol_name, parse_name};
 pub use self::shared::ModifierSet;
 
+mod error;
+mod name;
 mod shared;
 
+pub mod catalog;
+
 #[cfg(feature = "numeral-systems")]
 pub mod numeral_systems;
 
 #[cfg(feature = "styling")]
 pub mod styling;
 
+#[cfg(feature = "python")]
+mod python;
+
+#[cfg(feature = "wasm")]
+mod wasm;
+
 /// A module of definitions.
 #[derive(Debug, Copy, Clone)]
 pub struct Module(&'static [(&'static str, Binding)]);
 
 impl Module {
+    /// Creates a module from its definitions, which must be sorted by name.
+    ///
+    /// This is usually done with the [`module!`] macro. Panics if the names
+    /// are not sorted or not unique, which fails compilation when the module
+    /// is a constant.
+    pub const fn new(definitions: &'static [(&'static str, Binding)]) -> Self {
+        let mut i = 1;
+        while i < definitions.len() {
+            assert!(
+                precedes(definitions[i - 1].0, definitions[i].0),
+                "the definitions of a module must be sorted by name and unique",
+            );
+            i += 1;
+        }
+        Self(definitions)
+    }
+
     /// Try to get a bound definition in the module.
     pub fn get(&self, name: &str) -> Option<Binding> {
         self.0
@@ -117,8 +146,140 @@ impl Symbol {
     }
 }
 
+impl ModifierSet<&'static str> {
+    /// Constructs a modifier set from a string in a constant, where modifiers
+    /// are separated by the character `.`. See
+    /// [`from_raw_dotted`](Self::from_raw_dotted).
+    pub const fn from_static(s: &'static str) -> Self {
+        Self(s)
+    }
+}
+
+/// Whether a string comes strictly before another in lexicographic order.
+const fn precedes(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    let mut i = 0;
+    while i < a.len() && i < b.len() {
+        if a[i] != b[i] {
+            return a[i] < b[i];
+        }
+        i += 1;
+    }
+    a.len() < b.len()
+}
+
+/// Declares a [`Module`] of symbols as a constant, with the same structure as
+/// the built-in modules.
+///
+/// A module lists definitions, sorted by name. Each definition binds a name to
+/// either a symbol with a single value, a symbol with variants between
+/// brackets, or a nested module between braces. The first variant of a symbol
+/// is its default variant, and the other ones are preceded by their modifiers,
+/// separated by dots. Definitions and variants can be deprecated with a
+/// `#[deprecated = "..."]` attribute, which is how aliases are kept for
+/// renamed symbols. The order of the names is checked at compile time.
+///
+/// The module can then be used like the built-in ones, or merged into a
+/// [`Registry`](catalog::Registry) with
+/// [`define_module`](catalog::Registry::define_module).
+///
+/// ## Example
+///
+/// ```
+/// use codex::{Def, ModifierSet, module};
+///
+/// module! {
+///     /// Project-specific notation.
+///     pub const USER = {
+///         arrow: ["→", r.fancy: "➠", #[deprecated = "use `arrow.r.fancy` instead"] fancy: "➠"],
+///         ring: "⊚",
+///         set: {
+///             empty: "∅",
+///         },
+///     };
+/// }
+///
+/// let Def::Symbol(arrow) = USER.get("arrow").unwrap().def else { panic!() };
+/// assert_eq!(arrow.get(ModifierSet::from_raw_dotted("fancy.r")), Some(("➠", None)));
+/// assert!(matches!(USER.get("set").unwrap().def, Def::Module(_)));
+/// ```
+///
+/// Unsorted names are rejected:
+///
+/// ```compile_fail
+/// codex::module! {
+///     const USER = { ring: "⊚", arrow: "➠" };
+/// }
+/// # let _ = USER;
+/// ```
+#[macro_export]
+macro_rules! module {
+    (
+        $(#[$attr:meta])*
+        $vis:vis const $name:ident = { $($body:tt)* };
+    ) => {
+        $(#[$attr])*
+        $vis const $name: $crate::Module = $crate::module!(@module $($body)*);
+    };
+    (@module $(
+        $(#[deprecated = $deprecation:literal])?
+        $name:ident : $def:tt
+    ),* $(,)?) => {
+        $crate::Module::new(&[$((
+            stringify!($name),
+            $crate::Binding {
+                def: $crate::module!(@def $def),
+                deprecation: $crate::module!(@deprecation $($deprecation)?),
+            },
+        )),*])
+    };
+    (@def { $($body:tt)* }) => {
+        $crate::Def::Module($crate::module!(@module $($body)*))
+    };
+    (@def [ $($variants:tt)* ]) => {
+        $crate::Def::Symbol($crate::Symbol::Multi(&$crate::module!(@variants [] $($variants)*)))
+    };
+    (@def $value:literal) => {
+        $crate::Def::Symbol($crate::Symbol::Single($value))
+    };
+    (@variants [$($done:tt)*] $(,)?) => {
+        [$($done)*]
+    };
+    (@variants [$($done:tt)*]
+        $(#[deprecated = $deprecation:literal])?
+        $value:literal $(, $($rest:tt)*)?
+    ) => {
+        $crate::module!(@variants [$($done)* (
+            $crate::ModifierSet::from_static(""),
+            $value,
+            $crate::module!(@deprecation $($deprecation)?),
+        ),] $($($rest)*)?)
+    };
+    (@variants [$($done:tt)*]
+        $(#[deprecated = $deprecation:literal])?
+        $first:ident $(. $modifier:ident)* : $value:literal $(, $($rest:tt)*)?
+    ) => {
+        $crate::module!(@variants [$($done)* (
+            $crate::ModifierSet::from_static(
+                concat!(stringify!($first) $(, ".", stringify!($modifier))*),
+            ),
+            $value,
+            $crate::module!(@deprecation $($deprecation)?),
+        ),] $($($rest)*)?)
+    };
+    (@deprecation) => {
+        None
+    };
+    (@deprecation $deprecation:literal) => {
+        Some($deprecation)
+    };
+}
+
 /// A module that contains the other top-level modules.
+///
+/// The `emoji` module is only included with the `emoji` feature.
 pub const ROOT: Module = Module(&[
+    #[cfg(feature = "emoji")]
     ("emoji", Binding::new(Def::Module(EMOJI))),
     ("sym", Binding::new(Def::Module(SYM))),
 ]);
@@ -159,6 +320,30 @@ mod test {
         );
     }
 
+    #[cfg(feature = "emoji")]
+    #[test]
+    fn skin_tones() {
+        let Def::Symbol(baby) = EMOJI.get("baby").unwrap().def else { panic!() };
+        assert_eq!(baby.get(ModifierSet::default()).unwrap().0, "👶");
+        assert_eq!(baby.get(ModifierSet::from_raw_dotted("dark")).unwrap().0, "👶🏿");
+        let Def::Symbol(lifting) = EMOJI.get("weightlifting").unwrap().def else {
+            panic!()
+        };
+        assert_eq!(
+            lifting.get(ModifierSet::from_raw_dotted("woman")).unwrap().0,
+            "🏋\u{FE0F}\u{200D}♀\u{FE0F}"
+        );
+        assert_eq!(
+            lifting.get(ModifierSet::from_raw_dotted("medium.woman")).unwrap().0,
+            "🏋🏽\u{200D}♀\u{FE0F}"
+        );
+        let Def::Symbol(hands) = EMOJI.get("handholding").unwrap().def else { panic!() };
+        assert_eq!(
+            hands.get(ModifierSet::from_raw_dotted("light")).unwrap().0,
+            "🧑🏻\u{200D}🤝\u{200D}🧑🏻"
+        );
+    }
+
     #[test]
     fn random_sample() {
         for (key, control) in [
@@ -192,6 +377,7 @@ mod test {
     }
 
     /// https://www.unicode.org/reports/tr51/#def_text_presentation_selector.
+    #[cfg(any(feature = "emoji", feature = "_test-unicode-conformance"))]
     const TEXT_PRESENTATION_SELECTOR: char = '\u{FE0E}';
     /// https://www.unicode.org/reports/tr51/#def_emoji_presentation_selector.
     const EMOJI_PRESENTATION_SELECTOR: char = '\u{FE0F}';
@@ -204,6 +390,7 @@ mod test {
         )
     }
 
+    #[cfg(feature = "emoji")]
     #[test]
     fn emojis_are_not_text() {
         assert!(
@@ -272,7 +459,7 @@ mod test {
         )
     }
 
-    #[cfg(feature = "_test-unicode-conformance")]
+    #[cfg(all(feature = "emoji", feature = "_test-unicode-conformance"))]
     #[test]
     fn emojis_have_emoji_presentation() {
         let require_presentation_selector = get_valid_presentation_sequences()
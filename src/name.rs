@@ -0,0 +1,131 @@
+//! Syntax of the names of codex notation.
+
+use std::ops::Range;
+
+/// A name split into its base and modifiers, as returned by [`parse_name`].
+///
+/// For example, `arrow.r.long` has the base `arrow` and the modifiers `r` and
+/// `long`. In a full name such as `sym.arrow.r`, the base is the top-level
+/// module `sym`: telling modules and symbols apart requires a lookup, see
+/// [`catalog::resolve`](crate::catalog::resolve).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ParsedName<'a> {
+    /// The first part of the name.
+    pub base: NamePart<'a>,
+    /// The following parts of the name, in order.
+    pub modifiers: Vec<NamePart<'a>>,
+}
+
+/// A part of a name, between dots.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NamePart<'a> {
+    /// The text of the part.
+    pub text: &'a str,
+    /// The byte range of the part in the name.
+    pub span: Range<usize>,
+}
+
+/// A reason why a name is not valid, as returned by [`parse_name`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum NameError {
+    /// The name is empty.
+    Empty,
+    /// A part of the name is empty, such as the one between the dots of
+    /// `arrow..r`. The span is empty, and positioned where the part is missing.
+    EmptyPart {
+        /// The byte range of the empty part in the name.
+        span: Range<usize>,
+    },
+    /// A character is neither an ASCII letter, an ASCII digit, nor a dot.
+    InvalidCharacter {
+        /// The byte range of the character in the name.
+        span: Range<usize>,
+    },
+}
+
+/// Splits a name into its base and modifiers, checking its syntax.
+///
+/// A name consists of non-empty parts separated by dots, made of ASCII letters
+/// and digits, such as `arrow.r.long` or `sym.arrow.r`. Whether a symbol with
+/// this name exists is not checked. This allows syntax highlighters and
+/// linters to report malformed names precisely.
+///
+/// ## Example
+///
+/// ```
+/// use codex::{NameError, parse_name};
+///
+/// let name = parse_name("arrow.r.long").unwrap();
+/// assert_eq!(name.base.text, "arrow");
+/// assert_eq!(name.modifiers[1].text, "long");
+/// assert_eq!(name.modifiers[1].span, 8..12);
+///
+/// assert_eq!(parse_name("arrow..r"), Err(NameError::EmptyPart { span: 6..6 }));
+/// assert_eq!(parse_name("arrow.→"), Err(NameError::InvalidCharacter { span: 6..9 }));
+/// ```
+pub fn parse_name(name: &str) -> Result<ParsedName<'_>, NameError> {
+    if name.is_empty() {
+        return Err(NameError::Empty);
+    }
+    if let Some((i, c)) = name
+        .char_indices()
+        .find(|&(_, c)| c != '.' && !c.is_ascii_alphanumeric())
+    {
+        return Err(NameError::InvalidCharacter { span: i..i + c.len_utf8() });
+    }
+    let mut parts = vec![];
+    let mut start = 0;
+    for text in name.split('.') {
+        if text.is_empty() {
+            return Err(NameError::EmptyPart { span: start..start });
+        }
+        parts.push(NamePart { text, span: start..start + text.len() });
+        start += text.len() + 1;
+    }
+    let base = parts.remove(0);
+    Ok(ParsedName { base, modifiers: parts })
+}
+
+/// Whether a name is syntactically valid in codex notation.
+///
+/// See [`parse_name`] for the syntax of names.
+///
+/// ## Example
+///
+/// ```
+/// use codex::is_valid_symbol_name;
+///
+/// assert!(is_valid_symbol_name("sym.arrow.r.long"));
+/// assert!(is_valid_symbol_name("arrow.nonexistent"));
+/// assert!(!is_valid_symbol_name("arrow.r."));
+/// assert!(!is_valid_symbol_name("arrow r"));
+/// ```
+pub fn is_valid_symbol_name(name: &str) -> bool {
+    parse_name(name).is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{NameError, NamePart, is_valid_symbol_name, parse_name};
+    use crate::catalog;
+
+    #[test]
+    fn test_parse_name() {
+        let name = parse_name("sym.arrow").unwrap();
+        assert_eq!(name.base, NamePart { text: "sym", span: 0..3 });
+        assert_eq!(name.modifiers, [NamePart { text: "arrow", span: 4..9 }]);
+        assert!(parse_name("AA").unwrap().modifiers.is_empty());
+        assert_eq!(parse_name(""), Err(NameError::Empty));
+        assert_eq!(parse_name(".arrow"), Err(NameError::EmptyPart { span: 0..0 }));
+        assert_eq!(parse_name("arrow."), Err(NameError::EmptyPart { span: 6..6 }));
+        assert_eq!(parse_name("handholding.man.man").unwrap().modifiers.len(), 2);
+        assert_eq!(parse_name("a-b"), Err(NameError::InvalidCharacter { span: 1..2 }));
+    }
+
+    #[test]
+    fn test_catalog_names_are_valid() {
+        for (name, _) in catalog::iter() {
+            assert!(is_valid_symbol_name(name), "{name}");
+        }
+    }
+}
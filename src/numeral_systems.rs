@@ -1,8 +1,159 @@
 //! Various ways of displaying non-negative integers.
+//!
+//! ## Features
+//!
+//! Named numeral systems are grouped by script family, each behind a cargo
+//! feature, so that only the required ones need to be compiled. All of them
+//! are enabled by default. The groups are:
+//! - `cjk`: Chinese, Japanese, and Korean numeral systems. This also enables
+//!   [`NumeralSystem::Chinese`].
+//! - `indic`: Devanagari, Bengali, and Tibetan numeral systems.
+//! - `rtl`: Hebrew and Arabic-script numeral systems.
+//! - `historic`: Greek and Armenian numerals.
+//! - `symbols`: Circled numbers and note numbering symbols.
+//!
+//! Arabic numerals, Latin letters, and Roman numerals are always available.
+//!
+//! The `arbitrary` feature implements `Arbitrary` from the `arbitrary` crate
+//! for [`NamedNumeralSystem`] and [`NumeralSystem`], for fuzzing.
+//!
+//! The `schemars` feature implements `JsonSchema` from the `schemars` crate
+//! for [`NamedNumeralSystem`] and [`NumeralSystem`], as enumerations of
+//! names.
+//!
+//! The `serde` feature implements `Serialize` and `Deserialize` from the
+//! `serde` crate for [`CounterState`] and [`Preset`].
+//!
+//! The `proptest` feature enables the [`proptest`] module, which provides
+//! strategies for property testing.
+//!
+//! The `icu` feature enables the [`icu`] module, for interoperability with
+//! ICU4X.
+//!
+//! The `test-support` feature enables the [`golden`] module, which generates
+//! and compares golden samples of numeral systems for regression tests.
+//!
+//! ## Custom numeral systems
+//!
+//! Besides the named numeral systems, applications can [`register`] their own
+//! numeral systems under a name, and look them up with
+//! [`lookup_registered`].
+//!
+//! Custom numeral systems can be stored in a compact binary format, with the
+//! [`bundle`] module.
+//!
+//! Spell-out systems can also be imported from ICU rule-based number format
+//! rule sets, with the [`rbnf`] module.
+//!
+//! Numbering styles combining numeral systems with surrounding text can be
+//! bundled as [presets](Preset), and stored as text. The state of
+//! hierarchical counters, which start at a configurable value and restart
+//! according to a [`RestartPolicy`], can be persisted as a [`CounterState`].
+//!
+//! Numerals of different numeral systems can be compared and sorted by their
+//! values, with [`compare_numerals`] and [`sort_numerals`]. The numerals of
+//! a numeral system can also be given keys that sort bytewise, with
+//! [`collation_key`]. Roman numeral characters, such as `Ⅻ`, can be
+//! replaced with ASCII letters before parsing with [`normalize_roman`].
+//! Numerals can be given a spoken form as an accessible alternative, such as
+//! "14" or "fourteen" for "XIV", with [`spoken_numeral`] and
+//! [`spoken_numeral_with`].
+//!
+//! Numbers that would silently be represented in another style can be
+//! rejected instead, with [`NamedNumeralSystem::represent_strict`],
+//! [`Preset::represent_strict`], and the
+//! [`strict`](NumeralOptions::strict) option.
+//!
+//! Represented numbers can be surrounded by text such as "§" or "第…条",
+//! spaced and isolated according to their scripts, with [`Decoration`].
+//! Numerals embedded in Arabic-script text can be given the bidirectional
+//! context of Arabic numbers with [`arabic_context`].
+//!
+//! Numeral systems imported from CSS can be given the descriptors of their
+//! counter styles, such as a negative sign and padding, with [`CounterStyle`].
+//!
+//! Options such as padding, digit grouping, and a fallback for numbers that
+//! cannot be represented are gathered in [`NumeralOptions`].
+//!
+//! Code that represents numbers can be generic over numeral systems, presets,
+//! and its own formatters, with the [`NumberFormatter`] trait.
+//!
+// The modules of disabled features are linked to their documentation online.
+#![cfg_attr(
+    not(feature = "proptest"),
+    doc = "[`proptest`]: https://docs.rs/codex/latest/codex/numeral_systems/proptest/"
+)]
+#![cfg_attr(
+    not(feature = "icu"),
+    doc = "[`icu`]: https://docs.rs/codex/latest/codex/numeral_systems/icu/"
+)]
+#![cfg_attr(
+    not(feature = "test-support"),
+    doc = "[`golden`]: https://docs.rs/codex/latest/codex/numeral_systems/golden/"
+)]
 
-use std::fmt::{Display, Formatter};
+use std::borrow::Cow;
+use std::collections::BTreeSet;
+use std::fmt::{self, Display, Formatter, Write};
+use std::iter::FusedIterator;
+use std::ops::{Bound, RangeBounds};
+use std::sync::OnceLock;
 
-use chinese_number::{ChineseCase, ChineseVariant, from_u64_to_chinese_ten_thousand};
+#[cfg(feature = "cjk")]
+use chinese_number::{ChineseCase, ChineseVariant};
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+pub mod bundle;
+mod compare;
+mod counter;
+mod counter_style;
+mod decoration;
+mod formatter;
+#[cfg(feature = "test-support")]
+pub mod golden;
+#[cfg(feature = "icu")]
+pub mod icu;
+mod json;
+mod markup;
+mod options;
+mod preset;
+#[cfg(feature = "proptest")]
+pub mod proptest;
+pub mod rbnf;
+mod registry;
+#[cfg(feature = "schemars")]
+mod schemars;
+#[cfg(feature = "serde")]
+mod serde;
+mod spoken;
+
+pub use compare::{collation_key, compare_numerals, numeral_value, sort_numerals};
+pub use counter::{CounterState, RestartPolicy};
+pub use counter_style::CounterStyle;
+pub use decoration::Decoration;
+#[cfg(feature = "rtl")]
+pub use decoration::arabic_context;
+pub use formatter::NumberFormatter;
+pub use json::export_json;
+pub use markup::Translation;
+pub use options::NumeralOptions;
+pub use preset::{Preset, PresetError, Reference};
+pub use registry::{
+    Registered, RegistrationError, find, lookup_registered, names, register, registered,
+    resolve,
+};
+pub use spoken::{spoken_numeral, spoken_numeral_with};
+
+/// Expands to `Some` of its argument if there is one, and to `None` otherwise.
+macro_rules! optional {
+    () => {
+        None
+    };
+    ($value:expr) => {
+        Some($value)
+    };
+}
 
 macro_rules! declare_named {
     (
@@ -10,7 +161,7 @@ macro_rules! declare_named {
         $vis:vis enum $Ty:ident {
             $(
                 $( #[$vattr:meta] )*
-                $Value:ident = $name:literal $( ($shorthand:literal) )?,
+                $Value:ident = $name:literal $( ($shorthand:literal) )? $( in $feature:literal )? as $id:literal,
             )*
         }
     ) => {
@@ -18,23 +169,67 @@ macro_rules! declare_named {
         $vis enum $Ty {
             $(
                 $( #[$vattr] )*
+                $( #[cfg(feature = $feature)] )?
                 $Value,
             )*
         }
 
         impl $Ty {
+            /// The number of values of this type.
+            const COUNT: usize = [$( $( #[cfg(feature = $feature)] )? Self::$Value, )*].len();
+
             /// Returns the named numeral system associated with a name, if any.
+            ///
+            /// See also [`lookup`](Self::lookup), which additionally reports
+            /// whether the named numeral system exists but is disabled.
             pub fn from_name(name: &str) -> Option<Self> {
+                Self::lookup(name).ok()
+            }
+
+            /// Returns the named numeral system associated with a name.
+            ///
+            /// If the named numeral system exists but the cargo feature it
+            /// belongs to is disabled, [`NameError::Disabled`] is returned.
+            pub fn lookup(name: &str) -> Result<Self, NameError> {
                 match name {
-                    $( $name => Some(Self::$Value), )*
-                    _ => None,
+                    $( $( #[cfg(feature = $feature)] )? $name => Ok(Self::$Value), )*
+                    $( $(
+                        #[cfg(not(feature = $feature))]
+                        $name => Err(NameError::Disabled { feature: $feature }),
+                    )? )*
+                    _ => Err(NameError::Unknown),
                 }
             }
 
             /// Returns the name of a named numeral system.
             pub const fn name(self) -> &'static str {
                 match self {
-                    $( Self::$Value => $name, )*
+                    $( $( #[cfg(feature = $feature)] )? Self::$Value => $name, )*
+                }
+            }
+
+            /// Returns the stable identifier of a named numeral system.
+            ///
+            /// Unlike the position of a named numeral system in
+            /// [`ALL`](Self::ALL), which changes when systems are added or
+            /// disabled by cargo features, identifiers never change across
+            /// versions, and are never reused. This allows binary formats to
+            /// store named numeral systems compactly.
+            pub const fn id(self) -> u16 {
+                match self {
+                    $( $( #[cfg(feature = $feature)] )? Self::$Value => $id, )*
+                }
+            }
+
+            /// Returns the named numeral system with a stable
+            /// [identifier](Self::id), if any.
+            ///
+            /// Returns `None` for unknown identifiers, and for those of named
+            /// numeral systems whose cargo feature is disabled.
+            pub const fn from_id(id: u16) -> Option<Self> {
+                match id {
+                    $( $( #[cfg(feature = $feature)] )? $id => Some(Self::$Value), )*
+                    _ => None,
                 }
             }
 
@@ -42,30 +237,49 @@ macro_rules! declare_named {
             ///
             /// A shorthand is a short string that identifies a named numeral
             /// system, such as `"I"` for
-            /// [upper Roman numerals](Self::UpperRoman), or `"א"` for
-            /// [Hebrew numerals](Self::Hebrew). Usually, this is the number one
-            /// represented in the system.
+            /// [upper Roman numerals](Self::UpperRoman), or `"a"` for
+            /// [lower Latin letters](Self::LowerLatin). Usually, this is the
+            /// number one represented in the system.
             pub fn from_shorthand(shorthand: &str) -> Option<Self> {
-                match shorthand {
-                    $( $( $shorthand => Some(Self::$Value), )? )*
-                    _ => None,
-                }
+                Self::iter().find(|system| system.shorthand() == Some(shorthand))
             }
 
             /// Returns the optional [shorthand](Self::from_shorthand) for a
             /// named numeral system.
-            pub fn shorthand(self) -> Option<&'static str> {
+            pub const fn shorthand(self) -> Option<&'static str> {
                 match self {
-                    $( $( Self::$Value => Some($shorthand), )? )*
-                    _ => None,
+                    $(
+                        $( #[cfg(feature = $feature)] )?
+                        Self::$Value => optional!($( $shorthand )?),
+                    )*
                 }
             }
 
-            /// Returns an iterator over the values of this type.
+            /// All the values of this type, in the order in which they are
+            /// declared.
+            ///
+            /// This order is deterministic, but not alphabetical. See
+            /// [`iter_by_name`](Self::iter_by_name).
+            pub const ALL: [Self; Self::COUNT] = [
+                $( $( #[cfg(feature = $feature)] )? Self::$Value, )*
+            ];
+
+            /// Returns an iterator over the values of this type, in the order
+            /// of [`ALL`](Self::ALL).
             pub fn iter() -> impl Iterator<Item = Self> {
-                [
-                    $( Self::$Value, )*
-                ].into_iter()
+                Self::ALL.into_iter()
+            }
+
+            /// Returns an iterator over the values of this type, in
+            /// lexicographic order of their [names](Self::name).
+            ///
+            /// Unlike the order of [`ALL`](Self::ALL), this order does not
+            /// depend on how the values are declared, so it suits generated
+            /// documentation and snapshot tests.
+            pub fn iter_by_name() -> impl Iterator<Item = Self> {
+                let mut all = Self::ALL;
+                all.sort_by_key(|value| value.name());
+                all.into_iter()
             }
         }
     };
@@ -91,7 +305,7 @@ declare_named! {
         /// The first twelve non-negative integers are represented as follows:
         ///
         /// > 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11
-        Arabic = "arabic" ("1"),
+        Arabic = "arabic" ("1") as 0,
 
         /// Circled decimal positional notation using
         /// [Western Arabic numerals](https://en.wikipedia.org/wiki/Arabic_numerals).
@@ -105,21 +319,38 @@ declare_named! {
         /// The first twelve non-negative integers are represented as follows:
         ///
         /// > ⓪, ①, ②, ③, ④, ⑤, ⑥, ⑦, ⑧, ⑨, ⑩, ⑪
-        CircledArabic = "arabic.o" ("①"),
+        CircledArabic = "arabic.o" ("①") in "symbols" as 1,
 
         /// Double circled decimal positional notation using
         /// [Western Arabic numerals](https://en.wikipedia.org/wiki/Arabic_numerals).
         ///
         /// ## Representable Numbers
         ///
-        /// Positive integers up to and including ten can be represented.
+        /// Positive integers up to and including ten can be represented. See
+        /// [`represent_circled`](Self::represent_circled) to continue after
+        /// ten.
         ///
         /// ## Example
         ///
         /// The integers from one to ten are represented as follows:
         ///
         /// > ⓵, ⓶, ⓷, ⓸, ⓹, ⓺, ⓻, ⓼, ⓽, ⓾
-        DoubleCircledArabic = "arabic.oo" ("⓵"),
+        DoubleCircledArabic = "arabic.oo" ("⓵") in "symbols" as 2,
+
+        /// Decimal positional notation using fullwidth
+        /// [Western Arabic numerals](https://en.wikipedia.org/wiki/Arabic_numerals),
+        /// which are as wide as CJK ideographs.
+        ///
+        /// ## Representable Numbers
+        ///
+        /// All non-negative integers can be represented.
+        ///
+        /// ## Example
+        ///
+        /// The first twelve non-negative integers are represented as follows:
+        ///
+        /// > ０, １, ２, ３, ４, ５, ６, ７, ８, ９, １０, １１
+        FullwidthArabic = "arabic.full" ("１") in "cjk" as 3,
 
         /// Lowercase
         /// [Latin letters](https://en.wikipedia.org/wiki/Latin_alphabet).
@@ -134,7 +365,7 @@ declare_named! {
         ///
         /// > a, b, c, d, e, f, g, h, i, j, k, l, m, n, o, p, q, r, s, t, u, v,
         /// > w, x, y, z, aa, ab
-        LowerLatin = "latin" ("a"),
+        LowerLatin = "latin" ("a") as 4,
 
         /// Uppercase
         /// [Latin letters](https://en.wikipedia.org/wiki/Latin_alphabet).
@@ -149,90 +380,185 @@ declare_named! {
         ///
         /// > A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P, Q, R, S, T, U, V,
         /// > W, X, Y, Z, AA, AB
-        UpperLatin = "Latin" ("A"),
+        UpperLatin = "Latin" ("A") as 5,
+
+        /// Circled lowercase
+        /// [Latin letters](https://en.wikipedia.org/wiki/Latin_alphabet), from
+        /// the [enclosed alphanumerics](https://en.wikipedia.org/wiki/Enclosed_Alphanumerics).
+        ///
+        /// ## Representable Numbers
+        ///
+        /// Positive integers up to and including twenty-six can be represented.
+        ///
+        /// ## Example
+        ///
+        /// The first twelve positive integers are represented as follows:
+        ///
+        /// > ⓐ, ⓑ, ⓒ, ⓓ, ⓔ, ⓕ, ⓖ, ⓗ, ⓘ, ⓙ, ⓚ, ⓛ
+        CircledLowerLatin = "latin.o" ("ⓐ") in "symbols" as 6,
+
+        /// Circled uppercase
+        /// [Latin letters](https://en.wikipedia.org/wiki/Latin_alphabet), from
+        /// the [enclosed alphanumerics](https://en.wikipedia.org/wiki/Enclosed_Alphanumerics).
+        ///
+        /// ## Representable Numbers
+        ///
+        /// Positive integers up to and including twenty-six can be represented.
+        ///
+        /// ## Example
+        ///
+        /// The first twelve positive integers are represented as follows:
+        ///
+        /// > Ⓐ, Ⓑ, Ⓒ, Ⓓ, Ⓔ, Ⓕ, Ⓖ, Ⓗ, Ⓘ, Ⓙ, Ⓚ, Ⓛ
+        CircledUpperLatin = "Latin.o" ("Ⓐ") in "symbols" as 7,
+
+        /// Negative circled uppercase
+        /// [Latin letters](https://en.wikipedia.org/wiki/Latin_alphabet), from
+        /// the [enclosed alphanumerics](https://en.wikipedia.org/wiki/Enclosed_Alphanumerics).
+        ///
+        /// ## Representable Numbers
+        ///
+        /// Positive integers up to and including twenty-six can be represented.
+        ///
+        /// ## Example
+        ///
+        /// The first twelve positive integers are represented as follows:
+        ///
+        /// > 🅐, 🅑, 🅒, 🅓, 🅔, 🅕, 🅖, 🅗, 🅘, 🅙, 🅚, 🅛
+        NegativeCircledLatin = "Latin.o.filled" ("🅐") in "symbols" as 8,
+
+        /// Squared uppercase
+        /// [Latin letters](https://en.wikipedia.org/wiki/Latin_alphabet), from
+        /// the [enclosed alphanumerics](https://en.wikipedia.org/wiki/Enclosed_Alphanumerics).
+        ///
+        /// ## Representable Numbers
+        ///
+        /// Positive integers up to and including twenty-six can be represented.
+        ///
+        /// ## Example
+        ///
+        /// The first twelve positive integers are represented as follows:
+        ///
+        /// > 🄰, 🄱, 🄲, 🄳, 🄴, 🄵, 🄶, 🄷, 🄸, 🄹, 🄺, 🄻
+        SquaredLatin = "Latin.square" ("🄰") in "symbols" as 9,
+
+        /// Negative squared uppercase
+        /// [Latin letters](https://en.wikipedia.org/wiki/Latin_alphabet), from
+        /// the [enclosed alphanumerics](https://en.wikipedia.org/wiki/Enclosed_Alphanumerics).
+        ///
+        /// ## Representable Numbers
+        ///
+        /// Positive integers up to and including twenty-six can be represented.
+        ///
+        /// ## Example
+        ///
+        /// The first twelve positive integers are represented as follows:
+        ///
+        /// > 🅰, 🅱, 🅲, 🅳, 🅴, 🅵, 🅶, 🅷, 🅸, 🅹, 🅺, 🅻
+        NegativeSquaredLatin = "Latin.square.filled" ("🅰") in "symbols" as 10,
 
         /// Lowercase
         /// [Roman numerals](https://en.wikipedia.org/wiki/Roman_numerals).
         ///
         /// ## Representable Numbers
         ///
-        /// All non-negative integers can be represented.
+        /// Non-negative integers up to and including 10,000,999,999 can be
+        /// represented, as the numeral for a million is repeated at most
+        /// [`MAX_REPETITIONS`](NumeralSystem::MAX_REPETITIONS) times. Numbers
+        /// above 3,999,999 are not conventional, see
+        /// [`strict_max`](Self::strict_max).
         ///
         /// ## Example
         ///
         /// The first twelve non-negative integers are represented as follows:
         ///
         /// > n, i, ii, iii, iv, v, vi, vii, viii, ix, x, xi
-        LowerRoman = "roman" ("i"),
+        LowerRoman = "roman" ("i") as 11,
 
         /// Uppercase
         /// [Roman numerals](https://en.wikipedia.org/wiki/Roman_numerals).
         ///
         /// ## Representable Numbers
         ///
-        /// All non-negative integers can be represented.
+        /// Non-negative integers up to and including 10,000,999,999 can be
+        /// represented, as the numeral for a million is repeated at most
+        /// [`MAX_REPETITIONS`](NumeralSystem::MAX_REPETITIONS) times. Numbers
+        /// above 3,999,999 are not conventional, see
+        /// [`strict_max`](Self::strict_max).
         ///
         /// ## Example
         ///
         /// The first twelve non-negative integers are represented as follows:
         ///
         /// > N, I, II, III, IV, V, VI, VII, VIII, IX, X, XI
-        UpperRoman = "Roman" ("I"),
+        UpperRoman = "Roman" ("I") as 12,
 
         /// Lowercase
         /// [Greek numerals](https://en.wikipedia.org/wiki/Greek_numerals).
         ///
         /// ## Representable Numbers
         ///
-        /// All non-negative integers can be represented.
+        /// Non-negative integers up to and including 90,008,999 can be
+        /// represented, as the numeral for nine thousand is repeated at most
+        /// [`MAX_REPETITIONS`](NumeralSystem::MAX_REPETITIONS) times. From ten
+        /// thousand on, see [`represent_greek`](Self::represent_greek) for
+        /// conventional representations.
         ///
         /// ## Example
         ///
         /// The first thirteen non-negative integers are represented as follows:
         ///
         /// > 𐆊, α, β, γ, δ, ε, στ, ζ, η, θ, ι, ια, ιβ
-        LowerGreek = "greek" ("α"),
+        LowerGreek = "greek" ("α") in "historic" as 13,
         /// Uppercase
         /// [Greek numerals](https://en.wikipedia.org/wiki/Greek_numerals).
         ///
         /// ## Representable Numbers
         ///
-        /// All non-negative integers can be represented.
+        /// Non-negative integers up to and including 90,008,999 can be
+        /// represented, as the numeral for nine thousand is repeated at most
+        /// [`MAX_REPETITIONS`](NumeralSystem::MAX_REPETITIONS) times. From ten
+        /// thousand on, see [`represent_greek`](Self::represent_greek) for
+        /// conventional representations.
         ///
         /// ## Example
         ///
         /// The first thirteen non-negative integers are represented as follows:
         ///
         /// > 𐆊, Α, Β, Γ, Δ, Ε, ΣΤ, Ζ, Η, Θ, Ι, ΙΑ, ΙΒ
-        UpperGreek = "Greek" ("Α"),
+        UpperGreek = "Greek" ("Α") in "historic" as 14,
 
         /// Lowercase
         /// [Armenian numerals](https://en.wikipedia.org/wiki/Armenian_numerals).
         ///
         /// ## Representable Numbers
         ///
-        /// All positive integers can be represented.
+        /// Positive integers up to and including 90,008,999 can be represented,
+        /// as the numeral for nine thousand is repeated at most
+        /// [`MAX_REPETITIONS`](NumeralSystem::MAX_REPETITIONS) times.
         ///
         /// ## Example
         ///
         /// The first twelve positive integers are represented as follows:
         ///
         /// > ա, բ, գ, դ, ե, զ, է, ը, թ, ժ, ժա, ժբ
-        LowerArmenian = "armenian" ("ա"),
+        LowerArmenian = "armenian" ("ա") in "historic" as 15,
 
         /// Uppercase
         /// [Armenian numerals](https://en.wikipedia.org/wiki/Armenian_numerals).
         ///
         /// ## Representable Numbers
         ///
-        /// All positive integers can be represented.
+        /// Positive integers up to and including 90,008,999 can be represented,
+        /// as the numeral for nine thousand is repeated at most
+        /// [`MAX_REPETITIONS`](NumeralSystem::MAX_REPETITIONS) times.
         ///
         /// ## Example
         ///
         /// The first twelve positive integers are represented as follows:
         ///
         /// > Ա, Բ, Գ, Դ, Ե, Զ, Է, Ը, Թ, Ժ, ԺԱ, ԺԲ
-        UpperArmenian = "Armenian" ("Ա"),
+        UpperArmenian = "Armenian" ("Ա") in "historic" as 16,
 
         /// [Hebrew alphabetic numerals](https://en.wikipedia.org/wiki/Hebrew_numerals)
         /// without a
@@ -241,7 +567,9 @@ declare_named! {
         ///
         /// ## Representable Numbers
         ///
-        /// All positive integers can be represented.
+        /// Positive integers up to and including 4,000,399 can be represented,
+        /// as the numeral for four hundred is repeated at most
+        /// [`MAX_REPETITIONS`](NumeralSystem::MAX_REPETITIONS) times.
         ///
         /// ## Example
         ///
@@ -253,7 +581,7 @@ declare_named! {
         /// > <span dir="auto">ז</span>, <span dir="auto">ח</span>,
         /// > <span dir="auto">ט</span>, <span dir="auto">י</span>,
         /// > <span dir="auto">יא</span>, <span dir="auto">יב</span>
-        Hebrew = "hebrew" ("א"),
+        Hebrew = "hebrew" ("א") in "rtl" as 17,
 
         /// Everyday ordinary simplified
         /// [Chinese numerals](https://en.wikipedia.org/wiki/Chinese_numerals#Ordinary_numerals).
@@ -267,7 +595,7 @@ declare_named! {
         /// The first thirteen non-negative integers are represented as follows:
         ///
         /// > 零, 一, 二, 三, 四, 五, 六, 七, 八, 九, 十, 十一, 十二
-        LowerSimplifiedChinese = "chinese.simple" ("一"),
+        LowerSimplifiedChinese = "chinese.simple" ("一") in "cjk" as 18,
 
         /// Financial ("capital") ordinary simplified
         /// [Chinese numerals](https://en.wikipedia.org/wiki/Chinese_numerals#Ordinary_numerals).
@@ -281,7 +609,7 @@ declare_named! {
         /// The first thirteen non-negative integers are represented as follows:
         ///
         /// > 零, 壹, 贰, 叁, 肆, 伍, 陆, 柒, 捌, 玖, 拾, 拾壹, 拾贰
-        UpperSimplifiedChinese = "Chinese.simple" ("壹"),
+        UpperSimplifiedChinese = "Chinese.simple" ("壹") in "cjk" as 19,
 
         /// Everyday ordinary traditional
         /// [Chinese numerals](https://en.wikipedia.org/wiki/Chinese_numerals#Ordinary_numerals).
@@ -295,7 +623,7 @@ declare_named! {
         /// The first thirteen non-negative integers are represented as follows:
         ///
         /// > 零, 一, 二, 三, 四, 五, 六, 七, 八, 九, 十, 十一, 十二
-        LowerTraditionalChinese = "chinese.trad",
+        LowerTraditionalChinese = "chinese.trad" in "cjk" as 20,
 
         /// Financial ("capital") ordinary traditional
         /// [Chinese numerals](https://en.wikipedia.org/wiki/Chinese_numerals#Ordinary_numerals).
@@ -309,7 +637,7 @@ declare_named! {
         /// The first thirteen non-negative integers are represented as follows:
         ///
         /// > 零, 壹, 貳, 參, 肆, 伍, 陸, 柒, 捌, 玖, 拾, 拾壹, 拾貳
-        UpperTraditionalChinese = "Chinese.trad",
+        UpperTraditionalChinese = "Chinese.trad" in "cjk" as 21,
 
         /// Hiragana in the gojūon order. Includes n but excludes wi and we.
         ///
@@ -322,7 +650,7 @@ declare_named! {
         /// The first twelve positive integers are represented as follows:
         ///
         /// > あ, い, う, え, お, か, き, く, け, こ, さ, し
-        HiraganaAiueo = "hiragana.aiueo" ("あ"),
+        HiraganaAiueo = "hiragana.aiueo" ("あ") in "cjk" as 22,
 
         /// Hiragana in the iroha order. Includes wi and we but excludes n.
         ///
@@ -335,7 +663,7 @@ declare_named! {
         /// The first twelve positive integers are represented as follows:
         ///
         /// > い, ろ, は, に, ほ, へ, と, ち, り, ぬ, る, を
-        HiraganaIroha = "hiragana.iroha" ("い"),
+        HiraganaIroha = "hiragana.iroha" ("い") in "cjk" as 23,
 
         /// Katakana in the gojūon order. Includes n but excludes wi and we.
         ///
@@ -348,7 +676,7 @@ declare_named! {
         /// The first twelve positive integers are represented as follows:
         ///
         /// > ア, イ, ウ, エ, オ, カ, キ, ク, ケ, コ, サ, シ
-        KatakanaAiueo = "katakana.aiueo" ("ア"),
+        KatakanaAiueo = "katakana.aiueo" ("ア") in "cjk" as 24,
 
         /// Katakana in the iroha order. Includes wi and we but excludes n.
         ///
@@ -361,7 +689,22 @@ declare_named! {
         /// The first twelve positive integers are represented as follows:
         ///
         /// > イ, ロ, ハ, ニ, ホ, ヘ, ト, チ, リ, ヌ, ル, ヲ
-        KatakanaIroha = "katakana.iroha" ("イ"),
+        KatakanaIroha = "katakana.iroha" ("イ") in "cjk" as 25,
+
+        /// Half-width katakana in the gojūon order, as used by legacy Japanese
+        /// formats and narrow columns. Includes n but excludes wi and we,
+        /// which have no half-width forms.
+        ///
+        /// ## Representable Numbers
+        ///
+        /// All positive integers can be represented.
+        ///
+        /// ## Example
+        ///
+        /// The first twelve positive integers are represented as follows:
+        ///
+        /// > ｱ, ｲ, ｳ, ｴ, ｵ, ｶ, ｷ, ｸ, ｹ, ｺ, ｻ, ｼ
+        KatakanaHalfwidth = "katakana.halfwidth" ("ｱ") in "cjk" as 39,
 
         // TODO: Improve Korean numeral systems based on https://github.com/typst/typst/issues/7335.
         /// Korean jamo.
@@ -375,7 +718,7 @@ declare_named! {
         /// The first twelve positive integers are represented as follows:
         ///
         /// > ㄱ, ㄴ, ㄷ, ㄹ, ㅁ, ㅂ, ㅅ, ㅇ, ㅈ, ㅊ, ㅋ, ㅌ
-        KoreanJamo = "korean.jamo" ("ㄱ"),
+        KoreanJamo = "korean.jamo" ("ㄱ") in "cjk" as 26,
 
         /// Korean syllables.
         ///
@@ -388,7 +731,7 @@ declare_named! {
         /// The first twelve positive integers are represented as follows:
         ///
         /// > 가, 나, 다, 라, 마, 바, 사, 아, 자, 차, 카, 타
-        KoreanSyllable = "korean.syllable" ("가"),
+        KoreanSyllable = "korean.syllable" ("가") in "cjk" as 27,
 
         /// Decimal positional notation using
         /// [Eastern Arabic numerals](https://en.wikipedia.org/wiki/Eastern_Arabic_numerals#Numerals).
@@ -407,7 +750,7 @@ declare_named! {
         /// > <span dir="auto">٦</span>, <span dir="auto">٧</span>,
         /// > <span dir="auto">٨</span>, <span dir="auto">٩</span>,
         /// > <span dir="auto">١٠</span>, <span dir="auto">١١</span>
-        EasternArabic = "arabic.eastern" ("١"),
+        EasternArabic = "arabic.eastern" ("١") in "rtl" as 28,
         /// Abjad order
         /// [Arabic letters](https://en.wikipedia.org/wiki/Abjad).
         ///
@@ -425,7 +768,7 @@ declare_named! {
         /// > <span dir="auto">ز</span>, <span dir="auto">ح</span>,
         /// > <span dir="auto">ط</span>, <span dir="auto">ي</span>,
         /// > <span dir="auto">ك</span>, <span dir="auto">ل</span>
-        ArabicAbjad = "arabic.abjad" ("أ"),
+        ArabicAbjad = "arabic.abjad" ("أ") in "rtl" as 29,
 
         /// Decimal positional notation using the Persian variant of
         /// [Eastern Arabic numerals](https://en.wikipedia.org/wiki/Eastern_Arabic_numerals#Numerals).
@@ -444,7 +787,7 @@ declare_named! {
         /// > <span dir="auto">۶</span>, <span dir="auto">۷</span>,
         /// > <span dir="auto">۸</span>, <span dir="auto">۹</span>,
         /// > <span dir="auto">۱۰</span>, <span dir="auto">۱۱</span>
-        Persian = "persian" ("۱"),
+        Persian = "persian" ("۱") in "rtl" as 30,
 
         /// Decimal positional notation using
         /// [Devanagari numerals](https://en.wikipedia.org/wiki/Devanagari_numerals).
@@ -458,7 +801,7 @@ declare_named! {
         /// The first twelve non-negative integers are represented as follows:
         ///
         /// > ०, १, २, ३, ४, ५, ६, ७, ८, ९, १०, ११
-        Devanagari = "devanagari" ("१"),
+        Devanagari = "devanagari" ("१") in "indic" as 31,
 
         /// Decimal positional notation using
         /// [Tibetan numerals](https://en.wikipedia.org/wiki/Tibetan_numerals).
@@ -472,7 +815,7 @@ declare_named! {
         /// The first twelve non-negative integers are represented as follows:
         ///
         /// > ༠, ༡, ༢, ༣, ༤, ༥, ༦, ༧, ༨, ༩, ༡༠, ༡༡
-        Tibetan = "tibetan" ("༡"),
+        Tibetan = "tibetan" ("༡") in "indic" as 32,
 
         /// Decimal positional notation using
         /// [Bengali numerals](https://en.wikipedia.org/wiki/Bengali_numerals).
@@ -486,7 +829,7 @@ declare_named! {
         /// The first twelve non-negative integers are represented as follows:
         ///
         /// > ০, ১, ২, ৩, ৪, ৫, ৬, ৭, ৮, ৯, ১০, ১১
-        Bengali = "bengali" ("১"),
+        Bengali = "bengali" ("১") in "indic" as 33,
 
         /// Bengali letters.
         ///
@@ -499,496 +842,1512 @@ declare_named! {
         /// The first twelve positive integers are represented as follows:
         ///
         /// > ক, খ, গ, ঘ, ঙ, চ, ছ, জ, ঝ, ঞ, ট, ঠ
-        BengaliLetters = "bengali.letter" ("ক"),
+        BengaliLetters = "bengali.letter" ("ক") in "indic" as 34,
 
         /// Repeating
         /// [note numbering symbols](https://en.wikipedia.org/wiki/Note_(typography)#Numbering_and_symbols).
         ///
         /// ## Representable Numbers
         ///
-        /// All positive integers can be represented.
+        /// Positive integers up to and including 60,000 can be represented, as
+        /// each symbol is repeated at most
+        /// [`MAX_REPETITIONS`](NumeralSystem::MAX_REPETITIONS) times.
         ///
         /// ## Example
         ///
         /// The first fourteen positive integers are represented as follows:
         ///
         /// > *, †, ‡, §, ¶, ‖, **, ††, ‡‡, §§, ¶¶, ‖‖, ***, †††
-        Symbols = "symbol" ("*"),
+        Symbols = "symbol" ("*") in "symbols" as 35,
+
+        /// Repeating asterisks, following the German convention for
+        /// [notes](https://en.wikipedia.org/wiki/Note_(typography)#Numbering_and_symbols).
+        ///
+        /// ## Representable Numbers
+        ///
+        /// Positive integers up to and including 10,000 can be represented, as
+        /// the asterisk is repeated at most
+        /// [`MAX_REPETITIONS`](NumeralSystem::MAX_REPETITIONS) times.
+        ///
+        /// ## Example
+        ///
+        /// The first five positive integers are represented as follows:
+        ///
+        /// > *, **, ***, ****, *****
+        GermanSymbols = "symbol.de" in "symbols" as 36,
+
+        /// Asterisks, following the French convention for
+        /// [notes](https://en.wikipedia.org/wiki/Note_(typography)#Numbering_and_symbols):
+        /// an asterisk, a double asterisk, and an asterism, which are then
+        /// repeated.
+        ///
+        /// ## Representable Numbers
+        ///
+        /// Positive integers up to and including 30,000 can be represented, as
+        /// each symbol is repeated at most
+        /// [`MAX_REPETITIONS`](NumeralSystem::MAX_REPETITIONS) times.
+        ///
+        /// ## Example
+        ///
+        /// The first six positive integers are represented as follows:
+        ///
+        /// > *, ⁑, ⁂, **, ⁑⁑, ⁂⁂
+        FrenchSymbols = "symbol.fr" in "symbols" as 37,
+
+        /// Repeating
+        /// [reference marks](https://en.wikipedia.org/wiki/Reference_mark),
+        /// following the Chinese and Japanese convention for notes.
+        ///
+        /// ## Representable Numbers
+        ///
+        /// Positive integers up to and including 10,000 can be represented, as
+        /// the reference mark is repeated at most
+        /// [`MAX_REPETITIONS`](NumeralSystem::MAX_REPETITIONS) times.
+        ///
+        /// ## Example
+        ///
+        /// The first three positive integers are represented as follows:
+        ///
+        /// > ※, ※※, ※※※
+        ChineseSymbols = "symbol.zh" in "symbols" as 38,
     }
 }
 
 impl NamedNumeralSystem {
     /// Returns the underlying numeral system.
-    pub fn system(self) -> NumeralSystem<'static> {
-        match self {
-            Self::Arabic => NumeralSystem::Positional(&[
-                "0", "1", "2", "3", "4", "5", "6", "7", "8", "9",
-            ]),
-
-            Self::CircledArabic => NumeralSystem::Fixed(&[
-                "⓪", "①", "②", "③", "④", "⑤", "⑥", "⑦", "⑧", "⑨", "⑩", "⑪", "⑫", "⑬",
-                "⑭", "⑮", "⑯", "⑰", "⑱", "⑲", "⑳", "㉑", "㉒", "㉓", "㉔", "㉕", "㉖",
-                "㉗", "㉘", "㉙", "㉚", "㉛", "㉜", "㉝", "㉞", "㉟", "㊱", "㊲", "㊳",
-                "㊴", "㊵", "㊶", "㊷", "㊸", "㊹", "㊺", "㊻", "㊼", "㊽", "㊾", "㊿",
-            ]),
-
-            Self::DoubleCircledArabic => NumeralSystem::ZerolessFixed(&[
-                "⓵", "⓶", "⓷", "⓸", "⓹", "⓺", "⓻", "⓼", "⓽", "⓾",
-            ]),
-
-            Self::LowerLatin => NumeralSystem::Bijective(&[
-                "a", "b", "c", "d", "e", "f", "g", "h", "i", "j", "k", "l", "m", "n",
-                "o", "p", "q", "r", "s", "t", "u", "v", "w", "x", "y", "z",
-            ]),
-
-            Self::UpperLatin => NumeralSystem::Bijective(&[
-                "A", "B", "C", "D", "E", "F", "G", "H", "I", "J", "K", "L", "M", "N",
-                "O", "P", "Q", "R", "S", "T", "U", "V", "W", "X", "Y", "Z",
-            ]),
-
-            Self::LowerRoman => NumeralSystem::Additive(&[
-                ("m̅", 1000000),
-                ("d̅", 500000),
-                ("c̅", 100000),
-                ("l̅", 50000),
-                ("x̅", 10000),
-                ("v̅", 5000),
-                ("i̅v̅", 4000),
-                ("m", 1000),
-                ("cm", 900),
-                ("d", 500),
-                ("cd", 400),
-                ("c", 100),
-                ("xc", 90),
-                ("l", 50),
-                ("xl", 40),
-                ("x", 10),
-                ("ix", 9),
-                ("v", 5),
-                ("iv", 4),
-                ("i", 1),
-                ("n", 0),
-            ]),
-
-            Self::UpperRoman => NumeralSystem::Additive(&[
-                ("M̅", 1000000),
-                ("D̅", 500000),
-                ("C̅", 100000),
-                ("L̅", 50000),
-                ("X̅", 10000),
-                ("V̅", 5000),
-                ("I̅V̅", 4000),
-                ("M", 1000),
-                ("CM", 900),
-                ("D", 500),
-                ("CD", 400),
-                ("C", 100),
-                ("XC", 90),
-                ("L", 50),
-                ("XL", 40),
-                ("X", 10),
-                ("IX", 9),
-                ("V", 5),
-                ("IV", 4),
-                ("I", 1),
-                ("N", 0),
-            ]),
-
-            Self::LowerGreek => NumeralSystem::Additive(&[
-                ("͵θ", 9000),
-                ("͵η", 8000),
-                ("͵ζ", 7000),
-                ("͵ϛ", 6000),
-                ("͵ε", 5000),
-                ("͵δ", 4000),
-                ("͵γ", 3000),
-                ("͵β", 2000),
-                ("͵α", 1000),
-                ("ϡ", 900),
-                ("ω", 800),
-                ("ψ", 700),
-                ("χ", 600),
-                ("φ", 500),
-                ("υ", 400),
-                ("τ", 300),
-                ("σ", 200),
-                ("ρ", 100),
-                ("ϟ", 90),
-                ("π", 80),
-                ("ο", 70),
-                ("ξ", 60),
-                ("ν", 50),
-                ("μ", 40),
-                ("λ", 30),
-                ("κ", 20),
-                ("ι", 10),
-                ("θ", 9),
-                ("η", 8),
-                ("ζ", 7),
-                ("στ", 6),
-                ("ε", 5),
-                ("δ", 4),
-                ("γ", 3),
-                ("β", 2),
-                ("α", 1),
-                ("𐆊", 0),
-            ]),
-
-            Self::UpperGreek => NumeralSystem::Additive(&[
-                ("͵Θ", 9000),
-                ("͵Η", 8000),
-                ("͵Ζ", 7000),
-                ("͵Ϛ", 6000),
-                ("͵Ε", 5000),
-                ("͵Δ", 4000),
-                ("͵Γ", 3000),
-                ("͵Β", 2000),
-                ("͵Α", 1000),
-                ("Ϡ", 900),
-                ("Ω", 800),
-                ("Ψ", 700),
-                ("Χ", 600),
-                ("Φ", 500),
-                ("Υ", 400),
-                ("Τ", 300),
-                ("Σ", 200),
-                ("Ρ", 100),
-                ("Ϟ", 90),
-                ("Π", 80),
-                ("Ο", 70),
-                ("Ξ", 60),
-                ("Ν", 50),
-                ("Μ", 40),
-                ("Λ", 30),
-                ("Κ", 20),
-                ("Ι", 10),
-                ("Θ", 9),
-                ("Η", 8),
-                ("Ζ", 7),
-                ("ΣΤ", 6),
-                ("Ε", 5),
-                ("Δ", 4),
-                ("Γ", 3),
-                ("Β", 2),
-                ("Α", 1),
-                ("𐆊", 0),
-            ]),
-
-            Self::LowerArmenian => NumeralSystem::Additive(&[
-                ("ք", 9000),
-                ("փ", 8000),
-                ("ւ", 7000),
-                ("ց", 6000),
-                ("ր", 5000),
-                ("տ", 4000),
-                ("վ", 3000),
-                ("ս", 2000),
-                ("ռ", 1000),
-                ("ջ", 900),
-                ("պ", 800),
-                ("չ", 700),
-                ("ո", 600),
-                ("շ", 500),
-                ("ն", 400),
-                ("յ", 300),
-                ("մ", 200),
-                ("ճ", 100),
-                ("ղ", 90),
-                ("ձ", 80),
-                ("հ", 70),
-                ("կ", 60),
-                ("ծ", 50),
-                ("խ", 40),
-                ("լ", 30),
-                ("ի", 20),
-                ("ժ", 10),
-                ("թ", 9),
-                ("ը", 8),
-                ("է", 7),
-                ("զ", 6),
-                ("ե", 5),
-                ("դ", 4),
-                ("գ", 3),
-                ("բ", 2),
-                ("ա", 1),
-            ]),
-
-            Self::UpperArmenian => NumeralSystem::Additive(&[
-                ("Ք", 9000),
-                ("Փ", 8000),
-                ("Ւ", 7000),
-                ("Ց", 6000),
-                ("Ր", 5000),
-                ("Տ", 4000),
-                ("Վ", 3000),
-                ("Ս", 2000),
-                ("Ռ", 1000),
-                ("Ջ", 900),
-                ("Պ", 800),
-                ("Չ", 700),
-                ("Ո", 600),
-                ("Շ", 500),
-                ("Ն", 400),
-                ("Յ", 300),
-                ("Մ", 200),
-                ("Ճ", 100),
-                ("Ղ", 90),
-                ("Ձ", 80),
-                ("Հ", 70),
-                ("Կ", 60),
-                ("Ծ", 50),
-                ("Խ", 40),
-                ("Լ", 30),
-                ("Ի", 20),
-                ("Ժ", 10),
-                ("Թ", 9),
-                ("Ը", 8),
-                ("Է", 7),
-                ("Զ", 6),
-                ("Ե", 5),
-                ("Դ", 4),
-                ("Գ", 3),
-                ("Բ", 2),
-                ("Ա", 1),
-            ]),
-
-            Self::Hebrew => NumeralSystem::Additive(&[
-                ("ת", 400),
-                ("ש", 300),
-                ("ר", 200),
-                ("ק", 100),
-                ("צ", 90),
-                ("פ", 80),
-                ("ע", 70),
-                ("ס", 60),
-                ("נ", 50),
-                ("מ", 40),
-                ("ל", 30),
-                ("כ", 20),
-                ("יט", 19),
-                ("יח", 18),
-                ("יז", 17),
-                ("טז", 16),
-                ("טו", 15),
-                ("י", 10),
-                ("ט", 9),
-                ("ח", 8),
-                ("ז", 7),
-                ("ו", 6),
-                ("ה", 5),
-                ("ד", 4),
-                ("ג", 3),
-                ("ב", 2),
-                ("א", 1),
-            ]),
-
-            Self::LowerSimplifiedChinese => {
-                NumeralSystem::Chinese(ChineseVariant::Simple, ChineseCase::Lower)
-            }
-
-            Self::UpperSimplifiedChinese => {
-                NumeralSystem::Chinese(ChineseVariant::Simple, ChineseCase::Upper)
-            }
-
-            Self::LowerTraditionalChinese => {
-                NumeralSystem::Chinese(ChineseVariant::Traditional, ChineseCase::Lower)
-            }
-
-            Self::UpperTraditionalChinese => {
-                NumeralSystem::Chinese(ChineseVariant::Traditional, ChineseCase::Upper)
-            }
-
-            Self::HiraganaAiueo => NumeralSystem::Bijective(&[
-                "あ", "い", "う", "え", "お", "か", "き", "く", "け", "こ", "さ", "し",
-                "す", "せ", "そ", "た", "ち", "つ", "て", "と", "な", "に", "ぬ", "ね",
-                "の", "は", "ひ", "ふ", "へ", "ほ", "ま", "み", "む", "め", "も", "や",
-                "ゆ", "よ", "ら", "り", "る", "れ", "ろ", "わ", "を", "ん",
-            ]),
-
-            Self::HiraganaIroha => NumeralSystem::Bijective(&[
-                "い", "ろ", "は", "に", "ほ", "へ", "と", "ち", "り", "ぬ", "る", "を",
-                "わ", "か", "よ", "た", "れ", "そ", "つ", "ね", "な", "ら", "む", "う",
-                "ゐ", "の", "お", "く", "や", "ま", "け", "ふ", "こ", "え", "て", "あ",
-                "さ", "き", "ゆ", "め", "み", "し", "ゑ", "ひ", "も", "せ", "す",
-            ]),
-
-            Self::KatakanaAiueo => NumeralSystem::Bijective(&[
-                "ア", "イ", "ウ", "エ", "オ", "カ", "キ", "ク", "ケ", "コ", "サ", "シ",
-                "ス", "セ", "ソ", "タ", "チ", "ツ", "テ", "ト", "ナ", "ニ", "ヌ", "ネ",
-                "ノ", "ハ", "ヒ", "フ", "ヘ", "ホ", "マ", "ミ", "ム", "メ", "モ", "ヤ",
-                "ユ", "ヨ", "ラ", "リ", "ル", "レ", "ロ", "ワ", "ヲ", "ン",
-            ]),
-
-            Self::KatakanaIroha => NumeralSystem::Bijective(&[
-                "イ", "ロ", "ハ", "ニ", "ホ", "ヘ", "ト", "チ", "リ", "ヌ", "ル", "ヲ",
-                "ワ", "カ", "ヨ", "タ", "レ", "ソ", "ツ", "ネ", "ナ", "ラ", "ム", "ウ",
-                "ヰ", "ノ", "オ", "ク", "ヤ", "マ", "ケ", "フ", "コ", "エ", "テ", "ア",
-                "サ", "キ", "ユ", "メ", "ミ", "シ", "ヱ", "ヒ", "モ", "セ", "ス",
-            ]),
-
-            Self::KoreanJamo => NumeralSystem::Bijective(&[
-                "ㄱ", "ㄴ", "ㄷ", "ㄹ", "ㅁ", "ㅂ", "ㅅ", "ㅇ", "ㅈ", "ㅊ", "ㅋ", "ㅌ",
-                "ㅍ", "ㅎ",
-            ]),
-
-            Self::KoreanSyllable => NumeralSystem::Bijective(&[
-                "가", "나", "다", "라", "마", "바", "사", "아", "자", "차", "카", "타",
-                "파", "하",
-            ]),
-
-            Self::EasternArabic => NumeralSystem::Positional(&[
-                "٠", "١", "٢", "٣", "٤", "٥", "٦", "٧", "٨", "٩",
-            ]),
-
-            Self::ArabicAbjad => NumeralSystem::Bijective(&[
-                "أ", "ب", "ج", "د", "ه‍", "و", "ز", "ح", "ط", "ي", "ك", "ل", "م", "ن",
-                "س", "ع", "ف", "ص", "ق", "ر", "ش", "ت", "ث", "خ", "ذ", "ض", "ظ", "غ",
-            ]),
-
-            Self::Persian => NumeralSystem::Positional(&[
-                "۰", "۱", "۲", "۳", "۴", "۵", "۶", "۷", "۸", "۹",
-            ]),
-
-            Self::Devanagari => NumeralSystem::Positional(&[
-                "०", "१", "२", "३", "४", "५", "६", "७", "८", "९",
-            ]),
-
-            Self::Tibetan => NumeralSystem::Positional(&[
-                "༠", "༡", "༢", "༣", "༤", "༥", "༦", "༧", "༨", "༩",
-            ]),
-
-            Self::Bengali => NumeralSystem::Positional(&[
-                "০", "১", "২", "৩", "৪", "৫", "৬", "৭", "৮", "৯",
-            ]),
-
-            Self::BengaliLetters => NumeralSystem::Bijective(&[
-                "ক", "খ", "গ", "ঘ", "ঙ", "চ", "ছ", "জ", "ঝ", "ঞ", "ট", "ঠ", "ড", "ঢ",
-                "ণ", "ত", "থ", "দ", "ধ", "ন", "প", "ফ", "ব", "ভ", "ম", "য", "র", "ল",
-                "শ", "ষ", "স", "হ",
-            ]),
-
-            Self::Symbols => NumeralSystem::Symbolic(&["*", "†", "‡", "§", "¶", "‖"]),
-        }
+    pub const fn system(self) -> NumeralSystem<'static> {
+        SYSTEMS[self as usize]
     }
-}
 
-impl From<NamedNumeralSystem> for NumeralSystem<'static> {
-    fn from(value: NamedNumeralSystem) -> Self {
-        value.system()
+    /// Represents a number in this named numeral system, as a string.
+    ///
+    /// The representations of the numbers up to 100 are computed once per
+    /// named numeral system, on first use, and reused afterwards. Larger
+    /// numbers are represented as with [`NumeralSystem::represent`]. To cache
+    /// other numbers, use [`NumeralSystem::precompute`].
+    pub fn represent_cached(
+        self,
+        number: u64,
+    ) -> Result<Cow<'static, str>, RepresentationError> {
+        static CACHE: [OnceLock<Precomputed<'static>>; NamedNumeralSystem::COUNT] =
+            [const { OnceLock::new() }; NamedNumeralSystem::COUNT];
+        CACHE[self as usize]
+            .get_or_init(|| self.system().precompute(..=100))
+            .represent(number)
     }
-}
 
-/// Represents a numeral system of one of multiple predefined kinds.
-///
-/// Values of this type can be constructed manually. Alternatively, common
-/// numeral systems are listed as the values of [`NamedNumeralSystem`].
-#[non_exhaustive]
-#[derive(Debug, Clone, Copy)]
-pub enum NumeralSystem<'a> {
-    /// A big-endian
-    /// [positional notation](https://en.wikipedia.org/wiki/Positional_notation)
-    /// system.
+    /// Returns the largest number that this named numeral system represents
+    /// by its conventions, if it is smaller than the numbers it can represent
+    /// at all.
     ///
-    /// ## Representable Numbers
+    /// Above this number, additive numeral systems keep repeating their
+    /// largest numeral, such as `M̅M̅M̅M̅` for four million in Roman numerals,
+    /// or combine numerals that are not meant to be combined, such as `͵θ͵α`
+    /// for ten thousand in Greek numerals. The maximum is 3,999,999 for Roman
+    /// numerals, 9,999 for Greek and Armenian numerals, and 999 for Hebrew
+    /// numerals, whose thousands are written separately. It is `None` for the
+    /// other named numeral systems.
+    pub const fn strict_max(self) -> Option<u64> {
+        match self {
+            Self::LowerRoman | Self::UpperRoman => Some(3_999_999),
+            #[cfg(feature = "historic")]
+            Self::LowerGreek
+            | Self::UpperGreek
+            | Self::LowerArmenian
+            | Self::UpperArmenian => Some(9_999),
+            #[cfg(feature = "rtl")]
+            Self::Hebrew => Some(999),
+            _ => None,
+        }
+    }
+
+    /// Tries to represent a number in this named numeral system, without
+    /// exceeding its [conventional maximum](Self::strict_max).
     ///
-    /// A numeral system of this kind can represent any non-negative integer.
+    /// This is the same as [`NumeralSystem::represent`], except that larger
+    /// numbers are reported as [`Error::OutOfRange`](crate::Error::OutOfRange)
+    /// rather than represented unconventionally.
     ///
     /// ## Example
     ///
-    /// With the digits `['0', '1', '2']`, we obtain the ternary numeral system:
+    /// ```
+    /// use codex::Error;
+    /// use codex::numeral_systems::NamedNumeralSystem;
     ///
-    /// | Number | Representation |
-    /// |--------|----------------|
-    /// | 0      | 0              |
-    /// | 1      | 1              |
-    /// | 2      | 2              |
-    /// | 3      | 10             |
-    /// | 4      | 12             |
-    /// | 5      | 12             |
-    /// | 6      | 20             |
-    Positional(&'a [&'a str]),
+    /// let roman = NamedNumeralSystem::UpperRoman;
+    /// assert_eq!(roman.system().represent(4_000_000).unwrap().to_string(), "M̅M̅M̅M̅");
+    /// assert!(matches!(roman.represent_strict(4_000_000), Err(Error::OutOfRange)));
+    /// ```
+    pub fn represent_strict(
+        self,
+        number: u64,
+    ) -> Result<impl Display + use<>, crate::Error> {
+        if self.strict_max().is_some_and(|max| number > max) {
+            return Err(crate::Error::OutOfRange);
+        }
+        Ok(self.system().represent(number)?)
+    }
 
-    /// A big-endian
-    /// [bijective numeration](https://en.wikipedia.org/wiki/Bijective_numeration)
-    /// system. This is similar to positional notation, but without a digit for
-    /// zero.
+    /// Returns the named numeral system associated with a counting symbol.
     ///
-    /// ## Representable Numbers
+    /// Counting symbols are the [shorthands](Self::from_shorthand) that
+    /// consist of a single character, such as `'1'`, `'a'`, or `'一'`. They
+    /// are the same as the counting symbols of
+    /// [Typst's numbering patterns](https://typst.app/docs/reference/model/numbering/).
+    pub fn from_marker(marker: char) -> Option<Self> {
+        Self::iter().find(|system| system.marker() == Some(marker))
+    }
+
+    /// Returns the optional [counting symbol](Self::from_marker) for a named
+    /// numeral system.
+    pub fn marker(self) -> Option<char> {
+        let mut chars = self.shorthand()?.chars();
+        let marker = chars.next()?;
+        chars.next().is_none().then_some(marker)
+    }
+
+    /// Returns the underlying numeral system, with the archaic digits of Greek
+    /// numerals written as specified.
     ///
-    /// A numeral system of this kind can represent any positive integer.
+    /// For named numeral systems other than
+    /// [`LowerGreek`](Self::LowerGreek) and [`UpperGreek`](Self::UpperGreek),
+    /// this is the same as [`system`](Self::system).
     ///
     /// ## Example
     ///
-    /// With the digits `['A', 'B', 'C']`, we obtain a system similar to one
-    /// commonly used to number columns in spreadsheet software:
+    /// ```
+    /// use codex::numeral_systems::{GreekDigits, NamedNumeralSystem};
     ///
-    /// | Number | Representation |
-    /// |--------|----------------|
-    /// | 1      | A              |
-    /// | 2      | B              |
-    /// | 3      | C              |
-    /// | 4      | AA             |
-    /// | 5      | AB             |
-    /// | 6      | AC             |
-    /// | 7      | BA             |
-    Bijective(&'a [&'a str]),
+    /// let greek = NamedNumeralSystem::LowerGreek;
+    /// assert_eq!(greek.system().represent(6996).unwrap().to_string(), "͵ϛϡϟστ");
+    ///
+    /// let system = greek.with_greek_digits(GreekDigits::COMPATIBLE);
+    /// assert_eq!(system.represent(6996).unwrap().to_string(), "͵στͳϙστ");
+    /// ```
+    #[cfg(feature = "historic")]
+    pub fn with_greek_digits(self, digits: GreekDigits) -> NumeralSystem<'static> {
+        static TABLES: [OnceLock<Vec<(&'static str, u64)>>; 16] =
+            [const { OnceLock::new() }; 16];
+        let upper = match self {
+            Self::LowerGreek => false,
+            Self::UpperGreek => true,
+            _ => return self.system(),
+        };
+        let NumeralSystem::Additive(numerals) = self.system() else { unreachable!() };
+        let index = usize::from(upper) << 3
+            | usize::from(digits.digraph_stigma) << 2
+            | usize::from(digits.archaic_koppa) << 1
+            | usize::from(digits.archaic_sampi);
+        NumeralSystem::Additive(TABLES[index].get_or_init(|| {
+            numerals
+                .iter()
+                .map(|&(numeral, value)| match (value, upper) {
+                    (6000, false) if digits.digraph_stigma => ("͵στ", value),
+                    (6000, true) if digits.digraph_stigma => ("͵ΣΤ", value),
+                    (90, false) if digits.archaic_koppa => ("ϙ", value),
+                    (90, true) if digits.archaic_koppa => ("Ϙ", value),
+                    (900, false) if digits.archaic_sampi => ("ͳ", value),
+                    (900, true) if digits.archaic_sampi => ("Ͳ", value),
+                    _ => (numeral, value),
+                })
+                .collect()
+        }))
+    }
 
-    /// An additive
-    /// [sign-value notation](https://en.wikipedia.org/wiki/Sign-value_notation)
-    /// system.
+    /// Represents a number in this named numeral system, writing Greek
+    /// numerals from ten thousand on as specified.
     ///
-    /// The numerals must be specified by decreasing value.
+    /// Greek numerals only have letters up to nine thousand, so larger
+    /// numbers need another convention. Below ten thousand, and for named
+    /// numeral systems other than [`LowerGreek`](Self::LowerGreek) and
+    /// [`UpperGreek`](Self::UpperGreek), this is the same as
+    /// [`represent_cached`](Self::represent_cached).
     ///
-    /// ## Representable Numbers
+    /// ## Example
     ///
-    /// A numeral system of this kind can represent any positive integer. If a
-    /// numeral with null value is provided, the system can represent zero as
-    /// well.
+    /// ```
+    /// use codex::numeral_systems::{GreekMyriads, NamedNumeralSystem};
     ///
-    /// ## Examples
+    /// let greek = NamedNumeralSystem::LowerGreek;
+    /// let represent = |myriads| greek.represent_greek(12_345, myriads).unwrap();
+    /// assert_eq!(represent(GreekMyriads::Myriad), "αΜ͵βτμε");
+    /// assert_eq!(represent(GreekMyriads::Keraia), "͵ι͵βτμε");
+    /// ```
+    #[cfg(feature = "historic")]
+    pub fn represent_greek(
+        self,
+        number: u64,
+        myriads: GreekMyriads,
+    ) -> Result<Cow<'static, str>, RepresentationError> {
+        if !matches!(self, Self::LowerGreek | Self::UpperGreek) || number < 10_000 {
+            return self.represent_cached(number);
+        }
+        let system = self.system();
+        let NumeralSystem::Additive(numerals) = system else { unreachable!() };
+        let radix = match myriads {
+            GreekMyriads::Myriad => 10_000,
+            GreekMyriads::Keraia => 1000,
+        };
+
+        // The groups of digits, from least to most significant. Any `u64` has
+        // at most seven groups of three digits.
+        let mut groups = [0; 7];
+        let mut rest = number;
+        for group in &mut groups {
+            *group = rest % radix;
+            rest /= radix;
+        }
+
+        let mut out = String::new();
+        for (k, &group) in groups.iter().enumerate().rev() {
+            if group == 0 {
+                continue;
+            }
+            if k == 0 || myriads == GreekMyriads::Myriad {
+                write!(out, "{}", system.represent(group)?).unwrap();
+                out.extend(std::iter::repeat_n('Μ', k));
+                continue;
+            }
+            // Units are written with the letters of the thousands, so that six
+            // is a single letter.
+            let digits = [(group / 100, 100), (group / 10 % 10, 10), (group % 10, 1000)];
+            for (digit, scale) in digits {
+                if let Some(&(numeral, _)) = numerals
+                    .iter()
+                    .find(|&&(_, value)| digit > 0 && value == digit * scale)
+                {
+                    out.extend(std::iter::repeat_n('͵', k));
+                    out.push_str(numeral.trim_start_matches('͵'));
+                }
+            }
+        }
+        Ok(Cow::Owned(out))
+    }
+
+    /// Returns the underlying numeral system, avoiding the combinations of
+    /// Hebrew numerals with the specified strictness.
     ///
-    /// With the numerals `[("V", 5), ("IV", 4), ("I", 1)]`, we obtain the start
-    /// of the Roman numeral system:
+    /// For named numeral systems other than [`Hebrew`](Self::Hebrew), this is
+    /// the same as [`system`](Self::system).
     ///
-    /// | Number | Representation |
-    /// |--------|----------------|
-    /// | 1      | I              |
-    /// | 2      | II             |
-    /// | 3      | III            |
-    /// | 4      | IV             |
-    /// | 5      | V              |
-    /// | 6      | VI             |
-    /// | 7      | VII            |
+    /// ## Example
     ///
-    /// With the numerals `[("I", 1), ("Z", 0)]`, we obtain a unary system that
-    /// can represent zero with a non-empty string:
+    /// ```
+    /// use codex::numeral_systems::{HebrewStrictness, NamedNumeralSystem};
     ///
-    /// | Number | Representation |
-    /// |--------|----------------|
-    /// | 0      | Z              |
-    /// | 1      | I              |
-    /// | 2      | II             |
-    /// | 3      | III            |
-    /// | 4      | IIII           |
-    /// | 5      | IIIII          |
-    Additive(&'a [(&'a str, u64)]),
-
-    /// A system that uses repeating symbols.
+    /// let hebrew = NamedNumeralSystem::Hebrew;
+    /// assert_eq!(hebrew.system().represent(275).unwrap().to_string(), "רעה");
     ///
-    /// ## Representable Numbers
+    /// let system = hebrew.with_hebrew_strictness(HebrewStrictness::Strict);
+    /// assert_eq!(system.represent(275).unwrap().to_string(), "ערה");
+    /// ```
+    #[cfg(feature = "rtl")]
+    pub fn with_hebrew_strictness(
+        self,
+        strictness: HebrewStrictness,
+    ) -> NumeralSystem<'static> {
+        match (self, strictness) {
+            (Self::Hebrew, HebrewStrictness::Strict) => {
+                NumeralSystem::Additive(STRICT_HEBREW)
+            }
+            _ => self.system(),
+        }
+    }
+
+    /// Returns the underlying numeral system, with the digits four, five, and
+    /// six of Arabic-script numerals written as specified.
     ///
-    /// A numeral system of this kind can represent any positive integer.
+    /// For named numeral systems other than
+    /// [`EasternArabic`](Self::EasternArabic) and [`Persian`](Self::Persian),
+    /// this is the same as [`system`](Self::system).
     ///
     /// ## Example
     ///
-    /// With the symbols `['A', 'B', 'C']`, we obtain the following
-    /// representations:
+    /// ```
+    /// use codex::numeral_systems::{ArabicDigits, NamedNumeralSystem};
     ///
-    /// | Number | Representation |
-    /// |--------|----------------|
-    /// | 1      | A              |
-    /// | 2      | B              |
-    /// | 3      | C              |
+    /// let arabic = NamedNumeralSystem::EasternArabic;
+    /// assert_eq!(arabic.system().represent(456).unwrap().to_string(), "٤٥٦");
+    ///
+    /// let system = arabic.with_arabic_digits(ArabicDigits::EXTENDED);
+    /// assert_eq!(system.represent(456).unwrap().to_string(), "۴۵۶");
+    /// ```
+    #[cfg(feature = "rtl")]
+    pub fn with_arabic_digits(self, digits: ArabicDigits) -> NumeralSystem<'static> {
+        static TABLES: [OnceLock<Vec<&'static str>>; 16] =
+            [const { OnceLock::new() }; 16];
+        let persian = match self {
+            Self::EasternArabic => false,
+            Self::Persian => true,
+            _ => return self.system(),
+        };
+        let NumeralSystem::Positional(base) = self.system() else { unreachable!() };
+        let index = usize::from(persian) << 3
+            | usize::from(digits.extended_four) << 2
+            | usize::from(digits.extended_five) << 1
+            | usize::from(digits.extended_six);
+        NumeralSystem::Positional(TABLES[index].get_or_init(|| {
+            let mut table = base.to_vec();
+            table[4] = if digits.extended_four { "۴" } else { "٤" };
+            table[5] = if digits.extended_five { "۵" } else { "٥" };
+            table[6] = if digits.extended_six { "۶" } else { "٦" };
+            table
+        }))
+    }
+
+    /// Returns the named numeral system of the digits natively used to write
+    /// a language in the Arabic script, given as a BCP 47 language subtag
+    /// such as `"ckb"`, if it is known.
+    ///
+    /// Arabic (`ar`), Central Kurdish (`ckb`), and Sindhi (`sd`) use
+    /// [Eastern Arabic numerals](Self::EasternArabic). Persian (`fa`), Urdu
+    /// (`ur`), Kashmiri (`ks`), and Pashto (`ps`) use their
+    /// [extended variant](Self::Persian). The regional shapes of the digits,
+    /// such as those of four, six, and seven in Urdu and Sindhi, are not
+    /// distinguished in Unicode, and are chosen by fonts according to the
+    /// language of the text.
+    ///
+    /// These are the native numbering systems of the languages in CLDR.
+    #[cfg(feature = "rtl")]
+    pub fn from_arabic_script_language(language: &str) -> Option<Self> {
+        match language {
+            "ar" | "ckb" | "sd" => Some(Self::EasternArabic),
+            "fa" | "ur" | "ks" | "ps" => Some(Self::Persian),
+            _ => None,
+        }
+    }
+
+    /// Represents a number in this named numeral system, continuing the
+    /// sequence of Korean letters as specified once they are exhausted.
+    ///
+    /// For named numeral systems other than
+    /// [`KoreanJamo`](Self::KoreanJamo) and
+    /// [`KoreanSyllable`](Self::KoreanSyllable), this is the same as
+    /// [`represent_cached`](Self::represent_cached).
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use codex::numeral_systems::{KoreanContinuation, NamedNumeralSystem};
+    ///
+    /// let jamo = NamedNumeralSystem::KoreanJamo;
+    /// let represent = |continuation| jamo.represent_korean(15, continuation).unwrap();
+    /// assert_eq!(represent(KoreanContinuation::Bijective), "ㄱㄱ");
+    /// assert_eq!(represent(KoreanContinuation::Doubled), "ㄱㄱ");
+    /// assert_eq!(represent(KoreanContinuation::Digits), "15");
+    /// assert_eq!(jamo.represent_korean(16, KoreanContinuation::Doubled).unwrap(), "ㄴㄴ");
+    /// assert!(jamo.represent_korean(15, KoreanContinuation::Error).is_err());
+    /// ```
+    #[cfg(feature = "cjk")]
+    pub fn represent_korean(
+        self,
+        number: u64,
+        continuation: KoreanContinuation,
+    ) -> Result<Cow<'static, str>, RepresentationError> {
+        let (Self::KoreanJamo | Self::KoreanSyllable, NumeralSystem::Bijective(letters)) =
+            (self, self.system())
+        else {
+            return self.represent_cached(number);
+        };
+        let fixed = NumeralSystem::ZerolessFixed(letters);
+        match continuation {
+            KoreanContinuation::Bijective => self.represent_cached(number),
+            KoreanContinuation::Doubled => {
+                Ok(NumeralSystem::Symbolic(letters).represent(number)?.to_string().into())
+            }
+            KoreanContinuation::Digits if number > letters.len() as u64 => {
+                Self::Arabic.represent_cached(number)
+            }
+            KoreanContinuation::Digits | KoreanContinuation::Error => {
+                Ok(fixed.represent(number)?.to_string().into())
+            }
+        }
+    }
+
+    /// Represents a number in this named numeral system, in the given form.
+    ///
+    /// Chinese ordinal numbers are prefixed with 第, and Chinese years are
+    /// written digit by digit. Hebrew years are written without their
+    /// thousands, unless they are a multiple of a thousand, and marked with a
+    /// gershayim or a geresh. In the other named numeral systems, all the forms
+    /// are written the same, so this is the same as
+    /// [`represent_cached`](Self::represent_cached). To spell numbers out in
+    /// words, see [`rbnf::RuleSets::spellout`].
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use codex::numeral_systems::{NamedNumeralSystem, NumberForm};
+    ///
+    /// let roman = NamedNumeralSystem::UpperRoman;
+    /// assert_eq!(roman.represent_form(2024, NumberForm::Year).unwrap(), "MMXXIV");
+    ///
+    /// # #[cfg(feature = "cjk")] {
+    /// let chinese = NamedNumeralSystem::LowerSimplifiedChinese;
+    /// assert_eq!(chinese.represent_form(3, NumberForm::Cardinal).unwrap(), "三");
+    /// assert_eq!(chinese.represent_form(3, NumberForm::Ordinal).unwrap(), "第三");
+    /// assert_eq!(chinese.represent_form(2024, NumberForm::Year).unwrap(), "二〇二四");
+    /// # }
+    ///
+    /// # #[cfg(feature = "rtl")] {
+    /// let hebrew = NamedNumeralSystem::Hebrew;
+    /// assert_eq!(hebrew.represent_form(5784, NumberForm::Year).unwrap(), "תשפ״ד");
+    /// # }
+    /// ```
+    pub fn represent_form(
+        self,
+        number: u64,
+        form: NumberForm,
+    ) -> Result<Cow<'static, str>, RepresentationError> {
+        match (self.system(), form) {
+            #[cfg(feature = "cjk")]
+            (NumeralSystem::Chinese(_, _), NumberForm::Ordinal) => {
+                Ok(format!("第{}", self.represent_cached(number)?).into())
+            }
+            #[cfg(feature = "cjk")]
+            (NumeralSystem::Chinese(variant, case), NumberForm::Year) => {
+                let digits = match (variant, case) {
+                    (_, ChineseCase::Lower) => {
+                        &["〇", "一", "二", "三", "四", "五", "六", "七", "八", "九"]
+                    }
+                    (ChineseVariant::Simple, ChineseCase::Upper) => {
+                        &["零", "壹", "贰", "叁", "肆", "伍", "陆", "柒", "捌", "玖"]
+                    }
+                    (ChineseVariant::Traditional, ChineseCase::Upper) => {
+                        &["零", "壹", "貳", "參", "肆", "伍", "陸", "柒", "捌", "玖"]
+                    }
+                };
+                Ok(NumeralSystem::Positional(digits)
+                    .represent(number)?
+                    .to_string()
+                    .into())
+            }
+            #[cfg(feature = "rtl")]
+            (_, NumberForm::Year) if self == Self::Hebrew => {
+                let elided = if number.is_multiple_of(1000) {
+                    number / 1000
+                } else {
+                    number % 1000
+                };
+                let mut year = self.represent_cached(elided)?.into_owned();
+                let last = year.char_indices().next_back().map_or(0, |(i, _)| i);
+                if last == 0 {
+                    year.push('׳');
+                } else {
+                    year.insert(last, '״');
+                }
+                Ok(year.into())
+            }
+            _ => self.represent_cached(number),
+        }
+    }
+
+    /// Represents the year of a Japanese era in this named numeral system.
+    ///
+    /// The first year of an era is written 元 (gannen) rather than with the
+    /// number one, such as in 令和元年. Other years are written as with
+    /// [`represent_cached`](Self::represent_cached), usually with
+    /// [Chinese numerals](Self::LowerTraditionalChinese) or
+    /// [Arabic numerals](Self::Arabic).
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use codex::numeral_systems::NamedNumeralSystem;
+    ///
+    /// let arabic = NamedNumeralSystem::Arabic;
+    /// assert_eq!(arabic.represent_era_year(1).unwrap(), "元");
+    /// assert_eq!(arabic.represent_era_year(6).unwrap(), "6");
+    /// ```
+    pub fn represent_era_year(
+        self,
+        year: u64,
+    ) -> Result<Cow<'static, str>, RepresentationError> {
+        match year {
+            0 => Err(RepresentationError::Zero),
+            1 => Ok("元".into()),
+            _ => self.represent_cached(year),
+        }
+    }
+
+    /// Represents a number in this named numeral system, continuing the
+    /// sequence of double circled numbers as specified after ten.
+    ///
+    /// For named numeral systems other than
+    /// [`DoubleCircledArabic`](Self::DoubleCircledArabic), this is the same as
+    /// [`represent_cached`](Self::represent_cached).
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use codex::numeral_systems::{CircledContinuation, NamedNumeralSystem};
+    ///
+    /// let circled = NamedNumeralSystem::DoubleCircledArabic;
+    /// let represent = |n, continuation| circled.represent_circled(n, continuation);
+    /// assert!(represent(11, CircledContinuation::None).is_err());
+    /// assert_eq!(represent(11, CircledContinuation::NegativeCircled).unwrap(), "⓫");
+    /// ```
+    #[cfg(feature = "symbols")]
+    pub fn represent_circled(
+        self,
+        number: u64,
+        continuation: CircledContinuation,
+    ) -> Result<Cow<'static, str>, RepresentationError> {
+        const NEGATIVE_CIRCLED: NumeralSystem = NumeralSystem::ZerolessFixed(&[
+            "⓫", "⓬", "⓭", "⓮", "⓯", "⓰", "⓱", "⓲", "⓳", "⓴",
+        ]);
+        if self != Self::DoubleCircledArabic || number <= 10 {
+            return self.represent_cached(number);
+        }
+        match continuation {
+            CircledContinuation::None => Err(RepresentationError::TooLarge),
+            CircledContinuation::NegativeCircled => {
+                Ok(NEGATIVE_CIRCLED.represent(number - 10)?.to_string().into())
+            }
+        }
+    }
+
+    /// Returns the underlying numeral system, with the kana specified by the
+    /// options.
+    ///
+    /// For named numeral systems other than
+    /// [`HiraganaAiueo`](Self::HiraganaAiueo),
+    /// [`HiraganaIroha`](Self::HiraganaIroha),
+    /// [`KatakanaAiueo`](Self::KatakanaAiueo), and
+    /// [`KatakanaIroha`](Self::KatakanaIroha), this is the same as
+    /// [`system`](Self::system). The order of the kana is that of the named
+    /// numeral system.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use codex::numeral_systems::{KanaOptions, NamedNumeralSystem};
+    ///
+    /// let aiueo = NamedNumeralSystem::HiraganaAiueo;
+    /// assert_eq!(aiueo.system().represent(44).unwrap().to_string(), "わ");
+    /// assert_eq!(aiueo.system().represent(45).unwrap().to_string(), "を");
+    ///
+    /// let grid = aiueo.with_kana_options(KanaOptions::GRID);
+    /// assert_eq!(grid.represent(45).unwrap().to_string(), "ゐ");
+    /// assert_eq!(grid.represent(48).unwrap().to_string(), "ん");
+    /// ```
+    #[cfg(feature = "cjk")]
+    pub fn with_kana_options(self, options: KanaOptions) -> NumeralSystem<'static> {
+        static TABLES: [OnceLock<Vec<&'static str>>; 32] =
+            [const { OnceLock::new() }; 32];
+        let (kind, [wi, we, wo, n]) = match self {
+            Self::HiraganaAiueo => (0, ["ゐ", "ゑ", "を", "ん"]),
+            Self::HiraganaIroha => (1, ["ゐ", "ゑ", "を", "ん"]),
+            Self::KatakanaAiueo => (2, ["ヰ", "ヱ", "ヲ", "ン"]),
+            Self::KatakanaIroha => (3, ["ヰ", "ヱ", "ヲ", "ン"]),
+            _ => return self.system(),
+        };
+        let NumeralSystem::Bijective(kana) = self.system() else { unreachable!() };
+        let index = kind << 3
+            | usize::from(options.n) << 2
+            | usize::from(options.wi) << 1
+            | usize::from(options.we);
+        NumeralSystem::Bijective(TABLES[index].get_or_init(|| {
+            let mut table: Vec<&str> = kana
+                .iter()
+                .copied()
+                .filter(|&k| k != n && (k != wi || options.wi) && (k != we || options.we))
+                .collect();
+            if !kana.contains(&wi) {
+                // In the gojūon order, wi and we belong to the w row, before wo.
+                let i = table.iter().position(|&k| k == wo).unwrap();
+                let row = [(wi, options.wi), (we, options.we)];
+                table.splice(
+                    i..i,
+                    row.into_iter().filter_map(|(k, kept)| kept.then_some(k)),
+                );
+            }
+            if options.n {
+                table.push(n);
+            }
+            table
+        }))
+    }
+}
+
+/// Truncates a numeral to a width, ending it with an ellipsis (…) if it is
+/// wider.
+///
+/// The width is counted as by [`NumeralSystem::width`], and the numeral is
+/// only cut before a character that is not a combining mark, so that
+/// combining marks, such as the vinculum of large Roman numerals, are never
+/// separated from their characters. This allows long numerals, such as large
+/// Latin labels or repeated symbols, to be shown in narrow user interfaces.
+///
+/// With a width of zero, the result is empty.
+///
+/// ## Example
+///
+/// ```
+/// use codex::numeral_systems::{NamedNumeralSystem, truncate_numeral};
+///
+/// let roman = NamedNumeralSystem::UpperRoman.system();
+/// let numeral = roman.represent(8_888).unwrap().to_string();
+/// assert_eq!(numeral, "V̅MMMDCCCLXXXVIII");
+/// assert_eq!(truncate_numeral(&numeral, 3), "V̅M…");
+/// assert_eq!(truncate_numeral("XII", 3), "XII");
+/// ```
+pub fn truncate_numeral(numeral: &str, width: usize) -> Cow<'_, str> {
+    if width == 0 {
+        return Cow::Borrowed("");
+    }
+    // The byte offsets at which the numeral can be cut.
+    let mut starts = numeral.char_indices().filter(|&(_, c)| !is_mark(c));
+    match starts.nth(width - 1).zip(starts.next()) {
+        Some(((end, _), _)) => Cow::Owned(format!("{}…", &numeral[..end])),
+        None => Cow::Borrowed(numeral),
+    }
+}
+
+/// Replaces the Roman numeral characters of Unicode, such as `Ⅻ` (U+216B) or
+/// `ⅳ` (U+2173), with the ASCII letters they stand for, in the same case.
+///
+/// Roman numerals are represented with ASCII letters, while scanned or
+/// recognized text often mixes them with these characters. Other characters
+/// are kept as is. [Parsing](NumeralSystem::parse) Roman numerals does this
+/// first, so that both are accepted.
+///
+/// ## Example
+///
+/// ```
+/// use codex::numeral_systems::{NamedNumeralSystem, normalize_roman};
+///
+/// assert_eq!(normalize_roman("Ⅻ"), "XII");
+/// assert_eq!(normalize_roman("ⅿcmⅹcix"), "mcmxcix");
+///
+/// let roman = NamedNumeralSystem::UpperRoman.system();
+/// assert_eq!(roman.parse("ⅯⅭⅯⅩⅭⅨ"), Ok(1999));
+/// ```
+pub fn normalize_roman(numeral: &str) -> Cow<'_, str> {
+    const LETTERS: [&str; 16] = [
+        "I", "II", "III", "IV", "V", "VI", "VII", "VIII", "IX", "X", "XI", "XII", "L",
+        "C", "D", "M",
+    ];
+    if !numeral.contains(|c| matches!(c, '\u{2160}'..='\u{217F}')) {
+        return Cow::Borrowed(numeral);
+    }
+    let mut out = String::with_capacity(numeral.len());
+    for c in numeral.chars() {
+        match c {
+            '\u{2160}'..='\u{216F}' => out.push_str(LETTERS[c as usize - 0x2160]),
+            '\u{2170}'..='\u{217F}' => {
+                out.push_str(&LETTERS[c as usize - 0x2170].to_ascii_lowercase())
+            }
+            _ => out.push(c),
+        }
+    }
+    Cow::Owned(out)
+}
+
+/// The glyphs used for the archaic digits of
+/// [Greek numerals](https://en.wikipedia.org/wiki/Greek_numerals).
+///
+/// By default, six thousand is written with stigma (ϛ), ninety with koppa (ϟ),
+/// and nine hundred with sampi (ϡ). Many fonts lack these characters, so
+/// each of them can be substituted with a more widely supported alternative.
+/// See [`NamedNumeralSystem::with_greek_digits`].
+#[cfg(feature = "historic")]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default)]
+#[non_exhaustive]
+pub struct GreekDigits {
+    /// Whether six thousand is written with the digraph στ rather than with
+    /// stigma (ϛ), like six.
+    pub digraph_stigma: bool,
+    /// Whether ninety is written with archaic koppa (ϙ) rather than with
+    /// koppa (ϟ).
+    pub archaic_koppa: bool,
+    /// Whether nine hundred is written with archaic sampi (ͳ) rather than
+    /// with sampi (ϡ).
+    pub archaic_sampi: bool,
+}
+
+#[cfg(feature = "historic")]
+impl GreekDigits {
+    /// All the alternatives.
+    pub const COMPATIBLE: Self = Self {
+        digraph_stigma: true,
+        archaic_koppa: true,
+        archaic_sampi: true,
+    };
+}
+
+/// How [Greek numerals](https://en.wikipedia.org/wiki/Greek_numerals) write
+/// numbers from ten thousand on.
+///
+/// See [`NamedNumeralSystem::represent_greek`].
+#[cfg(feature = "historic")]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default)]
+pub enum GreekMyriads {
+    /// The number is split into groups of four digits. Each group is written
+    /// as usual, followed by a myriad sign (Μ) per power of ten thousand, such
+    /// as αΜ͵βτμε for 12,345 and αΜΜ for a hundred million. This follows the
+    /// convention of Aristarchus, with the number of myriads written before
+    /// the myriad sign rather than above it.
+    #[default]
+    Myriad,
+    /// The number is split into groups of three digits. The last group is
+    /// written as usual, and each letter of the other groups is preceded by a
+    /// lower keraia (͵) per power of a thousand, such as ͵ι͵βτμε for 12,345
+    /// and ͵͵α for a million.
+    Keraia,
+}
+
+/// Which combinations of letters
+/// [Hebrew numerals](https://en.wikipedia.org/wiki/Hebrew_numerals) avoid.
+///
+/// See [`NamedNumeralSystem::with_hebrew_strictness`].
+#[cfg(feature = "rtl")]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default)]
+pub enum HebrewStrictness {
+    /// Only the combinations that spell a divine name are avoided: fifteen
+    /// and sixteen are written טו and טז rather than יה and יו. This is the
+    /// convention of [`NamedNumeralSystem::Hebrew`].
+    #[default]
+    DivineNames,
+    /// The combinations that spell words of ill omen are avoided as well, by
+    /// reordering their letters, as some publishers require: 270 is written ער
+    /// rather than רע (evil), 298 רחצ rather than רצח (murder), 304 דש rather
+    /// than שד (demon), and 344 שדמ rather than שמד (destruction).
+    Strict,
+}
+
+/// The forms of the digits four, five, and six of
+/// [Eastern Arabic numerals](https://en.wikipedia.org/wiki/Eastern_Arabic_numerals).
+///
+/// Both the Arabic-Indic digits (U+0660 to U+0669) and their extended variant
+/// used for Persian and Urdu (U+06F0 to U+06F9) are available, and only the
+/// digits four, five, and six look different. Some regional conventions mix
+/// them. See [`NamedNumeralSystem::with_arabic_digits`]. By default, the
+/// Arabic-Indic forms are used.
+#[cfg(feature = "rtl")]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default)]
+#[non_exhaustive]
+pub struct ArabicDigits {
+    /// Whether four is written ۴ (U+06F4) rather than ٤ (U+0664).
+    pub extended_four: bool,
+    /// Whether five is written ۵ (U+06F5) rather than ٥ (U+0665).
+    pub extended_five: bool,
+    /// Whether six is written ۶ (U+06F6) rather than ٦ (U+0666).
+    pub extended_six: bool,
+}
+
+#[cfg(feature = "rtl")]
+impl ArabicDigits {
+    /// The Arabic-Indic forms of all three digits.
+    pub const ARABIC: Self = Self {
+        extended_four: false,
+        extended_five: false,
+        extended_six: false,
+    };
+
+    /// The extended forms of all three digits.
+    pub const EXTENDED: Self = Self {
+        extended_four: true,
+        extended_five: true,
+        extended_six: true,
+    };
+}
+
+/// How a number is used, which affects how it is written in some numeral
+/// systems.
+///
+/// See [`NamedNumeralSystem::represent_form`] and
+/// [`rbnf::RuleSets::spellout`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default)]
+pub enum NumberForm {
+    /// A cardinal number, such as "three".
+    #[default]
+    Cardinal,
+    /// An ordinal number, such as "third".
+    Ordinal,
+    /// A year, such as "nineteen ninety-nine".
+    Year,
+}
+
+/// How double circled numbering continues after ten.
+///
+/// See [`NamedNumeralSystem::represent_circled`].
+#[cfg(feature = "symbols")]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default)]
+pub enum CircledContinuation {
+    /// Numbers from eleven on cannot be represented, and
+    /// [`RepresentationError::TooLarge`] is returned. This is the behavior of
+    /// [`NamedNumeralSystem::DoubleCircledArabic`].
+    #[default]
+    None,
+    /// Numbers from eleven to twenty are written with negative circled numbers
+    /// (⓫–⓴), which are the closest in style. Larger numbers cannot be
+    /// represented.
+    NegativeCircled,
+}
+
+/// How Korean numbering continues once its fourteen letters are exhausted.
+///
+/// See [`NamedNumeralSystem::represent_korean`].
+#[cfg(feature = "cjk")]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default)]
+pub enum KoreanContinuation {
+    /// The letters are combined like digits of a
+    /// [bijective](NumeralSystem::Bijective) system: fifteen is written ㄱㄱ
+    /// and sixteen ㄱㄴ. This is the behavior of
+    /// [`NamedNumeralSystem::KoreanJamo`] and
+    /// [`NamedNumeralSystem::KoreanSyllable`].
+    #[default]
+    Bijective,
+    /// The letters are repeated like symbols of a
+    /// [symbolic](NumeralSystem::Symbolic) system: fifteen is written ㄱㄱ
+    /// and sixteen ㄴㄴ.
+    Doubled,
+    /// Numbers are written with [Arabic numerals](NamedNumeralSystem::Arabic)
+    /// from fifteen on.
+    Digits,
+    /// Numbers from fifteen on cannot be represented, and
+    /// [`RepresentationError::TooLarge`] is returned.
+    Error,
+}
+
+/// The kana of the kana numeral systems, as returned by
+/// [`NamedNumeralSystem::with_kana_options`].
+///
+/// Whether each of the kana that some publishers omit is included can be
+/// chosen independently. By default, none of them is.
+#[cfg(feature = "cjk")]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default)]
+#[non_exhaustive]
+pub struct KanaOptions {
+    /// Whether n (ん, ン) is included, after all the other kana.
+    pub n: bool,
+    /// Whether the obsolete wi (ゐ, ヰ) is included.
+    pub wi: bool,
+    /// Whether the obsolete we (ゑ, ヱ) is included.
+    pub we: bool,
+}
+
+#[cfg(feature = "cjk")]
+impl KanaOptions {
+    /// The kana of [`NamedNumeralSystem::HiraganaAiueo`] and
+    /// [`NamedNumeralSystem::KatakanaAiueo`]: n is included, but not wi and
+    /// we.
+    pub const AIUEO: Self = Self { n: true, wi: false, we: false };
+
+    /// The kana of [`NamedNumeralSystem::HiraganaIroha`] and
+    /// [`NamedNumeralSystem::KatakanaIroha`]: wi and we are included, but not
+    /// n.
+    pub const IROHA: Self = Self { n: false, wi: true, we: true };
+
+    /// All the kana. In the gojūon order, this is the full grid used by some
+    /// publishers, with wi and we in the w row.
+    pub const GRID: Self = Self { n: true, wi: true, we: true };
+}
+
+/// The numerals of [`HebrewStrictness::Strict`].
+///
+/// Numerals are added greedily, so like fifteen to nineteen in the default
+/// table, the numbers following a reordered combination are listed until the
+/// next multiple of ten, or of a hundred where the tens are reordered.
+#[cfg(feature = "rtl")]
+static STRICT_HEBREW: &[(&str, u64)] = &[
+    ("ת", 400),
+    ("שצ", 390),
+    ("שפ", 380),
+    ("שע", 370),
+    ("שס", 360),
+    ("שנ", 350),
+    ("שמט", 349),
+    ("שמח", 348),
+    ("שמז", 347),
+    ("שמו", 346),
+    ("שמה", 345),
+    ("שדמ", 344),
+    ("שמ", 340),
+    ("של", 330),
+    ("שכ", 320),
+    ("שיט", 319),
+    ("שיח", 318),
+    ("שיז", 317),
+    ("שטז", 316),
+    ("שטו", 315),
+    ("שי", 310),
+    ("שט", 309),
+    ("שח", 308),
+    ("שז", 307),
+    ("שו", 306),
+    ("שה", 305),
+    ("דש", 304),
+    ("ש", 300),
+    ("רצט", 299),
+    ("רחצ", 298),
+    ("רצ", 290),
+    ("רפ", 280),
+    ("ער", 270),
+    ("ר", 200),
+    ("ק", 100),
+    ("צ", 90),
+    ("פ", 80),
+    ("ע", 70),
+    ("ס", 60),
+    ("נ", 50),
+    ("מ", 40),
+    ("ל", 30),
+    ("כ", 20),
+    ("יט", 19),
+    ("יח", 18),
+    ("יז", 17),
+    ("טז", 16),
+    ("טו", 15),
+    ("י", 10),
+    ("ט", 9),
+    ("ח", 8),
+    ("ז", 7),
+    ("ו", 6),
+    ("ה", 5),
+    ("ד", 4),
+    ("ג", 3),
+    ("ב", 2),
+    ("א", 1),
+];
+
+/// The underlying numeral systems of the named numeral systems, in the order
+/// in which they are declared.
+///
+/// Looking systems up in a table rather than constructing them in a `match`
+/// keeps [`NamedNumeralSystem::system`] small as the number of systems grows.
+static SYSTEMS: [NumeralSystem<'static>; NamedNumeralSystem::COUNT] = [
+    // Arabic
+    NumeralSystem::Positional(&["0", "1", "2", "3", "4", "5", "6", "7", "8", "9"]),
+    // CircledArabic
+    #[cfg(feature = "symbols")]
+    NumeralSystem::Fixed(&[
+        "⓪", "①", "②", "③", "④", "⑤", "⑥", "⑦", "⑧", "⑨", "⑩", "⑪", "⑫", "⑬", "⑭", "⑮",
+        "⑯", "⑰", "⑱", "⑲", "⑳", "㉑", "㉒", "㉓", "㉔", "㉕", "㉖", "㉗", "㉘", "㉙",
+        "㉚", "㉛", "㉜", "㉝", "㉞", "㉟", "㊱", "㊲", "㊳", "㊴", "㊵", "㊶", "㊷",
+        "㊸", "㊹", "㊺", "㊻", "㊼", "㊽", "㊾", "㊿",
+    ]),
+    // DoubleCircledArabic
+    #[cfg(feature = "symbols")]
+    NumeralSystem::ZerolessFixed(&["⓵", "⓶", "⓷", "⓸", "⓹", "⓺", "⓻", "⓼", "⓽", "⓾"]),
+    // FullwidthArabic
+    #[cfg(feature = "cjk")]
+    NumeralSystem::Positional(&[
+        "０", "１", "２", "３", "４", "５", "６", "７", "８", "９",
+    ]),
+    // LowerLatin
+    NumeralSystem::Bijective(&[
+        "a", "b", "c", "d", "e", "f", "g", "h", "i", "j", "k", "l", "m", "n", "o", "p",
+        "q", "r", "s", "t", "u", "v", "w", "x", "y", "z",
+    ]),
+    // UpperLatin
+    NumeralSystem::Bijective(&[
+        "A", "B", "C", "D", "E", "F", "G", "H", "I", "J", "K", "L", "M", "N", "O", "P",
+        "Q", "R", "S", "T", "U", "V", "W", "X", "Y", "Z",
+    ]),
+    // CircledLowerLatin
+    #[cfg(feature = "symbols")]
+    NumeralSystem::ZerolessFixed(&[
+        "ⓐ", "ⓑ", "ⓒ", "ⓓ", "ⓔ", "ⓕ", "ⓖ", "ⓗ", "ⓘ", "ⓙ", "ⓚ", "ⓛ", "ⓜ", "ⓝ", "ⓞ", "ⓟ",
+        "ⓠ", "ⓡ", "ⓢ", "ⓣ", "ⓤ", "ⓥ", "ⓦ", "ⓧ", "ⓨ", "ⓩ",
+    ]),
+    // CircledUpperLatin
+    #[cfg(feature = "symbols")]
+    NumeralSystem::ZerolessFixed(&[
+        "Ⓐ", "Ⓑ", "Ⓒ", "Ⓓ", "Ⓔ", "Ⓕ", "Ⓖ", "Ⓗ", "Ⓘ", "Ⓙ", "Ⓚ", "Ⓛ", "Ⓜ", "Ⓝ", "Ⓞ", "Ⓟ",
+        "Ⓠ", "Ⓡ", "Ⓢ", "Ⓣ", "Ⓤ", "Ⓥ", "Ⓦ", "Ⓧ", "Ⓨ", "Ⓩ",
+    ]),
+    // NegativeCircledLatin
+    #[cfg(feature = "symbols")]
+    NumeralSystem::ZerolessFixed(&[
+        "🅐", "🅑", "🅒", "🅓", "🅔", "🅕", "🅖", "🅗", "🅘", "🅙", "🅚", "🅛", "🅜", "🅝", "🅞", "🅟",
+        "🅠", "🅡", "🅢", "🅣", "🅤", "🅥", "🅦", "🅧", "🅨", "🅩",
+    ]),
+    // SquaredLatin
+    #[cfg(feature = "symbols")]
+    NumeralSystem::ZerolessFixed(&[
+        "🄰", "🄱", "🄲", "🄳", "🄴", "🄵", "🄶", "🄷", "🄸", "🄹", "🄺", "🄻", "🄼", "🄽", "🄾", "🄿",
+        "🅀", "🅁", "🅂", "🅃", "🅄", "🅅", "🅆", "🅇", "🅈", "🅉",
+    ]),
+    // NegativeSquaredLatin
+    #[cfg(feature = "symbols")]
+    NumeralSystem::ZerolessFixed(&[
+        "🅰", "🅱", "🅲", "🅳", "🅴", "🅵", "🅶", "🅷", "🅸", "🅹", "🅺", "🅻", "🅼", "🅽", "🅾", "🅿",
+        "🆀", "🆁", "🆂", "🆃", "🆄", "🆅", "🆆", "🆇", "🆈", "🆉",
+    ]),
+    // LowerRoman
+    NumeralSystem::Additive(&[
+        ("m̅", 1000000),
+        ("d̅", 500000),
+        ("c̅", 100000),
+        ("l̅", 50000),
+        ("x̅", 10000),
+        ("v̅", 5000),
+        ("i̅v̅", 4000),
+        ("m", 1000),
+        ("cm", 900),
+        ("d", 500),
+        ("cd", 400),
+        ("c", 100),
+        ("xc", 90),
+        ("l", 50),
+        ("xl", 40),
+        ("x", 10),
+        ("ix", 9),
+        ("v", 5),
+        ("iv", 4),
+        ("i", 1),
+        ("n", 0),
+    ]),
+    // UpperRoman
+    NumeralSystem::Additive(&[
+        ("M̅", 1000000),
+        ("D̅", 500000),
+        ("C̅", 100000),
+        ("L̅", 50000),
+        ("X̅", 10000),
+        ("V̅", 5000),
+        ("I̅V̅", 4000),
+        ("M", 1000),
+        ("CM", 900),
+        ("D", 500),
+        ("CD", 400),
+        ("C", 100),
+        ("XC", 90),
+        ("L", 50),
+        ("XL", 40),
+        ("X", 10),
+        ("IX", 9),
+        ("V", 5),
+        ("IV", 4),
+        ("I", 1),
+        ("N", 0),
+    ]),
+    // LowerGreek
+    #[cfg(feature = "historic")]
+    NumeralSystem::Additive(&[
+        ("͵θ", 9000),
+        ("͵η", 8000),
+        ("͵ζ", 7000),
+        ("͵ϛ", 6000),
+        ("͵ε", 5000),
+        ("͵δ", 4000),
+        ("͵γ", 3000),
+        ("͵β", 2000),
+        ("͵α", 1000),
+        ("ϡ", 900),
+        ("ω", 800),
+        ("ψ", 700),
+        ("χ", 600),
+        ("φ", 500),
+        ("υ", 400),
+        ("τ", 300),
+        ("σ", 200),
+        ("ρ", 100),
+        ("ϟ", 90),
+        ("π", 80),
+        ("ο", 70),
+        ("ξ", 60),
+        ("ν", 50),
+        ("μ", 40),
+        ("λ", 30),
+        ("κ", 20),
+        ("ι", 10),
+        ("θ", 9),
+        ("η", 8),
+        ("ζ", 7),
+        ("στ", 6),
+        ("ε", 5),
+        ("δ", 4),
+        ("γ", 3),
+        ("β", 2),
+        ("α", 1),
+        ("𐆊", 0),
+    ]),
+    // UpperGreek
+    #[cfg(feature = "historic")]
+    NumeralSystem::Additive(&[
+        ("͵Θ", 9000),
+        ("͵Η", 8000),
+        ("͵Ζ", 7000),
+        ("͵Ϛ", 6000),
+        ("͵Ε", 5000),
+        ("͵Δ", 4000),
+        ("͵Γ", 3000),
+        ("͵Β", 2000),
+        ("͵Α", 1000),
+        ("Ϡ", 900),
+        ("Ω", 800),
+        ("Ψ", 700),
+        ("Χ", 600),
+        ("Φ", 500),
+        ("Υ", 400),
+        ("Τ", 300),
+        ("Σ", 200),
+        ("Ρ", 100),
+        ("Ϟ", 90),
+        ("Π", 80),
+        ("Ο", 70),
+        ("Ξ", 60),
+        ("Ν", 50),
+        ("Μ", 40),
+        ("Λ", 30),
+        ("Κ", 20),
+        ("Ι", 10),
+        ("Θ", 9),
+        ("Η", 8),
+        ("Ζ", 7),
+        ("ΣΤ", 6),
+        ("Ε", 5),
+        ("Δ", 4),
+        ("Γ", 3),
+        ("Β", 2),
+        ("Α", 1),
+        ("𐆊", 0),
+    ]),
+    // LowerArmenian
+    #[cfg(feature = "historic")]
+    NumeralSystem::Additive(&[
+        ("ք", 9000),
+        ("փ", 8000),
+        ("ւ", 7000),
+        ("ց", 6000),
+        ("ր", 5000),
+        ("տ", 4000),
+        ("վ", 3000),
+        ("ս", 2000),
+        ("ռ", 1000),
+        ("ջ", 900),
+        ("պ", 800),
+        ("չ", 700),
+        ("ո", 600),
+        ("շ", 500),
+        ("ն", 400),
+        ("յ", 300),
+        ("մ", 200),
+        ("ճ", 100),
+        ("ղ", 90),
+        ("ձ", 80),
+        ("հ", 70),
+        ("կ", 60),
+        ("ծ", 50),
+        ("խ", 40),
+        ("լ", 30),
+        ("ի", 20),
+        ("ժ", 10),
+        ("թ", 9),
+        ("ը", 8),
+        ("է", 7),
+        ("զ", 6),
+        ("ե", 5),
+        ("դ", 4),
+        ("գ", 3),
+        ("բ", 2),
+        ("ա", 1),
+    ]),
+    // UpperArmenian
+    #[cfg(feature = "historic")]
+    NumeralSystem::Additive(&[
+        ("Ք", 9000),
+        ("Փ", 8000),
+        ("Ւ", 7000),
+        ("Ց", 6000),
+        ("Ր", 5000),
+        ("Տ", 4000),
+        ("Վ", 3000),
+        ("Ս", 2000),
+        ("Ռ", 1000),
+        ("Ջ", 900),
+        ("Պ", 800),
+        ("Չ", 700),
+        ("Ո", 600),
+        ("Շ", 500),
+        ("Ն", 400),
+        ("Յ", 300),
+        ("Մ", 200),
+        ("Ճ", 100),
+        ("Ղ", 90),
+        ("Ձ", 80),
+        ("Հ", 70),
+        ("Կ", 60),
+        ("Ծ", 50),
+        ("Խ", 40),
+        ("Լ", 30),
+        ("Ի", 20),
+        ("Ժ", 10),
+        ("Թ", 9),
+        ("Ը", 8),
+        ("Է", 7),
+        ("Զ", 6),
+        ("Ե", 5),
+        ("Դ", 4),
+        ("Գ", 3),
+        ("Բ", 2),
+        ("Ա", 1),
+    ]),
+    // Hebrew
+    #[cfg(feature = "rtl")]
+    NumeralSystem::Additive(&[
+        ("ת", 400),
+        ("ש", 300),
+        ("ר", 200),
+        ("ק", 100),
+        ("צ", 90),
+        ("פ", 80),
+        ("ע", 70),
+        ("ס", 60),
+        ("נ", 50),
+        ("מ", 40),
+        ("ל", 30),
+        ("כ", 20),
+        ("יט", 19),
+        ("יח", 18),
+        ("יז", 17),
+        ("טז", 16),
+        ("טו", 15),
+        ("י", 10),
+        ("ט", 9),
+        ("ח", 8),
+        ("ז", 7),
+        ("ו", 6),
+        ("ה", 5),
+        ("ד", 4),
+        ("ג", 3),
+        ("ב", 2),
+        ("א", 1),
+    ]),
+    // LowerSimplifiedChinese
+    #[cfg(feature = "cjk")]
+    NumeralSystem::Chinese(ChineseVariant::Simple, ChineseCase::Lower),
+    // UpperSimplifiedChinese
+    #[cfg(feature = "cjk")]
+    NumeralSystem::Chinese(ChineseVariant::Simple, ChineseCase::Upper),
+    // LowerTraditionalChinese
+    #[cfg(feature = "cjk")]
+    NumeralSystem::Chinese(ChineseVariant::Traditional, ChineseCase::Lower),
+    // UpperTraditionalChinese
+    #[cfg(feature = "cjk")]
+    NumeralSystem::Chinese(ChineseVariant::Traditional, ChineseCase::Upper),
+    // HiraganaAiueo
+    #[cfg(feature = "cjk")]
+    NumeralSystem::Bijective(&[
+        "あ", "い", "う", "え", "お", "か", "き", "く", "け", "こ", "さ", "し", "す",
+        "せ", "そ", "た", "ち", "つ", "て", "と", "な", "に", "ぬ", "ね", "の", "は",
+        "ひ", "ふ", "へ", "ほ", "ま", "み", "む", "め", "も", "や", "ゆ", "よ", "ら",
+        "り", "る", "れ", "ろ", "わ", "を", "ん",
+    ]),
+    // HiraganaIroha
+    #[cfg(feature = "cjk")]
+    NumeralSystem::Bijective(&[
+        "い", "ろ", "は", "に", "ほ", "へ", "と", "ち", "り", "ぬ", "る", "を", "わ",
+        "か", "よ", "た", "れ", "そ", "つ", "ね", "な", "ら", "む", "う", "ゐ", "の",
+        "お", "く", "や", "ま", "け", "ふ", "こ", "え", "て", "あ", "さ", "き", "ゆ",
+        "め", "み", "し", "ゑ", "ひ", "も", "せ", "す",
+    ]),
+    // KatakanaAiueo
+    #[cfg(feature = "cjk")]
+    NumeralSystem::Bijective(&[
+        "ア", "イ", "ウ", "エ", "オ", "カ", "キ", "ク", "ケ", "コ", "サ", "シ", "ス",
+        "セ", "ソ", "タ", "チ", "ツ", "テ", "ト", "ナ", "ニ", "ヌ", "ネ", "ノ", "ハ",
+        "ヒ", "フ", "ヘ", "ホ", "マ", "ミ", "ム", "メ", "モ", "ヤ", "ユ", "ヨ", "ラ",
+        "リ", "ル", "レ", "ロ", "ワ", "ヲ", "ン",
+    ]),
+    // KatakanaIroha
+    #[cfg(feature = "cjk")]
+    NumeralSystem::Bijective(&[
+        "イ", "ロ", "ハ", "ニ", "ホ", "ヘ", "ト", "チ", "リ", "ヌ", "ル", "ヲ", "ワ",
+        "カ", "ヨ", "タ", "レ", "ソ", "ツ", "ネ", "ナ", "ラ", "ム", "ウ", "ヰ", "ノ",
+        "オ", "ク", "ヤ", "マ", "ケ", "フ", "コ", "エ", "テ", "ア", "サ", "キ", "ユ",
+        "メ", "ミ", "シ", "ヱ", "ヒ", "モ", "セ", "ス",
+    ]),
+    // KatakanaHalfwidth
+    #[cfg(feature = "cjk")]
+    NumeralSystem::Bijective(&[
+        "ｱ", "ｲ", "ｳ", "ｴ", "ｵ", "ｶ", "ｷ", "ｸ", "ｹ", "ｺ", "ｻ", "ｼ", "ｽ", "ｾ", "ｿ", "ﾀ",
+        "ﾁ", "ﾂ", "ﾃ", "ﾄ", "ﾅ", "ﾆ", "ﾇ", "ﾈ", "ﾉ", "ﾊ", "ﾋ", "ﾌ", "ﾍ", "ﾎ", "ﾏ", "ﾐ",
+        "ﾑ", "ﾒ", "ﾓ", "ﾔ", "ﾕ", "ﾖ", "ﾗ", "ﾘ", "ﾙ", "ﾚ", "ﾛ", "ﾜ", "ｦ", "ﾝ",
+    ]),
+    // KoreanJamo
+    #[cfg(feature = "cjk")]
+    NumeralSystem::Bijective(&[
+        "ㄱ", "ㄴ", "ㄷ", "ㄹ", "ㅁ", "ㅂ", "ㅅ", "ㅇ", "ㅈ", "ㅊ", "ㅋ", "ㅌ", "ㅍ",
+        "ㅎ",
+    ]),
+    // KoreanSyllable
+    #[cfg(feature = "cjk")]
+    NumeralSystem::Bijective(&[
+        "가", "나", "다", "라", "마", "바", "사", "아", "자", "차", "카", "타", "파",
+        "하",
+    ]),
+    // EasternArabic
+    #[cfg(feature = "rtl")]
+    NumeralSystem::Positional(&["٠", "١", "٢", "٣", "٤", "٥", "٦", "٧", "٨", "٩"]),
+    // ArabicAbjad
+    #[cfg(feature = "rtl")]
+    NumeralSystem::Bijective(&[
+        "أ", "ب", "ج", "د", "ه‍", "و", "ز", "ح", "ط", "ي", "ك", "ل", "م", "ن", "س", "ع",
+        "ف", "ص", "ق", "ر", "ش", "ت", "ث", "خ", "ذ", "ض", "ظ", "غ",
+    ]),
+    // Persian
+    #[cfg(feature = "rtl")]
+    NumeralSystem::Positional(&["۰", "۱", "۲", "۳", "۴", "۵", "۶", "۷", "۸", "۹"]),
+    // Devanagari
+    #[cfg(feature = "indic")]
+    NumeralSystem::Positional(&["०", "१", "२", "३", "४", "५", "६", "७", "८", "९"]),
+    // Tibetan
+    #[cfg(feature = "indic")]
+    NumeralSystem::Positional(&["༠", "༡", "༢", "༣", "༤", "༥", "༦", "༧", "༨", "༩"]),
+    // Bengali
+    #[cfg(feature = "indic")]
+    NumeralSystem::Positional(&["০", "১", "২", "৩", "৪", "৫", "৬", "৭", "৮", "৯"]),
+    // BengaliLetters
+    #[cfg(feature = "indic")]
+    NumeralSystem::Bijective(&[
+        "ক", "খ", "গ", "ঘ", "ঙ", "চ", "ছ", "জ", "ঝ", "ঞ", "ট", "ঠ", "ড", "ঢ", "ণ", "ত",
+        "থ", "দ", "ধ", "ন", "প", "ফ", "ব", "ভ", "ম", "য", "র", "ল", "শ", "ষ", "স", "হ",
+    ]),
+    // Symbols
+    #[cfg(feature = "symbols")]
+    NumeralSystem::Symbolic(&["*", "†", "‡", "§", "¶", "‖"]),
+    // GermanSymbols
+    #[cfg(feature = "symbols")]
+    NumeralSystem::Symbolic(&["*"]),
+    // FrenchSymbols
+    #[cfg(feature = "symbols")]
+    NumeralSystem::Symbolic(&["*", "⁑", "⁂"]),
+    // ChineseSymbols
+    #[cfg(feature = "symbols")]
+    NumeralSystem::Symbolic(&["※"]),
+];
+
+impl From<NamedNumeralSystem> for NumeralSystem<'static> {
+    fn from(value: NamedNumeralSystem) -> Self {
+        value.system()
+    }
+}
+
+/// Represents a numeral system of one of multiple predefined kinds.
+///
+/// Values of this type can be constructed manually. Alternatively, common
+/// numeral systems are listed as the values of [`NamedNumeralSystem`].
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy)]
+pub enum NumeralSystem<'a> {
+    /// A big-endian
+    /// [positional notation](https://en.wikipedia.org/wiki/Positional_notation)
+    /// system.
+    ///
+    /// ## Representable Numbers
+    ///
+    /// A numeral system of this kind can represent any non-negative integer.
+    ///
+    /// ## Example
+    ///
+    /// With the digits `['0', '1', '2']`, we obtain the ternary numeral system:
+    ///
+    /// | Number | Representation |
+    /// |--------|----------------|
+    /// | 0      | 0              |
+    /// | 1      | 1              |
+    /// | 2      | 2              |
+    /// | 3      | 10             |
+    /// | 4      | 12             |
+    /// | 5      | 12             |
+    /// | 6      | 20             |
+    Positional(&'a [&'a str]),
+
+    /// A big-endian
+    /// [bijective numeration](https://en.wikipedia.org/wiki/Bijective_numeration)
+    /// system. This is similar to positional notation, but without a digit for
+    /// zero.
+    ///
+    /// ## Representable Numbers
+    ///
+    /// A numeral system of this kind can represent any positive integer.
+    ///
+    /// ## Example
+    ///
+    /// With the digits `['A', 'B', 'C']`, we obtain a system similar to one
+    /// commonly used to number columns in spreadsheet software:
+    ///
+    /// | Number | Representation |
+    /// |--------|----------------|
+    /// | 1      | A              |
+    /// | 2      | B              |
+    /// | 3      | C              |
+    /// | 4      | AA             |
+    /// | 5      | AB             |
+    /// | 6      | AC             |
+    /// | 7      | BA             |
+    ///
+    /// With a single digit, the system is unary, so the digit is repeated
+    /// as many times as the number. It is then subject to the same limit as
+    /// [symbolic](NumeralSystem::Symbolic) systems.
+    Bijective(&'a [&'a str]),
+
+    /// An additive
+    /// [sign-value notation](https://en.wikipedia.org/wiki/Sign-value_notation)
+    /// system.
+    ///
+    /// The numerals must be specified by decreasing value.
+    ///
+    /// ## Representable Numbers
+    ///
+    /// A numeral system of this kind can represent any positive integer. If a
+    /// numeral with null value is provided, the system can represent zero as
+    /// well. Like in [symbolic](NumeralSystem::Symbolic) systems, a numeral is
+    /// repeated at most [`MAX_REPETITIONS`](NumeralSystem::MAX_REPETITIONS)
+    /// times.
+    ///
+    /// ## Examples
+    ///
+    /// With the numerals `[("V", 5), ("IV", 4), ("I", 1)]`, we obtain the start
+    /// of the Roman numeral system:
+    ///
+    /// | Number | Representation |
+    /// |--------|----------------|
+    /// | 1      | I              |
+    /// | 2      | II             |
+    /// | 3      | III            |
+    /// | 4      | IV             |
+    /// | 5      | V              |
+    /// | 6      | VI             |
+    /// | 7      | VII            |
+    ///
+    /// With the numerals `[("I", 1), ("Z", 0)]`, we obtain a unary system that
+    /// can represent zero with a non-empty string:
+    ///
+    /// | Number | Representation |
+    /// |--------|----------------|
+    /// | 0      | Z              |
+    /// | 1      | I              |
+    /// | 2      | II             |
+    /// | 3      | III            |
+    /// | 4      | IIII           |
+    /// | 5      | IIIII          |
+    Additive(&'a [(&'a str, u64)]),
+
+    /// A system that uses repeating symbols.
+    ///
+    /// ## Representable Numbers
+    ///
+    /// A numeral system of this kind can represent any positive integer whose
+    /// representation repeats a symbol at most
+    /// [`MAX_REPETITIONS`](NumeralSystem::MAX_REPETITIONS) times. See
+    /// [`represent_with_max_repetitions`](NumeralSystem::represent_with_max_repetitions)
+    /// to change this limit.
+    ///
+    /// ## Example
+    ///
+    /// With the symbols `['A', 'B', 'C']`, we obtain the following
+    /// representations:
+    ///
+    /// | Number | Representation |
+    /// |--------|----------------|
+    /// | 1      | A              |
+    /// | 2      | B              |
+    /// | 3      | C              |
     /// | 4      | AA             |
     /// | 5      | BB             |
     /// | 6      | CC             |
@@ -1055,162 +2414,1873 @@ pub enum NumeralSystem<'a> {
     /// | 4      | 四              |
     /// | 5      | 五              |
     /// | 6      | 六              |
+    ///
+    /// This variant is only available with the `cjk` feature.
+    #[cfg(feature = "cjk")]
     Chinese(ChineseVariant, ChineseCase),
 }
 
 impl<'a> NumeralSystem<'a> {
-    /// Tries to represent a number in this numeral system.
+    /// The maximum number of times a symbol is repeated by default in
+    /// [symbolic](Self::Symbolic), [additive](Self::Additive), and unary
+    /// [bijective](Self::Bijective) numeral systems.
     ///
-    /// If `Ok(r)` is returned, `r` is a value of a type that implements
-    /// [`Display`] by printing the number as represented in this numeral
+    /// Larger numbers cannot be represented in these systems, so that
+    /// representing a huge number does not attempt to write an equally huge
+    /// string.
+    pub const MAX_REPETITIONS: u64 = 10_000;
+
+    /// The maximum number of representations stored by
+    /// [`precompute`](Self::precompute).
+    ///
+    /// Larger ranges are truncated to their first numbers, so that
+    /// precomputing an unbounded range does not exhaust the memory.
+    pub const MAX_PRECOMPUTED: usize = 65_536;
+
+    /// The maximum number of representations compared by
+    /// [`widest_in_range`](Self::widest_in_range) when the widest number
+    /// cannot be determined from the structure of the numeral system.
+    ///
+    /// Only the largest numbers of larger ranges are compared, so that
+    /// laying out an unbounded range does not take forever.
+    pub const MAX_SCANNED: u64 = 4_096;
+
+    /// Tries to represent a number in this numeral system.
+    ///
+    /// If `Ok(r)` is returned, `r` is a value of a type that implements
+    /// [`Display`] by printing the number as represented in this numeral
     /// system.
+    ///
+    /// In [symbolic](Self::Symbolic), [additive](Self::Additive), and unary
+    /// [bijective](Self::Bijective) systems, a symbol is repeated at most
+    /// [`MAX_REPETITIONS`](Self::MAX_REPETITIONS) times. Otherwise,
+    /// [`RepresentationError::TooLarge`] is returned.
+    ///
+    /// ## Performance
+    ///
+    /// Neither this method nor formatting its result allocates: the
+    /// representation is written directly to the formatter, in time linear in
+    /// its length. These guarantees are checked by the `numeral_systems`
+    /// benchmark.
     pub const fn represent(
         self,
         number: u64,
     ) -> Result<impl Display, RepresentationError> {
+        match self.check(number) {
+            Ok(()) => Ok(RepresentedNumber { system: self, number }),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Tries to represent a number in this numeral system, repeating a symbol
+    /// at most `max_repetitions` times.
+    ///
+    /// This is the same as [`represent`](Self::represent), except that the
+    /// limit on repetitions in [symbolic](Self::Symbolic),
+    /// [additive](Self::Additive), and unary [bijective](Self::Bijective)
+    /// systems is `max_repetitions` instead of
+    /// [`MAX_REPETITIONS`](Self::MAX_REPETITIONS). To fall back to another
+    /// system for larger numbers, handle [`RepresentationError::TooLarge`].
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use codex::numeral_systems::{NumeralSystem, RepresentationError};
+    ///
+    /// let symbols = NumeralSystem::Symbolic(&["*", "†"]);
+    /// assert_eq!(symbols.represent_with_max_repetitions(5, 3).unwrap().to_string(), "***");
+    /// assert!(matches!(
+    ///     symbols.represent_with_max_repetitions(7, 3),
+    ///     Err(RepresentationError::TooLarge),
+    /// ));
+    /// ```
+    pub const fn represent_with_max_repetitions(
+        self,
+        number: u64,
+        max_repetitions: u64,
+    ) -> Result<impl Display, RepresentationError> {
+        match self.check_with_max_repetitions(number, max_repetitions) {
+            Ok(()) => Ok(RepresentedNumber { system: self, number }),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Tries to represent a number in this numeral system, in a string of
+    /// capacity `N` bytes.
+    ///
+    /// This does not allocate, and stops as soon as the representation
+    /// exceeds the capacity.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use codex::numeral_systems::{InlineRepresentationError, NamedNumeralSystem};
+    ///
+    /// let roman = NamedNumeralSystem::UpperRoman.system();
+    /// assert_eq!(roman.represent_inline::<32>(1999).unwrap().as_str(), "MCMXCIX");
+    /// assert_eq!(
+    ///     roman.represent_inline::<4>(1999),
+    ///     Err(InlineRepresentationError::Overflow),
+    /// );
+    /// ```
+    pub fn represent_inline<const N: usize>(
+        self,
+        number: u64,
+    ) -> Result<NumeralString<N>, InlineRepresentationError> {
+        self.check(number)?;
+        let mut s = NumeralString::new();
+        match self.write_unchecked(number, &mut s) {
+            Ok(()) => Ok(s),
+            Err(fmt::Error) => Err(InlineRepresentationError::Overflow),
+        }
+    }
+
+    /// Tries to represent a number in this numeral system as a single
+    /// character.
+    ///
+    /// Returns `None` if the number cannot be represented, or if its
+    /// representation is not a single character. In positional, bijective,
+    /// symbolic, and fixed numeral systems, the character is looked up
+    /// directly, so this involves no string formatting at all.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use codex::numeral_systems::NamedNumeralSystem;
+    ///
+    /// let latin = NamedNumeralSystem::UpperLatin.system();
+    /// assert_eq!(latin.represent_char(3), Some('C'));
+    /// assert_eq!(latin.represent_char(27), None);
+    /// let roman = NamedNumeralSystem::UpperRoman.system();
+    /// assert_eq!(roman.represent_char(50), Some('L'));
+    /// ```
+    pub fn represent_char(self, number: u64) -> Option<char> {
+        self.check(number).ok()?;
+        let inline: NumeralString<4>;
+        let symbol = match self {
+            Self::Positional(digits) if number < digits.len() as u64 => {
+                digits[number as usize]
+            }
+            Self::Bijective(symbols) | Self::Symbolic(symbols)
+                if number <= symbols.len() as u64 =>
+            {
+                symbols[(number - 1) as usize]
+            }
+            Self::Fixed(symbols) => symbols[number as usize],
+            Self::ZerolessFixed(symbols) => symbols[(number - 1) as usize],
+            // A character is at most four bytes long.
+            _ => {
+                inline = self.represent_inline::<4>(number).ok()?;
+                &inline
+            }
+        };
+        let mut chars = symbol.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Some(c),
+            _ => None,
+        }
+    }
+
+    /// Tries to represent a number in this numeral system, as a list of parts
+    /// that rich renderers can style differently.
+    ///
+    /// The texts of the parts, concatenated, are the representation of the
+    /// number. Combining marks are split off from the symbols they are
+    /// applied to, so that, for example, the vinculum of large Roman numerals
+    /// can be drawn separately.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use codex::numeral_systems::{NamedNumeralSystem, NumeralPartKind};
+    ///
+    /// let roman = NamedNumeralSystem::UpperRoman.system();
+    /// let parts = roman.represent_parts(5001).unwrap();
+    /// let kinds: Vec<_> = parts.iter().map(|part| part.kind).collect();
+    /// assert_eq!(
+    ///     kinds,
+    ///     [NumeralPartKind::Symbol, NumeralPartKind::Mark, NumeralPartKind::Symbol],
+    /// );
+    /// assert_eq!(parts[1].text, "\u{305}");
+    /// ```
+    pub fn represent_parts(
+        self,
+        number: u64,
+    ) -> Result<Vec<NumeralPart>, RepresentationError> {
+        self.check(number)?;
+        let kind = match self {
+            Self::Positional(_) | Self::Bijective(_) => NumeralPartKind::Digit,
+            Self::Additive(_)
+            | Self::Symbolic(_)
+            | Self::Fixed(_)
+            | Self::ZerolessFixed(_) => NumeralPartKind::Symbol,
+            #[cfg(feature = "cjk")]
+            Self::Chinese(_, _) => NumeralPartKind::Unit,
+        };
+        let mut writer = PartWriter { parts: vec![], kind };
+        self.write_unchecked(number, &mut writer)
+            .expect("splitting into parts cannot fail");
+        Ok(writer.parts)
+    }
+
+    /// Returns whether zero can be represented in this numeral system.
+    ///
+    /// This is the case of positional and fixed systems, of additive systems
+    /// with a numeral for zero, such as Roman numerals, and of Chinese
+    /// numerals, but not of bijective and symbolic systems.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use codex::numeral_systems::NamedNumeralSystem;
+    ///
+    /// assert!(NamedNumeralSystem::Arabic.system().supports_zero());
+    /// assert!(!NamedNumeralSystem::LowerLatin.system().supports_zero());
+    /// ```
+    pub const fn supports_zero(self) -> bool {
+        self.check(0).is_ok()
+    }
+
+    /// Returns the smallest number that can be represented in this numeral
+    /// system, or `None` if it cannot represent any number.
+    ///
+    /// This is zero if the numeral system [supports zero](Self::supports_zero),
+    /// and one otherwise. Counters usually start at one regardless, as with
+    /// [`CounterState`].
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use codex::numeral_systems::{NamedNumeralSystem, NumeralSystem};
+    ///
+    /// assert_eq!(NamedNumeralSystem::Arabic.system().first_value(), Some(0));
+    /// assert_eq!(NamedNumeralSystem::LowerLatin.system().first_value(), Some(1));
+    /// assert_eq!(NumeralSystem::Symbolic(&[]).first_value(), None);
+    /// ```
+    pub const fn first_value(self) -> Option<u64> {
+        if self.supports_zero() {
+            Some(0)
+        } else if self.check(1).is_ok() {
+            Some(1)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the number of characters of the representation of a number in
+    /// this numeral system, without writing it.
+    ///
+    /// Combining marks and variation selectors are not counted, since they
+    /// are displayed with the preceding character, so that this is the width
+    /// of the representation in a monospace font, up to characters that are
+    /// twice as wide, such as CJK ideographs. This allows layouts to reserve
+    /// room for numbers ahead of time.
+    ///
+    /// This does not allocate, and takes the same time as writing the
+    /// representation.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use codex::numeral_systems::NamedNumeralSystem;
+    ///
+    /// let roman = NamedNumeralSystem::UpperRoman.system();
+    /// assert_eq!(roman.width(38), Ok(7));
+    /// assert_eq!(roman.width(5000), Ok(1));
+    /// ```
+    pub fn width(self, number: u64) -> Result<usize, RepresentationError> {
+        self.check(number)?;
+        let mut counter = Counter(0);
+        self.write_unchecked(number, &mut counter)
+            .expect("counting characters cannot fail");
+        Ok(counter.0)
+    }
+
+    /// Returns the set of characters that representations of numbers in this
+    /// numeral system can contain, including combining marks.
+    ///
+    /// This allows applications to check that a font covers a numbering style
+    /// before rendering anything with it, and to fall back to another style
+    /// early otherwise.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use codex::numeral_systems::NamedNumeralSystem;
+    ///
+    /// let latin = NamedNumeralSystem::LowerLatin.system();
+    /// assert_eq!(latin.characters().len(), 26);
+    /// let roman = NamedNumeralSystem::UpperRoman.system();
+    /// assert!(roman.characters().contains(&'\u{305}'));
+    /// ```
+    pub fn characters(self) -> BTreeSet<char> {
+        let symbols: &[&str] = match self {
+            Self::Positional(symbols)
+            | Self::Bijective(symbols)
+            | Self::Symbolic(symbols)
+            | Self::Fixed(symbols)
+            | Self::ZerolessFixed(symbols) => symbols,
+            Self::Additive(numerals) => {
+                return numerals
+                    .iter()
+                    .flat_map(|(numeral, _)| numeral.chars())
+                    .collect();
+            }
+            #[cfg(feature = "cjk")]
+            Self::Chinese(variant, case) => {
+                // These numbers contain every digit and unit.
+                let numbers =
+                    (0..=10).chain([100, 1000]).chain((1..5).map(|i| 10u64.pow(4 * i)));
+                let mut collector = Collector(BTreeSet::new());
+                for n in numbers {
+                    write_chinese(variant, case, n, &mut collector)
+                        .expect("collecting characters cannot fail");
+                }
+                return collector.0;
+            }
+        };
+        symbols.iter().flat_map(|symbol| symbol.chars()).collect()
+    }
+
+    /// Returns the number of a range whose representation in this numeral
+    /// system is the [widest](Self::width), or `None` if no number of the
+    /// range is representable.
+    ///
+    /// This is not always the largest number, such as 38 (XXXVIII) rather
+    /// than 40 (XL) in Roman numerals. If several numbers are the widest, the
+    /// largest of them is returned. This allows right-aligned numbering to be
+    /// laid out without representing every number twice.
+    ///
+    /// In positional and bijective systems whose digits all have the same
+    /// width, this takes constant time, and in symbolic systems, it takes
+    /// time linear in the number of symbols. Otherwise, the representations of
+    /// the numbers of the range are compared, up to
+    /// [`MAX_SCANNED`](Self::MAX_SCANNED) of them: for larger ranges, only the
+    /// largest representable numbers are compared, so a narrower number than
+    /// a smaller one may be returned.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use codex::numeral_systems::NamedNumeralSystem;
+    ///
+    /// let roman = NamedNumeralSystem::UpperRoman.system();
+    /// assert_eq!(roman.widest_in_range(1..=40), Some(38));
+    /// let arabic = NamedNumeralSystem::Arabic.system();
+    /// assert_eq!(arabic.widest_in_range(..1000), Some(999));
+    /// ```
+    pub fn widest_in_range(self, range: impl RangeBounds<u64>) -> Option<u64> {
+        let Sequence { next: first, last, .. } = self.sequence(range);
+        let first = first?;
+        let width = |digit: &str| {
+            let mut counter = Counter(0);
+            let _ = counter.write_str(digit);
+            counter.0
+        };
+        if let Self::Positional(digits) | Self::Bijective(digits) = self
+            && digits.len() >= 2
+            && digits.iter().all(|&digit| width(digit) == width(digits[0]))
+            && self.check(last).is_ok()
+        {
+            // The width of representations grows with the numbers.
+            return Some(last);
+        }
+
+        // Larger numbers are too large to be represented.
+        let last = match self {
+            Self::Positional(symbols) | Self::Fixed(symbols) => {
+                last.min((symbols.len() as u64).saturating_sub(1))
+            }
+            Self::ZerolessFixed(symbols) => last.min(symbols.len() as u64),
+            Self::Bijective([_]) => last.min(Self::MAX_REPETITIONS),
+            Self::Symbolic(symbols) => {
+                last.min((symbols.len() as u64).saturating_mul(Self::MAX_REPETITIONS))
+            }
+            Self::Additive(numerals) => {
+                let largest = numerals.iter().map(|&(_, weight)| weight).max();
+                last.min(largest.map_or(0, |weight| {
+                    weight.saturating_mul(Self::MAX_REPETITIONS + 1).saturating_sub(1)
+                }))
+            }
+            _ => last,
+        };
+        if first > last {
+            return None;
+        }
+
+        if let Self::Symbolic(symbols) = self {
+            // The widest number written with each symbol is the largest one,
+            // as it repeats the symbol the most.
+            let count = symbols.len() as u64;
+            let offset = (last - 1) % count;
+            return (0..count)
+                .filter_map(|i| {
+                    let n = last - (offset + count - i) % count;
+                    let repetitions = n.div_ceil(count);
+                    (n >= first.max(1))
+                        .then(|| (repetitions * width(symbols[i as usize]) as u64, n))
+                })
+                .max()
+                .map(|(_, n)| n);
+        }
+
+        let mut widest: Option<(usize, u64)> = None;
+        let scanned = last.saturating_sub(Self::MAX_SCANNED - 1).max(first);
+        for n in (scanned..=last).rev() {
+            if let Ok(w) = self.width(n)
+                && widest.is_none_or(|(max, _)| w > max)
+            {
+                widest = Some((w, n));
+            }
+        }
+        widest.map(|(_, n)| n)
+    }
+
+    /// Tries to represent a number in this numeral system, in a string of
+    /// capacity `N` bytes.
+    ///
+    /// Unlike [`represent_inline`](Self::represent_inline), this can be used in const
+    /// contexts. However, only positional, bijective, symbolic, and fixed
+    /// numeral systems are supported. For other kinds of numeral systems,
+    /// [`InlineRepresentationError::Unsupported`] is returned.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use codex::numeral_systems::{NamedNumeralSystem, NumeralString};
+    ///
+    /// const TWELVE: NumeralString<8> =
+    ///     match NamedNumeralSystem::Arabic.system().represent_const(12) {
+    ///         Ok(s) => s,
+    ///         Err(_) => panic!(),
+    ///     };
+    /// assert_eq!(TWELVE.as_str(), "12");
+    /// ```
+    pub const fn represent_const<const N: usize>(
+        self,
+        number: u64,
+    ) -> Result<NumeralString<N>, InlineRepresentationError> {
+        if let Err(err) = self.check(number) {
+            return Err(InlineRepresentationError::Unrepresentable(err));
+        }
+
+        let mut s = NumeralString::new();
+        let fits = match self {
+            Self::Positional(digits) => {
+                let radix = digits.len() as u64;
+                // See `write_positional`.
+                let mut buf = [0; u64::BITS as usize];
+                let mut size = 0;
+                let mut n = number;
+                loop {
+                    buf[size] = (n % radix) as usize;
+                    size += 1;
+                    n /= radix;
+                    if n == 0 {
+                        break;
+                    }
+                }
+                push_digits(&mut s, digits, &buf, size)
+            }
+            Self::Bijective(digits) if digits.len() > 1 => {
+                let radix = digits.len() as u64;
+                // See `write_bijective`.
+                let mut buf = [0; u64::BITS as usize];
+                let mut size = 0;
+                let mut n = number;
+                while n > 0 {
+                    n -= 1;
+                    buf[size] = (n % radix) as usize;
+                    size += 1;
+                    n /= radix;
+                }
+                push_digits(&mut s, digits, &buf, size)
+            }
+            Self::Bijective(digits) => push_repeated(&mut s, digits[0], number),
+            Self::Symbolic(symbols) => {
+                let symbol_count = symbols.len() as u64;
+                let symbol = symbols[((number - 1) % symbol_count) as usize];
+                push_repeated(&mut s, symbol, number.div_ceil(symbol_count))
+            }
+            Self::Fixed(symbols) => s.push_str(symbols[number as usize]),
+            Self::ZerolessFixed(symbols) => s.push_str(symbols[(number - 1) as usize]),
+            Self::Additive(_) => return Err(InlineRepresentationError::Unsupported),
+            #[cfg(feature = "cjk")]
+            Self::Chinese(_, _) => return Err(InlineRepresentationError::Unsupported),
+        };
+
+        if fits { Ok(s) } else { Err(InlineRepresentationError::Overflow) }
+    }
+
+    /// Checks whether a number can be represented in this numeral system.
+    const fn check(self, number: u64) -> Result<(), RepresentationError> {
+        self.check_with_max_repetitions(number, Self::MAX_REPETITIONS)
+    }
+
+    /// Checks whether a number can be represented in this numeral system,
+    /// repeating a symbol at most `max_repetitions` times.
+    const fn check_with_max_repetitions(
+        self,
+        number: u64,
+        max_repetitions: u64,
+    ) -> Result<(), RepresentationError> {
         match self {
-            Self::Positional(_) | Self::Chinese(_, _) => {}
-            Self::Bijective(_) | Self::Symbolic(_) => {
+            Self::Positional(digits) => {
+                // Without at least two digits, only the numbers below the
+                // number of digits can be represented.
+                if digits.len() < 2 && number >= digits.len() as u64 {
+                    return Err(RepresentationError::TooLarge);
+                }
+            }
+            #[cfg(feature = "cjk")]
+            Self::Chinese(_, _) => {}
+            Self::Bijective(digits) => {
                 if number == 0 {
                     return Err(RepresentationError::Zero);
                 }
+                if digits.is_empty() || (digits.len() == 1 && number > max_repetitions) {
+                    return Err(RepresentationError::TooLarge);
+                }
+            }
+            Self::Symbolic(symbols) => {
+                if number == 0 {
+                    return Err(RepresentationError::Zero);
+                }
+                if symbols.is_empty()
+                    || number.div_ceil(symbols.len() as u64) > max_repetitions
+                {
+                    return Err(RepresentationError::TooLarge);
+                }
             }
             Self::Additive(numerals) => {
                 if number == 0 && !matches!(numerals.last(), Some((_, 0))) {
                     return Err(RepresentationError::Zero);
                 }
+                // Mirror `write_additive` to count the repetitions.
+                let mut n = number;
+                let mut i = 0;
+                while i < numerals.len() {
+                    let weight = numerals[i].1;
+                    if weight != 0 && weight <= n {
+                        if n / weight > max_repetitions {
+                            return Err(RepresentationError::TooLarge);
+                        }
+                        n %= weight;
+                    }
+                    i += 1;
+                }
+                // The weights cannot add up to the number.
+                if n != 0 {
+                    return Err(RepresentationError::TooLarge);
+                }
+            }
+            Self::Fixed(symbols) => {
+                if number >= symbols.len() as u64 {
+                    return Err(RepresentationError::TooLarge);
+                }
+            }
+            Self::ZerolessFixed(symbols) => {
+                if number == 0 {
+                    return Err(RepresentationError::Zero);
+                }
+                if number > symbols.len() as u64 {
+                    return Err(RepresentationError::TooLarge);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Parses a number represented in this numeral system.
+    ///
+    /// This is the inverse of [`represent`](Self::represent): exactly the
+    /// strings it produces are accepted. In particular, positional numerals
+    /// with leading zeros and non-canonical additive numerals such as `IIII`
+    /// are rejected. However, Roman numerals may also be written with the
+    /// Roman numeral characters of Unicode, such as `Ⅻ`, which are
+    /// [normalized](normalize_roman) first.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use codex::numeral_systems::{NamedNumeralSystem, ParseError};
+    ///
+    /// let roman = NamedNumeralSystem::UpperRoman.system();
+    /// assert_eq!(roman.parse("MCMXCIX"), Ok(1999));
+    /// assert_eq!(roman.parse("IIII"), Err(ParseError::Invalid));
+    /// assert_eq!(roman.parse("Ⅻ"), Ok(12));
+    /// ```
+    pub fn parse(self, s: &str) -> Result<u64, ParseError> {
+        let s = &*self.normalize(s);
+        let number = parse_unchecked(self, s)?;
+        match self.check(number) {
+            Ok(()) => {}
+            Err(RepresentationError::Zero) => return Err(ParseError::Invalid),
+            Err(RepresentationError::TooLarge) => return Err(ParseError::TooLarge),
+        }
+
+        // Make sure `s` is the canonical representation of the number,
+        // without allocating it.
+        let mut matcher = Matcher(s);
+        match self.write_unchecked(number, &mut matcher) {
+            Ok(()) if matcher.0.is_empty() => Ok(number),
+            _ => Err(ParseError::Invalid),
+        }
+    }
+
+    /// Parses a number represented in this numeral system, reporting where
+    /// the string is invalid.
+    ///
+    /// This is the same as [`parse`](Self::parse), except that invalid
+    /// strings are reported as [`Error::Parse`](crate::Error::Parse), with the
+    /// byte offset of the first unexpected symbol, or of the first symbol that
+    /// differs from the canonical representation of the number. Too large
+    /// numbers are reported as [`Error::OutOfRange`](crate::Error::OutOfRange).
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use codex::Error;
+    /// use codex::numeral_systems::NamedNumeralSystem;
+    ///
+    /// let roman = NamedNumeralSystem::UpperRoman.system();
+    /// assert_eq!(roman.parse_with_position("MCMXCIX"), Ok(1999));
+    /// assert_eq!(roman.parse_with_position("MCMZ"), Err(Error::Parse { position: 3 }));
+    /// assert_eq!(roman.parse_with_position("XIIII"), Err(Error::Parse { position: 1 }));
+    /// ```
+    pub fn parse_with_position(self, s: &str) -> Result<u64, crate::Error> {
+        match self.parse(s) {
+            Ok(number) => Ok(number),
+            Err(ParseError::TooLarge) => Err(crate::Error::OutOfRange),
+            Err(ParseError::Invalid) => {
+                let normalized = self.normalize(s);
+                let position = self.invalid_position(&normalized);
+                // Map the offset back to the characters it was normalized from.
+                let mut offset = 0;
+                let position = s
+                    .char_indices()
+                    .find(|&(_, c)| {
+                        offset += normalize_roman(c.encode_utf8(&mut [0; 4])).len();
+                        offset > position
+                    })
+                    .map_or(s.len(), |(i, _)| i);
+                Err(crate::Error::Parse { position })
+            }
+        }
+    }
+
+    /// Replaces the Roman numeral characters of a string with ASCII letters
+    /// if this is a Roman numeral system, whose numerals include `I` or `i`.
+    fn normalize(self, s: &str) -> Cow<'_, str> {
+        match self {
+            Self::Additive(numerals)
+                if numerals.iter().any(|&(numeral, weight)| {
+                    weight == 1 && numeral.eq_ignore_ascii_case("i")
+                }) =>
+            {
+                normalize_roman(s)
+            }
+            _ => Cow::Borrowed(s),
+        }
+    }
+
+    /// Returns the byte offset from which a string is not a valid
+    /// representation, as described in
+    /// [`parse_with_position`](Self::parse_with_position).
+    fn invalid_position(self, s: &str) -> usize {
+        // If the string is read as a non-canonical representation, it is
+        // invalid from where it differs from the canonical one.
+        if let Ok(number) = parse_unchecked(self, s)
+            && self.check(number).is_ok()
+        {
+            let mut matcher = Matcher(s);
+            let _ = self.write_unchecked(number, &mut matcher);
+            return s.len() - matcher.0.len();
+        }
+
+        // Otherwise, it is invalid from the first unexpected symbol.
+        let rest = match self {
+            Self::Positional(symbols)
+            | Self::Bijective(symbols)
+            | Self::Symbolic(symbols)
+            | Self::Fixed(symbols)
+            | Self::ZerolessFixed(symbols) => {
+                let mut rest = s;
+                while let Some((_, symbol)) = longest_prefix(symbols, rest) {
+                    rest = &rest[symbol.len()..];
+                }
+                rest
+            }
+            Self::Additive(numerals) => {
+                read_additive(numerals, s).map_or(s, |(_, rest)| rest)
+            }
+            #[cfg(feature = "cjk")]
+            Self::Chinese(_, _) => s,
+        };
+        if rest.is_empty() { 0 } else { s.len() - rest.len() }
+    }
+
+    /// Returns the label following a label in this numeral system, such as
+    /// `ba` after `az` in Latin letters, or `None` if the label is invalid.
+    ///
+    /// In positional, bijective, and symbolic systems, the next label is
+    /// computed directly from the digits or symbols of the label, so that
+    /// labels whose value does not fit in a `u64` can be continued. Leading
+    /// zeros are kept. In other numeral systems, the label is parsed and the
+    /// next number represented, so `None` is also returned if that number is
+    /// too large.
+    ///
+    /// This allows numbering to continue from labels supplied by users.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use codex::numeral_systems::NamedNumeralSystem;
+    ///
+    /// let latin = NamedNumeralSystem::LowerLatin.system();
+    /// assert_eq!(latin.successor("az").as_deref(), Some("ba"));
+    /// assert_eq!(latin.successor("zzzzzzzzzzzzzzzzzzzz").as_deref(), Some("aaaaaaaaaaaaaaaaaaaaa"));
+    /// let roman = NamedNumeralSystem::UpperRoman.system();
+    /// assert_eq!(roman.successor("XIX").as_deref(), Some("XX"));
+    /// assert_eq!(roman.successor("XIIII"), None);
+    /// ```
+    pub fn successor(self, label: &str) -> Option<String> {
+        match self {
+            Self::Positional(digits) | Self::Bijective(digits) if digits.len() >= 2 => {
+                let mut indices = digit_indices(digits, label)?;
+                // Add one to the last digit, carrying over to the previous ones.
+                let carry = indices.iter_mut().rev().all(|index| {
+                    *index = (*index + 1) % digits.len();
+                    *index == 0
+                });
+                // Positional systems carry a one, and bijective systems their
+                // first digit, which stands for one.
+                if carry {
+                    indices.insert(0, usize::from(matches!(self, Self::Positional(_))));
+                }
+                Some(indices.into_iter().map(|index| digits[index]).collect())
+            }
+            Self::Bijective([digit]) => {
+                parse_repeated(digit, label).ok()?;
+                Some(format!("{label}{digit}"))
+            }
+            Self::Symbolic(symbols) => {
+                let (index, symbol) = longest_prefix(symbols, label)?;
+                let count = parse_repeated(symbol, label).ok()? as usize;
+                Some(match symbols.get(index + 1) {
+                    Some(next) => next.repeat(count),
+                    None => symbols[0].repeat(count + 1),
+                })
+            }
+            _ => {
+                let number = self.parse(label).ok()?.checked_add(1)?;
+                Some(self.represent(number).ok()?.to_string())
+            }
+        }
+    }
+
+    /// Represents each number of a slice in this numeral system, and appends
+    /// the results to `out`.
+    ///
+    /// This yields the same results as calling [`represent`](Self::represent)
+    /// on each number and converting it to a string, but the kind of numeral
+    /// system is only dispatched on once for the whole batch.
+    ///
+    /// `out` grows at most once.
+    pub fn represent_many(
+        self,
+        numbers: &[u64],
+        out: &mut Vec<Result<String, RepresentationError>>,
+    ) {
+        fn extend(
+            system: NumeralSystem,
+            numbers: &[u64],
+            out: &mut Vec<Result<String, RepresentationError>>,
+            write: impl Fn(u64, &mut String) -> fmt::Result,
+        ) {
+            out.extend(numbers.iter().map(|&n| {
+                system.check(n)?;
+                let mut buf = String::new();
+                write(n, &mut buf).expect("writing to a string cannot fail");
+                Ok(buf)
+            }));
+        }
+
+        out.reserve(numbers.len());
+        match self {
+            Self::Positional(digits) => {
+                extend(self, numbers, out, |n, f| write_positional(digits, n, f))
+            }
+            Self::Bijective(digits) => {
+                extend(self, numbers, out, |n, f| write_bijective(digits, n, f))
+            }
+            Self::Additive(numerals) => {
+                extend(self, numbers, out, |n, f| write_additive(numerals, n, f))
+            }
+            Self::Symbolic(symbols) => {
+                extend(self, numbers, out, |n, f| write_symbolic(symbols, n, f))
+            }
+            Self::Fixed(symbols) => {
+                extend(self, numbers, out, |n, f| f.write_str(symbols[n as usize]))
+            }
+            Self::ZerolessFixed(symbols) => {
+                extend(self, numbers, out, |n, f| f.write_str(symbols[(n - 1) as usize]))
+            }
+            #[cfg(feature = "cjk")]
+            Self::Chinese(variant, case) => {
+                extend(self, numbers, out, |n, f| write_chinese(variant, case, n, f))
+            }
+        }
+    }
+
+    /// Represents each number of a range in this numeral system.
+    ///
+    /// This yields the same results as calling [`represent`](Self::represent)
+    /// on each number and converting it to a string, but is more efficient
+    /// for long sequences: in positional and bijective systems, the digits of
+    /// each number are obtained by incrementing those of the previous one
+    /// instead of being recomputed.
+    pub fn sequence(self, range: impl RangeBounds<u64>) -> Sequence<'a> {
+        let first = match range.start_bound() {
+            Bound::Included(&start) => Some(start),
+            Bound::Excluded(&start) => start.checked_add(1),
+            Bound::Unbounded => Some(0),
+        };
+        let last = match range.end_bound() {
+            Bound::Included(&end) => Some(end),
+            Bound::Excluded(&end) => end.checked_sub(1),
+            Bound::Unbounded => Some(u64::MAX),
+        };
+        let (next, last) = match (first, last) {
+            (Some(first), Some(last)) if first <= last => (Some(first), last),
+            _ => (None, 0),
+        };
+        Sequence {
+            system: self,
+            next,
+            last,
+            digits: Vec::new(),
+            len: 0,
+        }
+    }
+
+    /// Returns the representations of the first `count` numbers that can be
+    /// represented in this numeral system, starting from its
+    /// [first value](Self::first_value).
+    ///
+    /// This allows numbering style pickers to show previews of numeral
+    /// systems, whether they start at zero or one. Fewer representations are
+    /// returned if the numeral system cannot represent that many numbers.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use codex::numeral_systems::NamedNumeralSystem;
+    ///
+    /// let latin = NamedNumeralSystem::LowerLatin.system();
+    /// assert_eq!(latin.samples(3), ["a", "b", "c"]);
+    /// let arabic = NamedNumeralSystem::Arabic.system();
+    /// assert_eq!(arabic.samples(3), ["0", "1", "2"]);
+    /// ```
+    pub fn samples(self, count: usize) -> Vec<String> {
+        let Some(first) = self.first_value() else { return vec![] };
+        self.sequence(first..).take(count).map_while(Result::ok).collect()
+    }
+
+    /// Represents each number of a range in this numeral system, and stores
+    /// the results for later lookup.
+    ///
+    /// The range is represented eagerly. Only its first
+    /// [`MAX_PRECOMPUTED`](Self::MAX_PRECOMPUTED) numbers are stored, and the
+    /// following ones are represented on demand.
+    pub fn precompute(self, range: impl RangeBounds<u64>) -> Precomputed<'a> {
+        let sequence = self.sequence(range);
+        let start = sequence.next.unwrap_or(0);
+        let values = sequence.take(Self::MAX_PRECOMPUTED).collect();
+        Precomputed { system: self, start, values }
+    }
+}
+
+/// A number, together with a numeral system in which it is representable.
+///
+/// Notably, this type implements [`Display`] and is thus compatible with
+/// [`format!()`].
+///
+/// Values of this type are constructed by [`NumeralSystem::represent`].
+#[derive(Debug, Clone, Copy)]
+struct RepresentedNumber<'a> {
+    /// Invariant: This system must be able to represent the number.
+    system: NumeralSystem<'a>,
+    number: u64,
+}
+
+impl<'a> Display for RepresentedNumber<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.system.write_unchecked(self.number, f)
+    }
+}
+
+impl<'a> NumeralSystem<'a> {
+    /// Writes a number in this numeral system.
+    ///
+    /// The number must be representable in this numeral system.
+    fn write_unchecked(self, n: u64, f: &mut impl Write) -> fmt::Result {
+        match self {
+            Self::Positional(digits) => write_positional(digits, n, f),
+            Self::Bijective(digits) => write_bijective(digits, n, f),
+            Self::Additive(numerals) => write_additive(numerals, n, f),
+            Self::Symbolic(symbols) => write_symbolic(symbols, n, f),
+            Self::Fixed(symbols) => f.write_str(symbols[n as usize]),
+            Self::ZerolessFixed(symbols) => f.write_str(symbols[(n - 1) as usize]),
+            #[cfg(feature = "cjk")]
+            Self::Chinese(variant, case) => write_chinese(variant, case, n, f),
+        }
+    }
+}
+
+/// Writes a number in a positional numeral system.
+fn write_positional(digits: &[&str], mut n: u64, f: &mut impl Write) -> fmt::Result {
+    // With fewer than two digits, `n` is the only digit, as checked by
+    // `NumeralSystem::check`.
+    let radix = digits.len() as u64;
+
+    // The digits of `n`, from least to most significant. Any `u64` has at
+    // most 64 digits, which is reached in binary.
+    let mut buf = [0; u64::BITS as usize];
+    let mut size = 0;
+    loop {
+        buf[size] = (n % radix) as usize;
+        size += 1;
+        n /= radix;
+        if n == 0 {
+            break;
+        }
+    }
+
+    for &digit in buf[..size].iter().rev() {
+        f.write_str(digits[digit])?;
+    }
+    Ok(())
+}
+
+/// Writes a positive number in a bijective numeral system.
+fn write_bijective(digits: &[&str], mut n: u64, f: &mut impl Write) -> fmt::Result {
+    assert_ne!(n, 0);
+
+    let radix = digits.len() as u64;
+    if radix == 1 {
+        for _ in 0..n {
+            f.write_str(digits[0])?;
+        }
+        return Ok(());
+    }
+
+    // The digits of `n`, from least to most significant. Any `u64` has at
+    // most 64 digits, which is reached in bijective base-2.
+    let mut buf = [0; u64::BITS as usize];
+    let mut size = 0;
+    while n > 0 {
+        // Bijective digits range from one to `radix`, hence the shift.
+        n -= 1;
+        buf[size] = (n % radix) as usize;
+        size += 1;
+        n /= radix;
+    }
+
+    for &digit in buf[..size].iter().rev() {
+        f.write_str(digits[digit])?;
+    }
+    Ok(())
+}
+
+/// Appends the first `size` digits of `buf`, from most to least
+/// significant, or returns `false` if they do not fit.
+const fn push_digits<const N: usize>(
+    s: &mut NumeralString<N>,
+    digits: &[&str],
+    buf: &[usize],
+    mut size: usize,
+) -> bool {
+    while size > 0 {
+        size -= 1;
+        if !s.push_str(digits[buf[size]]) {
+            return false;
+        }
+    }
+    true
+}
+
+/// Appends a symbol `count` times, or returns `false` if it does not fit.
+const fn push_repeated<const N: usize>(
+    s: &mut NumeralString<N>,
+    symbol: &str,
+    count: u64,
+) -> bool {
+    let mut i = 0;
+    while i < count {
+        if !s.push_str(symbol) {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Writes a number in an additive numeral system.
+fn write_additive(
+    numerals: &[(&str, u64)],
+    mut n: u64,
+    f: &mut impl Write,
+) -> fmt::Result {
+    if n == 0 {
+        if let Some(&(numeral, 0)) = numerals.last() {
+            return f.write_str(numeral);
+        }
+        unreachable!()
+    }
+
+    // Greedily add any symbol that fits.
+    for (numeral, weight) in numerals {
+        if *weight == 0 || *weight > n {
+            continue;
+        }
+        let reps = n / weight;
+        for _ in 0..reps {
+            f.write_str(numeral)?
+        }
+
+        n -= weight * reps;
+    }
+    Ok(())
+}
+
+/// Writes a positive number in a symbolic numeral system.
+fn write_symbolic(symbols: &[&str], n: u64, f: &mut impl Write) -> fmt::Result {
+    assert_ne!(n, 0);
+    let symbol_count = symbols.len() as u64;
+    for _ in 0..n.div_ceil(symbol_count) {
+        f.write_str(symbols[((n - 1) % symbol_count) as usize])?
+    }
+    Ok(())
+}
+
+/// Parses a number in a numeral system, possibly accepting non-canonical
+/// representations.
+fn parse_unchecked(system: NumeralSystem, s: &str) -> Result<u64, ParseError> {
+    match system {
+        NumeralSystem::Positional(digits) => parse_digits(digits, s, |value, digit| {
+            value.checked_mul(digits.len() as u64)?.checked_add(digit)
+        }),
+        NumeralSystem::Bijective([digit]) => parse_repeated(digit, s),
+        NumeralSystem::Bijective(digits) => parse_digits(digits, s, |value, digit| {
+            value.checked_mul(digits.len() as u64)?.checked_add(digit + 1)
+        }),
+        NumeralSystem::Additive(numerals) => {
+            if let Some(&(zero, 0)) = numerals.last()
+                && s == zero
+            {
+                return Ok(0);
+            }
+            let (value, rest) = read_additive(numerals, s)?;
+            if rest.is_empty() && value != 0 {
+                Ok(value)
+            } else {
+                Err(ParseError::Invalid)
+            }
+        }
+        NumeralSystem::Symbolic(symbols) => {
+            let (index, symbol) =
+                longest_prefix(symbols, s).ok_or(ParseError::Invalid)?;
+            let count = parse_repeated(symbol, s)?;
+            (count - 1)
+                .checked_mul(symbols.len() as u64)
+                .and_then(|value| value.checked_add(index as u64 + 1))
+                .ok_or(ParseError::TooLarge)
+        }
+        NumeralSystem::Fixed(symbols) => match symbols.iter().position(|&x| x == s) {
+            Some(index) => Ok(index as u64),
+            None => Err(ParseError::Invalid),
+        },
+        NumeralSystem::ZerolessFixed(symbols) => {
+            match symbols.iter().position(|&x| x == s) {
+                Some(index) => Ok(index as u64 + 1),
+                None => Err(ParseError::Invalid),
+            }
+        }
+        #[cfg(feature = "cjk")]
+        NumeralSystem::Chinese(_, _) => {
+            chinese_number::from_chinese_to_u64_ten_thousand(s).map_err(|err| match err {
+                chinese_number::ChineseToNumberError::Overflow => ParseError::TooLarge,
+                _ => ParseError::Invalid,
+            })
+        }
+    }
+}
+
+/// Reads the numerals of an additive numeral system at the start of a string,
+/// and returns the sum of their values and the rest of the string.
+fn read_additive<'s>(
+    numerals: &[(&str, u64)],
+    s: &'s str,
+) -> Result<(u64, &'s str), ParseError> {
+    // Numerals appear by decreasing value, but some are prefixes of others
+    // (like `σ` and `στ` in Greek numerals), so the longest numeral that does
+    // not precede the previous one is taken.
+    let mut rest = s;
+    let mut value = 0u64;
+    let mut start = 0;
+    loop {
+        let mut best: Option<usize> = None;
+        for (i, &(numeral, weight)) in numerals.iter().enumerate().skip(start) {
+            if weight != 0
+                && !numeral.is_empty()
+                && rest.starts_with(numeral)
+                && best.is_none_or(|b| numeral.len() > numerals[b].0.len())
+            {
+                best = Some(i);
+            }
+        }
+        let Some(i) = best else { break };
+        let (numeral, weight) = numerals[i];
+        value = value.checked_add(weight).ok_or(ParseError::TooLarge)?;
+        rest = &rest[numeral.len()..];
+        start = i;
+    }
+    Ok((value, rest))
+}
+
+/// Parses a non-empty sequence of digits, folding their indices with `push`,
+/// which returns `None` on overflow.
+fn parse_digits(
+    digits: &[&str],
+    s: &str,
+    push: impl Fn(u64, u64) -> Option<u64>,
+) -> Result<u64, ParseError> {
+    if s.is_empty() {
+        return Err(ParseError::Invalid);
+    }
+    let mut rest = s;
+    let mut value = 0;
+    while !rest.is_empty() {
+        let (index, digit) = longest_prefix(digits, rest).ok_or(ParseError::Invalid)?;
+        value = push(value, index as u64).ok_or(ParseError::TooLarge)?;
+        rest = &rest[digit.len()..];
+    }
+    Ok(value)
+}
+
+/// Returns the indices of the digits of a non-empty string, if it only
+/// consists of digits.
+fn digit_indices(digits: &[&str], s: &str) -> Option<Vec<usize>> {
+    let mut indices = vec![];
+    let mut rest = s;
+    while !rest.is_empty() {
+        let (index, digit) = longest_prefix(digits, rest)?;
+        indices.push(index);
+        rest = &rest[digit.len()..];
+    }
+    (!indices.is_empty()).then_some(indices)
+}
+
+/// Returns how many times `s` repeats `symbol`, if it is a positive number of
+/// times.
+fn parse_repeated(symbol: &str, s: &str) -> Result<u64, ParseError> {
+    if symbol.is_empty() || s.is_empty() {
+        return Err(ParseError::Invalid);
+    }
+    let mut rest = s;
+    let mut count = 0u64;
+    while let Some(tail) = rest.strip_prefix(symbol) {
+        count += 1;
+        rest = tail;
+    }
+    if rest.is_empty() { Ok(count) } else { Err(ParseError::Invalid) }
+}
+
+/// Returns the longest non-empty symbol that `s` starts with, and its index.
+fn longest_prefix<'a>(symbols: &[&'a str], s: &str) -> Option<(usize, &'a str)> {
+    symbols
+        .iter()
+        .copied()
+        .enumerate()
+        .filter(|(_, symbol)| !symbol.is_empty() && s.starts_with(symbol))
+        .max_by_key(|(_, symbol)| symbol.len())
+}
+
+/// A writer that only accepts a prefix of a string, and keeps track of the
+/// rest of the string.
+struct Matcher<'s>(&'s str);
+
+impl Write for Matcher<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 = self.0.strip_prefix(s).ok_or(fmt::Error)?;
+        Ok(())
+    }
+}
+
+/// A writer that counts the characters written to it, except combining marks
+/// and variation selectors.
+struct Counter(usize);
+
+impl Write for Counter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += s.chars().filter(|&c| !is_mark(c)).count();
+        Ok(())
+    }
+}
+
+/// Whether a character is a combining mark or a variation selector, which is
+/// displayed with the preceding character.
+fn is_mark(c: char) -> bool {
+    matches!(
+        c,
+        '\u{300}'..='\u{36F}'
+            | '\u{483}'..='\u{489}'
+            | '\u{591}'..='\u{5BD}'
+            | '\u{20D0}'..='\u{20F0}'
+            | '\u{FE00}'..='\u{FE0F}'
+    )
+}
+
+/// A writer that splits what is written to it into the parts of a
+/// representation.
+struct PartWriter {
+    parts: Vec<NumeralPart>,
+    /// The kind of the parts written, before combining marks are split off.
+    kind: NumeralPartKind,
+}
+
+impl Write for PartWriter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        // The digits of Chinese numerals are interleaved with their units.
+        #[cfg(feature = "cjk")]
+        let kind = match self.kind {
+            NumeralPartKind::Unit
+                if "零一二三四五六七八九壹贰貳叁參肆伍陆陸柒捌玖".contains(s) =>
+            {
+                NumeralPartKind::Digit
+            }
+            kind => kind,
+        };
+        #[cfg(not(feature = "cjk"))]
+        let kind = self.kind;
+        let base = s.trim_end_matches(is_mark);
+        if !base.is_empty() {
+            self.parts.push(NumeralPart { kind, text: base.into() });
+        }
+        if base.len() < s.len() {
+            self.parts.push(NumeralPart {
+                kind: NumeralPartKind::Mark,
+                text: s[base.len()..].into(),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// A writer that collects the distinct characters written to it.
+#[cfg(feature = "cjk")]
+struct Collector(BTreeSet<char>);
+
+#[cfg(feature = "cjk")]
+impl Write for Collector {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0.extend(s.chars());
+        Ok(())
+    }
+}
+
+/// Writes a number in a Chinese numeral system, using the myriad scale.
+///
+/// This produces the same output as
+/// [`chinese_number::from_u64_to_chinese_ten_thousand`], but without
+/// allocating an intermediate string.
+#[cfg(feature = "cjk")]
+fn write_chinese(
+    variant: ChineseVariant,
+    case: ChineseCase,
+    n: u64,
+    f: &mut impl Write,
+) -> fmt::Result {
+    let digit = |d: u64| match (d, case, variant) {
+        (0, _, _) => "零",
+        (1, ChineseCase::Lower, _) => "一",
+        (1, ChineseCase::Upper, _) => "壹",
+        (2, ChineseCase::Lower, _) => "二",
+        (2, ChineseCase::Upper, ChineseVariant::Simple) => "贰",
+        (2, ChineseCase::Upper, ChineseVariant::Traditional) => "貳",
+        (3, ChineseCase::Lower, _) => "三",
+        (3, ChineseCase::Upper, ChineseVariant::Simple) => "叁",
+        (3, ChineseCase::Upper, ChineseVariant::Traditional) => "參",
+        (4, ChineseCase::Lower, _) => "四",
+        (4, ChineseCase::Upper, _) => "肆",
+        (5, ChineseCase::Lower, _) => "五",
+        (5, ChineseCase::Upper, _) => "伍",
+        (6, ChineseCase::Lower, _) => "六",
+        (6, ChineseCase::Upper, ChineseVariant::Simple) => "陆",
+        (6, ChineseCase::Upper, ChineseVariant::Traditional) => "陸",
+        (7, ChineseCase::Lower, _) => "七",
+        (7, ChineseCase::Upper, _) => "柒",
+        (8, ChineseCase::Lower, _) => "八",
+        (8, ChineseCase::Upper, _) => "捌",
+        (9, ChineseCase::Lower, _) => "九",
+        (9, ChineseCase::Upper, _) => "玖",
+        _ => unreachable!(),
+    };
+    // The units for the digits of a group of four digits.
+    let small_units = match case {
+        ChineseCase::Lower => ["", "十", "百", "千"],
+        ChineseCase::Upper => ["", "拾", "佰", "仟"],
+    };
+    // The units for each group of four digits.
+    let large_units = match variant {
+        ChineseVariant::Simple => ["", "万", "亿", "兆", "京"],
+        ChineseVariant::Traditional => ["", "萬", "億", "兆", "京"],
+    };
+
+    if n == 0 {
+        return f.write_str(digit(0));
+    }
+
+    // The groups of four digits of `n`, from least to most significant.
+    let mut groups = [0; 5];
+    let mut rest = n;
+    for group in &mut groups {
+        *group = rest % 1_0000;
+        rest /= 1_0000;
+    }
+    let top = groups.iter().rposition(|&g| g != 0).unwrap();
+
+    // The index of the last group that was written.
+    let mut last = None;
+    for i in (0..=top).rev() {
+        let group = groups[i];
+        if group == 0 {
+            continue;
+        }
+        // A zero is written if the groups between this group and the last
+        // written one start with a zero.
+        if let Some(last) = last
+            && groups[last - 1] < 1000
+        {
+            f.write_str(digit(0))?;
+        }
+        last = Some(i);
+
+        // A leading one in the tens is only omitted at the very start.
+        let dependent = i < top;
+        let digits = [group % 10, group / 10 % 10, group / 100 % 10, group / 1000];
+        let msd = digits.iter().rposition(|&d| d != 0).unwrap();
+        // The position of the last digit that was written in this group.
+        let mut last_digit = None;
+        for j in (0..=msd).rev() {
+            let d = digits[j];
+            if d == 0 {
+                continue;
+            }
+            if last_digit.is_some_and(|last_digit| last_digit > j + 1) {
+                f.write_str(digit(0))?;
+            }
+            last_digit = Some(j);
+            if !(j == 1 && d == 1 && msd == 1 && !dependent) {
+                f.write_str(digit(d))?;
+            }
+            f.write_str(small_units[j])?;
+        }
+        f.write_str(large_units[i])?;
+    }
+    Ok(())
+}
+
+/// Two numeral systems in which numbers are represented at once, such as
+/// "٣ (3)" or "三（3）".
+///
+/// Bilingual legal and scholarly documents commonly require numbers in both
+/// a local and a reference numeral system. The number is first represented in
+/// the [primary](Self::primary) system, then the separator, and the number
+/// represented in the [secondary](Self::secondary) system between brackets.
+///
+/// ## Example
+///
+/// ```
+/// use codex::numeral_systems::{Dual, NamedNumeralSystem};
+///
+/// let dual = Dual::new(
+///     NamedNumeralSystem::UpperRoman.system(),
+///     NamedNumeralSystem::Arabic.system(),
+/// );
+/// assert_eq!(dual.represent(3).unwrap().to_string(), "III (3)");
+///
+/// # #[cfg(feature = "cjk")] {
+/// let mut dual = Dual::new(
+///     NamedNumeralSystem::LowerSimplifiedChinese.system(),
+///     NamedNumeralSystem::Arabic.system(),
+/// );
+/// dual.separator = "";
+/// dual.brackets = ("（", "）");
+/// assert_eq!(dual.represent(3).unwrap().to_string(), "三（3）");
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
+pub struct Dual<'a> {
+    /// The numeral system in which numbers are represented first.
+    pub primary: NumeralSystem<'a>,
+    /// The numeral system in which numbers are represented between brackets.
+    pub secondary: NumeralSystem<'a>,
+    /// The text between the two representations. Defaults to a space.
+    pub separator: &'a str,
+    /// The opening and closing brackets around the second representation.
+    /// Defaults to parentheses.
+    pub brackets: (&'a str, &'a str),
+}
+
+impl<'a> Dual<'a> {
+    /// Combines two numeral systems, with a space as separator and
+    /// parentheses as brackets.
+    pub const fn new(primary: NumeralSystem<'a>, secondary: NumeralSystem<'a>) -> Self {
+        Self {
+            primary,
+            secondary,
+            separator: " ",
+            brackets: ("(", ")"),
+        }
+    }
+
+    /// Tries to represent a number in both numeral systems.
+    ///
+    /// If the number cannot be represented in either numeral system, the
+    /// error of the first one that fails is returned. Like
+    /// [`NumeralSystem::represent`], this does not allocate.
+    pub fn represent(
+        self,
+        number: u64,
+    ) -> Result<impl Display + 'a, RepresentationError> {
+        let primary = self.primary.represent(number)?;
+        let secondary = self.secondary.represent(number)?;
+        let (open, close) = self.brackets;
+        let separator = self.separator;
+        Ok(DualRepresentation { primary, separator, open, secondary, close })
+    }
+}
+
+/// A number represented in two numeral systems, as returned by
+/// [`Dual::represent`].
+struct DualRepresentation<'a, P, S> {
+    primary: P,
+    separator: &'a str,
+    open: &'a str,
+    secondary: S,
+    close: &'a str,
+}
+
+impl<P: Display, S: Display> Display for DualRepresentation<'_, P, S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let Self { primary, separator, open, secondary, close } = self;
+        write!(f, "{primary}{separator}{open}{secondary}{close}")
+    }
+}
+
+/// A numeral system whose representations are padded to a fixed width, so
+/// that they line up in tables of contents and line numbers.
+///
+/// Representations narrower than the [width](Self::width), as measured by
+/// [`NumeralSystem::width`], are preceded by the [padding](Self::padding)
+/// once per missing character. In CJK text,
+/// [fullwidth digits](NamedNumeralSystem::FullwidthArabic) can be padded with
+/// ideographic spaces instead.
+///
+/// ## Example
+///
+/// ```
+/// use codex::numeral_systems::{NamedNumeralSystem, Tabular};
+///
+/// let arabic = NamedNumeralSystem::Arabic.system();
+/// let tabular = Tabular::for_range(arabic, 1..=120);
+/// assert_eq!(tabular.represent(7).unwrap().to_string(), "\u{2007}\u{2007}7");
+/// assert_eq!(tabular.represent(120).unwrap().to_string(), "120");
+///
+/// # #[cfg(feature = "cjk")] {
+/// let mut tabular = Tabular::new(NamedNumeralSystem::FullwidthArabic.system(), 2);
+/// tabular.padding = "\u{3000}";
+/// assert_eq!(tabular.represent(7).unwrap().to_string(), "\u{3000}７");
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
+pub struct Tabular<'a> {
+    /// The numeral system in which numbers are represented.
+    pub system: NumeralSystem<'a>,
+    /// The width to which representations are padded. Wider representations
+    /// are not truncated.
+    pub width: usize,
+    /// The text written for each missing character. Defaults to a figure
+    /// space (U+2007), which is as wide as a digit.
+    pub padding: &'a str,
+}
+
+impl<'a> Tabular<'a> {
+    /// Pads the representations of a numeral system to a width, with figure
+    /// spaces.
+    pub const fn new(system: NumeralSystem<'a>, width: usize) -> Self {
+        Self { system, width, padding: "\u{2007}" }
+    }
+
+    /// Pads the representations of a numeral system to the width of the
+    /// [widest](NumeralSystem::widest_in_range) number of a range, with
+    /// figure spaces.
+    ///
+    /// For ranges of more than [`NumeralSystem::MAX_SCANNED`] numbers, the
+    /// width may be too small for some of the numbers.
+    pub fn for_range(system: NumeralSystem<'a>, range: impl RangeBounds<u64>) -> Self {
+        let width = system
+            .widest_in_range(range)
+            .map_or(0, |widest| system.width(widest).unwrap_or(0));
+        Self::new(system, width)
+    }
+
+    /// Tries to represent a number in the numeral system, padded to the
+    /// width.
+    ///
+    /// Like [`NumeralSystem::represent`], this does not allocate.
+    pub fn represent(
+        self,
+        number: u64,
+    ) -> Result<impl Display + 'a, RepresentationError> {
+        let missing = self.width.saturating_sub(self.system.width(number)?);
+        let represented = self.system.represent(number)?;
+        Ok(TabularRepresentation { padding: self.padding, missing, represented })
+    }
+}
+
+/// A padded representation of a number, as returned by [`Tabular::represent`].
+struct TabularRepresentation<'a, R> {
+    padding: &'a str,
+    missing: usize,
+    represented: R,
+}
+
+impl<R: Display> Display for TabularRepresentation<'_, R> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for _ in 0..self.missing {
+            f.write_str(self.padding)?;
+        }
+        self.represented.fmt(f)
+    }
+}
+
+/// A part of the representation of a number, as returned by
+/// [`NumeralSystem::represent_parts`].
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub struct NumeralPart {
+    /// What the part is.
+    pub kind: NumeralPartKind,
+    /// The text of the part.
+    pub text: String,
+}
+
+/// What a [`NumeralPart`] is.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum NumeralPartKind {
+    /// A digit of a positional or bijective numeral system, or of Chinese
+    /// numerals.
+    Digit,
+    /// A numeral of an additive numeral system, or a symbol of a symbolic or
+    /// fixed numeral system.
+    Symbol,
+    /// A unit of Chinese numerals, such as 十 or 万.
+    Unit,
+    /// Combining marks applied to the preceding part, such as the vinculum
+    /// of large Roman numerals.
+    Mark,
+}
+
+/// An iterator over the representations of consecutive numbers in a numeral
+/// system.
+///
+/// Values of this type are constructed by [`NumeralSystem::sequence`].
+#[derive(Debug, Clone)]
+pub struct Sequence<'a> {
+    system: NumeralSystem<'a>,
+    /// The next number to represent, or `None` if the iterator is exhausted.
+    next: Option<u64>,
+    /// The last number to represent.
+    last: u64,
+    /// For positional and bijective systems, the digits of the next number as
+    /// indices into the list of digits, from least to most significant. This
+    /// is empty until the first representable number is reached.
+    digits: Vec<usize>,
+    /// The length of the previously yielded representation, used to allocate
+    /// a buffer of appropriate size for the next one.
+    len: usize,
+}
+
+impl<'a> Sequence<'a> {
+    /// Computes the digits of `n` from scratch.
+    fn init_digits(&mut self, mut n: u64) {
+        self.digits.clear();
+        match self.system {
+            NumeralSystem::Positional(digits) => {
+                let radix = digits.len() as u64;
+                loop {
+                    self.digits.push((n % radix) as usize);
+                    n /= radix;
+                    if n == 0 {
+                        break;
+                    }
+                }
+            }
+            NumeralSystem::Bijective(digits) => {
+                let radix = digits.len() as u64;
+                while n > 0 {
+                    n -= 1;
+                    self.digits.push((n % radix) as usize);
+                    n /= radix;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Increments the digits by one.
+    fn increment_digits(&mut self, radix: usize, carry_digit: usize) {
+        for digit in &mut self.digits {
+            if *digit + 1 < radix {
+                *digit += 1;
+                return;
             }
-            Self::Fixed(symbols) => {
-                if number as usize >= symbols.len() {
-                    return Err(RepresentationError::TooLarge);
+            *digit = 0;
+        }
+        self.digits.push(carry_digit);
+    }
+
+    /// Writes the current digits using the given symbols.
+    fn render_digits(&self, symbols: &[&str]) -> String {
+        let mut buf = String::with_capacity(self.len);
+        for &digit in self.digits.iter().rev() {
+            buf.push_str(symbols[digit]);
+        }
+        buf
+    }
+}
+
+impl<'a> Iterator for Sequence<'a> {
+    type Item = Result<String, RepresentationError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let n = self.next?;
+        self.next = if n < self.last { Some(n + 1) } else { None };
+
+        let result = match self.system {
+            NumeralSystem::Positional(digits) if digits.len() > 1 => {
+                if self.digits.is_empty() {
+                    self.init_digits(n);
+                } else {
+                    self.increment_digits(digits.len(), 1);
                 }
+                Ok(self.render_digits(digits))
             }
-            Self::ZerolessFixed(symbols) => {
-                if number == 0 {
-                    return Err(RepresentationError::Zero);
-                }
-                if number as usize > symbols.len() {
-                    return Err(RepresentationError::TooLarge);
+            NumeralSystem::Bijective(digits) if n != 0 && digits.len() > 1 => {
+                if self.digits.is_empty() {
+                    self.init_digits(n);
+                } else {
+                    self.increment_digits(digits.len(), 0);
                 }
+                Ok(self.render_digits(digits))
             }
+            system => system.represent(n).map(|r| r.to_string()),
+        };
+
+        if let Ok(s) = &result {
+            self.len = s.len();
+        }
+        Some(result)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.next {
+            Some(next) => match usize::try_from(self.last - next) {
+                Ok(remaining) => match remaining.checked_add(1) {
+                    Some(len) => (len, Some(len)),
+                    None => (usize::MAX, None),
+                },
+                Err(_) => (usize::MAX, None),
+            },
+            None => (0, Some(0)),
         }
-        Ok(RepresentedNumber { system: self, number })
     }
 }
 
-/// A number, together with a numeral system in which it is representable.
-///
-/// Notably, this type implements [`Display`] and is thus compatible with
-/// [`format!()`].
+impl FusedIterator for Sequence<'_> {}
+
+/// The representations of a range of numbers in a numeral system.
 ///
-/// Values of this type are constructed by [`NumeralSystem::represent`].
-#[derive(Debug, Clone, Copy)]
-struct RepresentedNumber<'a> {
-    /// Invariant: This system must be able to represent the number.
+/// Values of this type are constructed by [`NumeralSystem::precompute`].
+#[derive(Debug, Clone)]
+pub struct Precomputed<'a> {
     system: NumeralSystem<'a>,
-    number: u64,
+    /// The first number of the range.
+    start: u64,
+    /// The representations of the numbers of the range, in order.
+    values: Vec<Result<String, RepresentationError>>,
 }
 
-impl<'a> Display for RepresentedNumber<'a> {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        match self.system {
-            NumeralSystem::Positional(digits) => {
-                let mut n = self.number;
+impl<'a> Precomputed<'a> {
+    /// Returns the precomputed representation of a number, or `None` if it is
+    /// outside of the precomputed range.
+    pub fn get(&self, number: u64) -> Option<Result<&str, RepresentationError>> {
+        let index = usize::try_from(number.checked_sub(self.start)?).ok()?;
+        self.values.get(index).map(|r| r.as_deref().map_err(|e| *e))
+    }
 
-                if n == 0 {
-                    return write!(f, "{}", digits[0]);
-                }
+    /// Represents a number, using its precomputed representation if it is
+    /// available.
+    pub fn represent(&self, number: u64) -> Result<Cow<'_, str>, RepresentationError> {
+        match self.get(number) {
+            Some(result) => result.map(Cow::Borrowed),
+            None => self.system.represent(number).map(|r| Cow::Owned(r.to_string())),
+        }
+    }
+}
 
-                let radix = digits.len() as u64;
-                let size = n.ilog(radix) + 1;
-                // The place value of the most significant digit. For a number
-                // of size 1, the MSD's place is the ones place, hence `- 1`.
-                let mut msd_place = radix.pow(size - 1);
-                for _ in 0..size {
-                    let msd = n / msd_place;
-                    write!(f, "{}", digits[msd as usize])?;
-                    n -= msd * msd_place;
-                    msd_place /= radix;
-                }
-                Ok(())
-            }
+/// A string of bounded length, stored inline.
+///
+/// Values of this type are constructed by [`NumeralSystem::represent_inline`]
+/// and [`NumeralSystem::represent_const`].
+#[derive(Clone, Copy)]
+pub struct NumeralString<const N: usize> {
+    /// Invariant: The first `len` bytes are valid UTF-8.
+    buf: [u8; N],
+    len: usize,
+}
 
-            NumeralSystem::Bijective(digits) => {
-                let mut n = self.number;
+impl<const N: usize> NumeralString<N> {
+    /// Creates an empty string.
+    const fn new() -> Self {
+        Self { buf: [0; N], len: 0 }
+    }
 
-                assert_ne!(n, 0);
+    /// Returns the contents of this string.
+    pub const fn as_str(&self) -> &str {
+        let (bytes, _) = self.buf.split_at(self.len);
+        match std::str::from_utf8(bytes) {
+            Ok(s) => s,
+            Err(_) => unreachable!(),
+        }
+    }
 
-                let radix = digits.len() as u64;
-                // Number of digits when representing `n` in this system.
-                // From https://en.wikipedia.org/wiki/Bijective_numeration#Properties_of_bijective_base-k_numerals.
-                let size = ((n + 1) * (radix - 1)).ilog(radix);
-                // Remove from `n` the number consisting of `size - 1` ones in
-                // base-`radix`, and the print the result using the symbols as
-                // a positional numeral system.
-                n -= (radix.pow(size) - 1) / (radix - 1);
-                // The place value of the most significant digit. For a number
-                // of size 1, the MSD's place is the ones place, hence `- 1`.
-                let mut msd_place = radix.pow(size - 1);
-                for _ in 0..size {
-                    let msd = n / msd_place;
-                    write!(f, "{}", digits[msd as usize])?;
-                    n -= msd * msd_place;
-                    msd_place /= radix;
-                }
-                Ok(())
-            }
+    /// Appends a string, or returns `false` if it does not fit.
+    const fn push_str(&mut self, s: &str) -> bool {
+        let bytes = s.as_bytes();
+        if bytes.len() > N - self.len {
+            return false;
+        }
+        let mut i = 0;
+        while i < bytes.len() {
+            self.buf[self.len + i] = bytes[i];
+            i += 1;
+        }
+        self.len += bytes.len();
+        true
+    }
+}
 
-            NumeralSystem::Additive(numerals) => {
-                let mut n = self.number;
+impl<const N: usize> Write for NumeralString<N> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if self.push_str(s) { Ok(()) } else { Err(fmt::Error) }
+    }
+}
 
-                if n == 0 {
-                    if let Some(&(numeral, 0)) = numerals.last() {
-                        return write!(f, "{numeral}");
-                    }
-                    unreachable!()
-                }
+impl<const N: usize> std::ops::Deref for NumeralString<N> {
+    type Target = str;
 
-                // Greedily add any symbol that fits.
-                for (numeral, weight) in numerals {
-                    if *weight == 0 || *weight > n {
-                        continue;
-                    }
-                    let reps = n / weight;
-                    for _ in 0..reps {
-                        write!(f, "{numeral}")?
-                    }
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
 
-                    n -= weight * reps;
-                }
-                Ok(())
-            }
-            NumeralSystem::Symbolic(symbols) => {
-                let n = self.number;
-                assert_ne!(n, 0);
-                let symbol_count = symbols.len() as u64;
-                for _ in 0..n.div_ceil(symbol_count) {
-                    write!(f, "{}", symbols[((n - 1) % symbol_count) as usize])?
-                }
-                Ok(())
-            }
+impl<const N: usize> Display for NumeralString<N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
 
-            NumeralSystem::Fixed(symbols) => {
-                write!(f, "{}", symbols[self.number as usize])
-            }
+impl<const N: usize> PartialEq for NumeralString<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
 
-            NumeralSystem::ZerolessFixed(symbols) => {
-                write!(f, "{}", symbols[(self.number - 1) as usize])
-            }
+impl<const N: usize> Eq for NumeralString<N> {}
 
-            NumeralSystem::Chinese(variant, case) => write!(
-                f,
-                "{}",
-                from_u64_to_chinese_ten_thousand(variant, case, self.number),
-            ),
+impl<const N: usize> std::hash::Hash for NumeralString<N> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
+
+impl<const N: usize> fmt::Debug for NumeralString<N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+/// A reason why a number cannot be represented in a [`NumeralString`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum InlineRepresentationError {
+    /// The number cannot be represented in the numeral system.
+    Unrepresentable(RepresentationError),
+    /// The representation does not fit in the string.
+    Overflow,
+    /// The kind of numeral system is not supported.
+    Unsupported,
+}
+
+impl From<RepresentationError> for InlineRepresentationError {
+    fn from(err: RepresentationError) -> Self {
+        Self::Unrepresentable(err)
+    }
+}
+
+impl From<InlineRepresentationError> for crate::Error {
+    fn from(err: InlineRepresentationError) -> Self {
+        match err {
+            InlineRepresentationError::Unrepresentable(_)
+            | InlineRepresentationError::Overflow => Self::OutOfRange,
+            InlineRepresentationError::Unsupported => Self::Unsupported { feature: None },
         }
     }
 }
 
+/// A reason why a name does not correspond to a named numeral system.
+///
+/// Values of this type are returned by [`NamedNumeralSystem::lookup`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum NameError {
+    /// No named numeral system has this name.
+    Unknown,
+    /// The named numeral system exists, but the cargo feature it belongs to
+    /// is disabled.
+    Disabled {
+        /// The name of the cargo feature.
+        feature: &'static str,
+    },
+}
+
+/// A reason why a string cannot be parsed in a numeral system.
+///
+/// Values of this type are returned by [`NumeralSystem::parse`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum ParseError {
+    /// The string is not the representation of a number in the numeral
+    /// system.
+    Invalid,
+    /// The string represents a number that is too large.
+    TooLarge,
+}
+
 /// A reason why a number cannot be represented in a numeral system.
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
 pub enum RepresentationError {
@@ -1220,13 +4290,55 @@ pub enum RepresentationError {
     TooLarge,
 }
 
+impl From<RepresentationError> for crate::Error {
+    fn from(_: RepresentationError) -> Self {
+        Self::OutOfRange
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::hash::{Hash, Hasher};
 
     use siphasher::sip128::{Hasher128, SipHasher13};
 
-    use super::{NamedNumeralSystem, NumeralSystem};
+    #[cfg(feature = "symbols")]
+    use super::CircledContinuation;
+    #[cfg(feature = "rtl")]
+    use super::{ArabicDigits, HebrewStrictness};
+    use super::{
+        Dual, InlineRepresentationError, NameError, NamedNumeralSystem, NumberForm,
+        NumeralPartKind, NumeralSystem, ParseError, RepresentationError, Tabular,
+        normalize_roman, truncate_numeral,
+    };
+    #[cfg(feature = "historic")]
+    use super::{GreekDigits, GreekMyriads};
+    #[cfg(feature = "cjk")]
+    use super::{KanaOptions, KoreanContinuation};
+    use crate::Error;
+
+    #[test]
+    fn iteration_orders() {
+        assert!(NamedNumeralSystem::iter().eq(NamedNumeralSystem::ALL));
+        assert_eq!(NamedNumeralSystem::ALL[0], NamedNumeralSystem::Arabic);
+        let names: Vec<_> =
+            NamedNumeralSystem::iter_by_name().map(|s| s.name()).collect();
+        assert!(names.is_sorted());
+        assert_eq!(names.len(), NamedNumeralSystem::ALL.len());
+    }
+
+    #[test]
+    fn test_ids() {
+        for named in NamedNumeralSystem::iter() {
+            assert_eq!(NamedNumeralSystem::from_id(named.id()), Some(named));
+        }
+        // Identifiers are stable, and must never change.
+        assert_eq!(NamedNumeralSystem::Arabic.id(), 0);
+        assert_eq!(NamedNumeralSystem::UpperRoman.id(), 12);
+        #[cfg(feature = "symbols")]
+        assert_eq!(NamedNumeralSystem::ChineseSymbols.id(), 38);
+        assert_eq!(NamedNumeralSystem::from_id(u16::MAX), None);
+    }
 
     /// Makes sure shorthands correspond to the way the number one is
     /// represented in the corresponding system.
@@ -1249,6 +4361,50 @@ mod tests {
         }
     }
 
+    /// Makes sure shorthands are counting symbols, and that the counting
+    /// symbols of Typst are supported.
+    #[test]
+    fn test_markers() {
+        for named in NamedNumeralSystem::iter() {
+            assert_eq!(named.marker().is_some(), named.shorthand().is_some());
+            if let Some(marker) = named.marker() {
+                assert_eq!(NamedNumeralSystem::from_marker(marker), Some(named));
+            }
+        }
+        let markers = [
+            "1aAiI",
+            #[cfg(feature = "historic")]
+            "αΑ",
+            #[cfg(feature = "cjk")]
+            "一壹あいアイ가ㄱ",
+            #[cfg(feature = "rtl")]
+            "א",
+            #[cfg(feature = "symbols")]
+            "*①⓵",
+        ];
+        for marker in markers.concat().chars() {
+            assert!(NamedNumeralSystem::from_marker(marker).is_some(), "{marker}");
+        }
+        assert_eq!(NamedNumeralSystem::from_marker('x'), None);
+    }
+
+    /// Makes sure names are looked up properly, including those of disabled
+    /// systems.
+    #[test]
+    fn test_lookup() {
+        for named in NamedNumeralSystem::iter() {
+            assert_eq!(NamedNumeralSystem::lookup(named.name()), Ok(named));
+            assert_eq!(NamedNumeralSystem::from_name(named.name()), Some(named));
+        }
+        assert_eq!(NamedNumeralSystem::lookup("unknown"), Err(NameError::Unknown));
+        assert_eq!(NamedNumeralSystem::from_name("unknown"), None);
+        #[cfg(not(feature = "cjk"))]
+        assert_eq!(
+            NamedNumeralSystem::lookup("chinese.simple"),
+            Err(NameError::Disabled { feature: "cjk" }),
+        );
+    }
+
     /// Makes sure fixed systems are implemented properly.
     #[test]
     fn test_fixed_systems() {
@@ -1273,87 +4429,497 @@ mod tests {
         }
     }
 
-    /// Makes sure [`NamedNumeralSystem::Arabic`] represents numbers properly.
-    /// This also tests [`NumeralSystem::Positional`].
+    /// Makes sure [`NamedNumeralSystem::Arabic`] represents numbers properly.
+    /// This also tests [`NumeralSystem::Positional`].
+    #[test]
+    fn test_arabic_numerals() {
+        for n in 0..=9999 {
+            assert_eq!(
+                NamedNumeralSystem::Arabic.system().represent(n).unwrap().to_string(),
+                n.to_string(),
+            )
+        }
+    }
+
+    /// Makes sure [`NamedNumeralSystem::LowerLatin`] and
+    /// [`NamedNumeralSystem::UpperLatin`] represent numbers properly. This also
+    /// tests [`NumeralSystem::Bijective`].
+    #[test]
+    fn test_latin() {
+        let mut n = 1;
+        for c1 in 'a'..='z' {
+            assert_eq!(
+                NamedNumeralSystem::LowerLatin
+                    .system()
+                    .represent(n)
+                    .unwrap()
+                    .to_string(),
+                format!("{c1}"),
+            );
+            assert_eq!(
+                NamedNumeralSystem::UpperLatin
+                    .system()
+                    .represent(n)
+                    .unwrap()
+                    .to_string(),
+                format!("{c1}").to_uppercase(),
+            );
+            n += 1;
+        }
+        for c2 in 'a'..='z' {
+            for c1 in 'a'..='z' {
+                assert_eq!(
+                    NamedNumeralSystem::LowerLatin
+                        .system()
+                        .represent(n)
+                        .unwrap()
+                        .to_string(),
+                    format!("{c2}{c1}"),
+                );
+                assert_eq!(
+                    NamedNumeralSystem::UpperLatin
+                        .system()
+                        .represent(n)
+                        .unwrap()
+                        .to_string(),
+                    format!("{c2}{c1}").to_uppercase(),
+                );
+                n += 1;
+            }
+        }
+        for c3 in 'a'..='z' {
+            for c2 in 'a'..='z' {
+                for c1 in 'a'..='z' {
+                    assert_eq!(
+                        NamedNumeralSystem::LowerLatin
+                            .system()
+                            .represent(n)
+                            .unwrap()
+                            .to_string(),
+                        format!("{c3}{c2}{c1}"),
+                    );
+                    assert_eq!(
+                        NamedNumeralSystem::UpperLatin
+                            .system()
+                            .represent(n)
+                            .unwrap()
+                            .to_string(),
+                        format!("{c3}{c2}{c1}").to_uppercase(),
+                    );
+                    n += 1;
+                }
+            }
+        }
+    }
+
+    /// Makes sure positional and bijective systems can represent the largest
+    /// numbers.
+    #[test]
+    fn test_large_numbers() {
+        assert_eq!(
+            NamedNumeralSystem::Arabic
+                .system()
+                .represent(u64::MAX)
+                .unwrap()
+                .to_string(),
+            u64::MAX.to_string(),
+        );
+        assert_eq!(
+            NumeralSystem::Positional(&["0", "1"])
+                .represent(u64::MAX)
+                .unwrap()
+                .to_string(),
+            format!("{:b}", u64::MAX),
+        );
+        assert_eq!(
+            NamedNumeralSystem::LowerLatin
+                .system()
+                .represent(u64::MAX)
+                .unwrap()
+                .to_string(),
+            "gkgwbylwrxtlpo",
+        );
+        assert_eq!(
+            NumeralSystem::Bijective(&["A", "B"])
+                .represent(u64::MAX)
+                .unwrap()
+                .to_string(),
+            "A".repeat(64),
+        );
+        assert_eq!(
+            NumeralSystem::Bijective(&["I"]).represent(5).unwrap().to_string(),
+            "IIIII",
+        );
+    }
+
+    /// Makes sure Chinese numerals agree with the `chinese_number` crate.
+    #[cfg(feature = "cjk")]
+    #[test]
+    fn test_chinese() {
+        use chinese_number::{
+            ChineseCase, ChineseVariant, from_u64_to_chinese_ten_thousand,
+        };
+
+        let mut numbers = vec![
+            0,
+            10,
+            15,
+            100_000,
+            100_010,
+            1_000_100,
+            10_000_000,
+            10_001_000,
+            100_000_001,
+            1_000_000_000_000,
+            10_000_000_000_000_000,
+            u64::MAX,
+        ];
+        // A simple linear congruential generator, to reach larger numbers.
+        let mut state: u64 = 1;
+        for _ in 0..5000 {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            numbers.push(state >> (state % 64));
+        }
+
+        for variant in [ChineseVariant::Simple, ChineseVariant::Traditional] {
+            for case in [ChineseCase::Lower, ChineseCase::Upper] {
+                for &n in &numbers {
+                    assert_eq!(
+                        NumeralSystem::Chinese(variant, case)
+                            .represent(n)
+                            .unwrap()
+                            .to_string(),
+                        from_u64_to_chinese_ten_thousand(variant, case, n),
+                        "unexpected representation of {n}",
+                    );
+                }
+            }
+        }
+    }
+
+    /// Makes sure the archaic digits of Greek numerals can be substituted.
+    #[cfg(feature = "historic")]
+    #[test]
+    fn test_greek_digits() {
+        let lower = NamedNumeralSystem::LowerGreek;
+        let upper = NamedNumeralSystem::UpperGreek;
+        let mut digits = GreekDigits::default();
+        let represent = |named: NamedNumeralSystem, digits, n| {
+            named.with_greek_digits(digits).represent(n).unwrap().to_string()
+        };
+        assert_eq!(represent(lower, digits, 6996), "͵ϛϡϟστ");
+        digits.archaic_koppa = true;
+        assert_eq!(represent(lower, digits, 6996), "͵ϛϡϙστ");
+        assert_eq!(represent(upper, digits, 6996), "͵ϚϠϘΣΤ");
+        assert_eq!(represent(upper, GreekDigits::COMPATIBLE, 6996), "͵ΣΤͲϘΣΤ");
+        for n in [0, 6, 96, 900, 6000, 6996, 9999] {
+            let system = lower.with_greek_digits(GreekDigits::COMPATIBLE);
+            let represented = system.represent(n).unwrap().to_string();
+            assert_eq!(system.parse(&represented), Ok(n));
+            assert!(!represented.contains(['ϛ', 'ϟ', 'ϡ']));
+        }
+        assert_eq!(
+            represent(NamedNumeralSystem::UpperRoman, GreekDigits::COMPATIBLE, 90),
+            "XC",
+        );
+    }
+
+    /// Makes sure the strict convention of Hebrew numerals avoids words of ill
+    /// omen, and only differs from the default one when it has to.
+    #[cfg(feature = "rtl")]
+    #[test]
+    fn test_hebrew_strictness() {
+        let hebrew = NamedNumeralSystem::Hebrew.system();
+        let strict =
+            NamedNumeralSystem::Hebrew.with_hebrew_strictness(HebrewStrictness::Strict);
+        let represent =
+            |system: NumeralSystem, n| system.represent(n).unwrap().to_string();
+        for (n, expected) in [
+            (270, "ער"),
+            (275, "ערה"),
+            (298, "רחצ"),
+            (304, "דש"),
+            (344, "שדמ"),
+            (670, "תער"),
+            (715, "תשטו"),
+        ] {
+            assert_eq!(represent(strict, n), expected);
+            assert_eq!(strict.parse(expected), Ok(n));
+        }
+        for n in 1..=2000 {
+            let default = represent(hebrew, n);
+            let represented = represent(strict, n);
+            assert_eq!(strict.parse(&represented), Ok(n));
+            if !["רע", "רצח", "שד", "שמד"].iter().any(|word| default.contains(word))
+            {
+                assert_eq!(represented, default);
+            }
+        }
+        assert_eq!(
+            represent(
+                NamedNumeralSystem::Hebrew
+                    .with_hebrew_strictness(HebrewStrictness::DivineNames),
+                275,
+            ),
+            "רעה",
+        );
+    }
+
+    /// Makes sure Korean numbering continues as specified.
+    #[cfg(feature = "cjk")]
+    #[test]
+    fn test_korean_continuation() {
+        for named in [NamedNumeralSystem::KoreanJamo, NamedNumeralSystem::KoreanSyllable]
+        {
+            for continuation in [
+                KoreanContinuation::Bijective,
+                KoreanContinuation::Doubled,
+                KoreanContinuation::Digits,
+                KoreanContinuation::Error,
+            ] {
+                for n in 1..=14 {
+                    assert_eq!(
+                        named.represent_korean(n, continuation),
+                        named.represent_cached(n),
+                    );
+                }
+                assert_eq!(
+                    named.represent_korean(0, continuation),
+                    Err(RepresentationError::Zero),
+                );
+            }
+            let represent = |n, continuation| {
+                named.represent_korean(n, continuation).map(|s| s.into_owned())
+            };
+            assert_eq!(
+                represent(16, KoreanContinuation::Bijective),
+                named.represent_cached(16).map(|s| s.into_owned())
+            );
+            assert_eq!(represent(30, KoreanContinuation::Digits).as_deref(), Ok("30"));
+            assert_eq!(
+                represent(15, KoreanContinuation::Error),
+                Err(RepresentationError::TooLarge)
+            );
+        }
+        let syllable = NamedNumeralSystem::KoreanSyllable;
+        assert_eq!(
+            syllable.represent_korean(29, KoreanContinuation::Doubled).as_deref(),
+            Ok("가가가")
+        );
+        assert_eq!(
+            NamedNumeralSystem::UpperRoman
+                .represent_korean(15, KoreanContinuation::Error)
+                .as_deref(),
+            Ok("XV"),
+        );
+    }
+
+    /// Makes sure ordinal numbers are only distinguished where they have to.
+    #[test]
+    fn test_number_forms() {
+        for named in NamedNumeralSystem::iter() {
+            if !named.name().to_lowercase().starts_with("chinese.") {
+                for n in [1, 2, 10, 42] {
+                    assert_eq!(
+                        named.represent_form(n, NumberForm::Ordinal),
+                        named.represent_form(n, NumberForm::Cardinal),
+                    );
+                }
+            }
+        }
+        #[cfg(feature = "cjk")]
+        {
+            let chinese = NamedNumeralSystem::UpperTraditionalChinese;
+            assert_eq!(
+                chinese.represent_form(12, NumberForm::Ordinal).unwrap(),
+                "第拾貳"
+            );
+            assert_eq!(chinese.represent_form(12, NumberForm::Cardinal).unwrap(), "拾貳");
+        }
+        assert_eq!(
+            NamedNumeralSystem::LowerLatin.represent_form(0, NumberForm::Ordinal),
+            Err(RepresentationError::Zero),
+        );
+    }
+
+    /// Makes sure numbers are represented in two numeral systems at once.
     #[test]
-    fn test_arabic_numerals() {
-        for n in 0..=9999 {
-            assert_eq!(
-                NamedNumeralSystem::Arabic.system().represent(n).unwrap().to_string(),
-                n.to_string(),
-            )
+    fn test_dual() {
+        let mut dual = Dual::new(
+            NamedNumeralSystem::UpperRoman.system(),
+            NamedNumeralSystem::Arabic.system(),
+        );
+        let represent = |dual: Dual, n| dual.represent(n).map(|r| r.to_string());
+        assert_eq!(represent(dual, 14).as_deref(), Ok("XIV (14)"));
+        dual.separator = " = ";
+        dual.brackets = ("", "");
+        assert_eq!(represent(dual, 14).as_deref(), Ok("XIV = 14"));
+        let dual = Dual::new(
+            NamedNumeralSystem::Arabic.system(),
+            NamedNumeralSystem::LowerLatin.system(),
+        );
+        assert_eq!(represent(dual, 0), Err(RepresentationError::Zero));
+    }
+
+    /// Makes sure the first year of Japanese eras is written 元.
+    #[test]
+    fn test_era_years() {
+        for named in NamedNumeralSystem::iter() {
+            assert_eq!(named.represent_era_year(0), Err(RepresentationError::Zero));
+            assert_eq!(named.represent_era_year(1).as_deref(), Ok("元"));
         }
+        #[cfg(feature = "cjk")]
+        assert_eq!(
+            NamedNumeralSystem::LowerTraditionalChinese
+                .represent_era_year(31)
+                .as_deref(),
+            Ok("三十一"),
+        );
+        assert_eq!(
+            NamedNumeralSystem::Arabic.represent_era_year(64).as_deref(),
+            Ok("64")
+        );
     }
 
-    /// Makes sure [`NamedNumeralSystem::LowerLatin`] and
-    /// [`NamedNumeralSystem::UpperLatin`] represent numbers properly. This also
-    /// tests [`NumeralSystem::Bijective`].
+    /// Makes sure years are written as customary.
     #[test]
-    fn test_latin() {
-        let mut n = 1;
-        for c1 in 'a'..='z' {
+    fn test_years() {
+        let year = |named: NamedNumeralSystem, n| {
+            named.represent_form(n, NumberForm::Year).map(|s| s.into_owned())
+        };
+        assert_eq!(year(NamedNumeralSystem::Arabic, 12345).as_deref(), Ok("12345"));
+        assert_eq!(year(NamedNumeralSystem::UpperRoman, 1999).as_deref(), Ok("MCMXCIX"));
+        #[cfg(feature = "cjk")]
+        {
             assert_eq!(
-                NamedNumeralSystem::LowerLatin
-                    .system()
-                    .represent(n)
-                    .unwrap()
-                    .to_string(),
-                format!("{c1}"),
+                year(NamedNumeralSystem::LowerTraditionalChinese, 1999).as_deref(),
+                Ok("一九九九")
             );
             assert_eq!(
-                NamedNumeralSystem::UpperLatin
-                    .system()
-                    .represent(n)
-                    .unwrap()
-                    .to_string(),
-                format!("{c1}").to_uppercase(),
+                year(NamedNumeralSystem::UpperSimplifiedChinese, 2024).as_deref(),
+                Ok("贰零贰肆")
+            );
+            assert_eq!(
+                year(NamedNumeralSystem::UpperTraditionalChinese, 2024).as_deref(),
+                Ok("貳零貳肆")
+            );
+            assert_eq!(
+                year(NamedNumeralSystem::LowerSimplifiedChinese, 0).as_deref(),
+                Ok("〇")
             );
-            n += 1;
         }
-        for c2 in 'a'..='z' {
-            for c1 in 'a'..='z' {
-                assert_eq!(
-                    NamedNumeralSystem::LowerLatin
-                        .system()
-                        .represent(n)
-                        .unwrap()
-                        .to_string(),
-                    format!("{c2}{c1}"),
-                );
+        #[cfg(feature = "rtl")]
+        {
+            let hebrew = NamedNumeralSystem::Hebrew;
+            assert_eq!(year(hebrew, 5784).as_deref(), Ok("תשפ״ד"));
+            assert_eq!(year(hebrew, 5775).as_deref(), Ok("תשע״ה"));
+            assert_eq!(year(hebrew, 5715).as_deref(), Ok("תשט״ו"));
+            assert_eq!(year(hebrew, 5400).as_deref(), Ok("ת׳"));
+            assert_eq!(year(hebrew, 5000).as_deref(), Ok("ה׳"));
+            assert_eq!(year(hebrew, 0), Err(RepresentationError::Zero));
+        }
+    }
+
+    /// Makes sure double circled numbering continues as specified.
+    #[cfg(feature = "symbols")]
+    #[test]
+    fn test_circled_continuation() {
+        let circled = NamedNumeralSystem::DoubleCircledArabic;
+        for continuation in
+            [CircledContinuation::None, CircledContinuation::NegativeCircled]
+        {
+            for n in 0..=10 {
                 assert_eq!(
-                    NamedNumeralSystem::UpperLatin
-                        .system()
-                        .represent(n)
-                        .unwrap()
-                        .to_string(),
-                    format!("{c2}{c1}").to_uppercase(),
+                    circled.represent_circled(n, continuation),
+                    circled.represent_cached(n),
                 );
-                n += 1;
             }
         }
-        for c3 in 'a'..='z' {
-            for c2 in 'a'..='z' {
-                for c1 in 'a'..='z' {
-                    assert_eq!(
-                        NamedNumeralSystem::LowerLatin
-                            .system()
-                            .represent(n)
-                            .unwrap()
-                            .to_string(),
-                        format!("{c3}{c2}{c1}"),
-                    );
-                    assert_eq!(
-                        NamedNumeralSystem::UpperLatin
-                            .system()
-                            .represent(n)
-                            .unwrap()
-                            .to_string(),
-                        format!("{c3}{c2}{c1}").to_uppercase(),
-                    );
-                    n += 1;
-                }
+        let represent = |n, continuation| {
+            circled.represent_circled(n, continuation).map(|s| s.into_owned())
+        };
+        assert_eq!(
+            represent(11, CircledContinuation::None),
+            Err(RepresentationError::TooLarge)
+        );
+        assert_eq!(
+            represent(20, CircledContinuation::NegativeCircled).as_deref(),
+            Ok("⓴")
+        );
+        assert_eq!(
+            represent(21, CircledContinuation::NegativeCircled),
+            Err(RepresentationError::TooLarge),
+        );
+        assert_eq!(
+            NamedNumeralSystem::CircledArabic
+                .represent_circled(11, CircledContinuation::NegativeCircled)
+                .as_deref(),
+            Ok("⑪"),
+        );
+    }
+
+    /// Makes sure the kana of kana numeral systems can be chosen.
+    #[cfg(feature = "cjk")]
+    #[test]
+    fn test_kana_options() {
+        let len =
+            |named: NamedNumeralSystem, options| match named.with_kana_options(options) {
+                NumeralSystem::Bijective(kana) => kana.len(),
+                _ => unreachable!(),
+            };
+        for (named, default) in [
+            (NamedNumeralSystem::HiraganaAiueo, KanaOptions::AIUEO),
+            (NamedNumeralSystem::HiraganaIroha, KanaOptions::IROHA),
+            (NamedNumeralSystem::KatakanaAiueo, KanaOptions::AIUEO),
+            (NamedNumeralSystem::KatakanaIroha, KanaOptions::IROHA),
+        ] {
+            for n in 1..=100 {
+                assert_eq!(
+                    named.with_kana_options(default).represent(n).unwrap().to_string(),
+                    named.represent_cached(n).unwrap(),
+                );
             }
+            assert_eq!(len(named, KanaOptions::default()), 45);
+            assert_eq!(len(named, KanaOptions::GRID), 48);
         }
+
+        let represent = |named: NamedNumeralSystem, options, n| {
+            named.with_kana_options(options).represent(n).unwrap().to_string()
+        };
+        let mut options = KanaOptions::AIUEO;
+        options.we = true;
+        assert_eq!(represent(NamedNumeralSystem::HiraganaAiueo, options, 45), "ゑ");
+        assert_eq!(represent(NamedNumeralSystem::KatakanaAiueo, options, 46), "ヲ");
+        assert_eq!(represent(NamedNumeralSystem::KatakanaAiueo, options, 47), "ン");
+        assert_eq!(represent(NamedNumeralSystem::HiraganaIroha, options, 42), "ゑ");
+        assert_eq!(represent(NamedNumeralSystem::HiraganaIroha, options, 25), "の");
+        assert_eq!(
+            represent(NamedNumeralSystem::KatakanaIroha, KanaOptions::GRID, 48),
+            "ン"
+        );
+        assert_eq!(represent(NamedNumeralSystem::Arabic, KanaOptions::GRID, 48), "48",);
+    }
+
+    #[cfg(feature = "cjk")]
+    #[test]
+    fn test_katakana_halfwidth() {
+        let halfwidth = NamedNumeralSystem::KatakanaHalfwidth;
+        let full = NamedNumeralSystem::KatakanaAiueo.system();
+        assert_eq!(halfwidth.represent_cached(1).unwrap(), "ｱ");
+        assert_eq!(halfwidth.represent_cached(46).unwrap(), "ﾝ");
+        assert_eq!(halfwidth.represent_cached(47).unwrap(), "ｱｱ");
+        assert_eq!(halfwidth.system().parse("ﾜｦ"), full.parse("ワヲ"));
+        assert_eq!(NamedNumeralSystem::from_shorthand("ｱ"), Some(halfwidth));
+        assert_eq!(
+            halfwidth
+                .with_kana_options(KanaOptions::GRID)
+                .represent(47)
+                .unwrap()
+                .to_string(),
+            "ｱｱ",
+        );
     }
 
     /// Makes sure Roman numerals work properly. This also tests
@@ -1404,6 +4970,678 @@ mod tests {
         }
     }
 
+    /// Makes sure repeating systems refuse to write huge representations.
+    #[test]
+    fn test_max_repetitions() {
+        let max = NumeralSystem::MAX_REPETITIONS;
+        for system in
+            [NumeralSystem::Symbolic(&["a", "b"]), NumeralSystem::Bijective(&["a"])]
+        {
+            let len = system.represent(max).unwrap().to_string().len() as u64;
+            assert!(len <= max);
+            assert!(matches!(
+                system.represent(u64::MAX),
+                Err(RepresentationError::TooLarge)
+            ));
+            assert!(matches!(
+                system.represent_with_max_repetitions(5, 2),
+                Err(RepresentationError::TooLarge)
+            ));
+        }
+        let symbolic = NumeralSystem::Symbolic(&["a", "b"]);
+        assert_eq!(symbolic.represent(2 * max).unwrap().to_string().len() as u64, max);
+        assert!(symbolic.represent(2 * max + 1).is_err());
+        assert_eq!(
+            symbolic
+                .represent_with_max_repetitions(2 * max + 1, u64::MAX)
+                .unwrap()
+                .to_string()
+                .len() as u64,
+            max + 1
+        );
+    }
+
+    /// Makes sure numeral systems with unusual parameters never panic nor
+    /// write huge representations.
+    #[test]
+    fn test_degenerate_systems() {
+        let systems = [
+            NumeralSystem::Positional(&[]),
+            NumeralSystem::Positional(&["0"]),
+            NumeralSystem::Bijective(&[]),
+            NumeralSystem::Additive(&[]),
+            NumeralSystem::Additive(&[("V", 5)]),
+            NumeralSystem::Additive(&[("M", 1000), ("I", 1)]),
+            NumeralSystem::Symbolic(&[]),
+            NumeralSystem::Fixed(&[]),
+            NumeralSystem::ZerolessFixed(&[]),
+        ];
+        let numbers = [0, 1, 2, 4_294_967_296, u64::MAX - 1, u64::MAX];
+        for system in systems {
+            for n in numbers {
+                if let Ok(r) = system.represent(n) {
+                    assert!(
+                        r.to_string().len()
+                            <= 4 * NumeralSystem::MAX_REPETITIONS as usize
+                    );
+                }
+                let _ = system.represent_inline::<64>(n);
+                let _ = system.represent_const::<64>(n);
+                system.sequence(n..=n.saturating_add(1)).for_each(drop);
+            }
+            system.represent_many(&numbers, &mut Vec::new());
+        }
+        assert_eq!(
+            NumeralSystem::Positional(&["0"]).represent(0).unwrap().to_string(),
+            "0"
+        );
+        assert!(
+            NumeralSystem::Additive(&[("M", 1000), ("I", 1)])
+                .represent(u64::MAX)
+                .is_err()
+        );
+        let fives = NumeralSystem::Additive(&[("V", 5)]);
+        assert_eq!(fives.represent(10).unwrap().to_string(), "VV");
+        assert!(matches!(fives.represent(3), Err(RepresentationError::TooLarge)));
+        assert!(matches!(fives.represent(12), Err(RepresentationError::TooLarge)));
+        assert_eq!(fives.widest_in_range(1..=9), Some(5));
+    }
+
+    /// Makes sure [`NumeralSystem::parse`] is the inverse of
+    /// [`NumeralSystem::represent`].
+    #[test]
+    fn test_parse() {
+        for named in NamedNumeralSystem::iter() {
+            let system = named.system();
+            for n in (0..3000).chain([123_456_789, 1 << 40, u64::MAX]) {
+                if let Ok(r) = system.represent(n) {
+                    assert_eq!(
+                        system.parse(&r.to_string()),
+                        Ok(n),
+                        "cannot parse {n} with `{}`",
+                        named.name(),
+                    );
+                }
+            }
+            assert_eq!(system.parse(""), Err(ParseError::Invalid));
+        }
+
+        let arabic = NamedNumeralSystem::Arabic.system();
+        assert_eq!(arabic.parse("007"), Err(ParseError::Invalid));
+        assert_eq!(arabic.parse("12a"), Err(ParseError::Invalid));
+        assert_eq!(arabic.parse("18446744073709551616"), Err(ParseError::TooLarge));
+        let roman = NamedNumeralSystem::LowerRoman.system();
+        assert_eq!(roman.parse("iiii"), Err(ParseError::Invalid));
+        assert_eq!(roman.parse("xiv"), Ok(14));
+        #[cfg(feature = "symbols")]
+        {
+            let symbols = NamedNumeralSystem::Symbols.system();
+            assert_eq!(symbols.parse("*†"), Err(ParseError::Invalid));
+            assert_eq!(symbols.parse(&"*".repeat(20_000)), Err(ParseError::TooLarge));
+        }
+    }
+
+    /// Makes sure the documented bounds of the named numeral systems limited
+    /// by [`NumeralSystem::MAX_REPETITIONS`] hold.
+    #[test]
+    fn test_repetition_bounds() {
+        let bounds = [
+            (NamedNumeralSystem::LowerRoman, 10_000_999_999),
+            (NamedNumeralSystem::UpperRoman, 10_000_999_999),
+            #[cfg(feature = "historic")]
+            (NamedNumeralSystem::LowerGreek, 90_008_999),
+            #[cfg(feature = "historic")]
+            (NamedNumeralSystem::UpperArmenian, 90_008_999),
+            #[cfg(feature = "rtl")]
+            (NamedNumeralSystem::Hebrew, 4_000_399),
+            #[cfg(feature = "symbols")]
+            (NamedNumeralSystem::Symbols, 60_000),
+            #[cfg(feature = "symbols")]
+            (NamedNumeralSystem::GermanSymbols, 10_000),
+            #[cfg(feature = "symbols")]
+            (NamedNumeralSystem::FrenchSymbols, 30_000),
+            #[cfg(feature = "symbols")]
+            (NamedNumeralSystem::ChineseSymbols, 10_000),
+        ];
+        for (named, max) in bounds {
+            assert!(named.system().check(max).is_ok(), "{named:?}");
+            assert_eq!(
+                named.system().check(max + 1),
+                Err(RepresentationError::TooLarge),
+                "{named:?}",
+            );
+        }
+    }
+
+    /// Makes sure [`NumeralSystem::sequence`] agrees with
+    /// [`NumeralSystem::represent`].
+    #[test]
+    fn test_sequence() {
+        for named in NamedNumeralSystem::iter() {
+            let system = named.system();
+            for (start, end) in [(0, 2000), (15_620, 15_660), (59_995, 60_010)] {
+                let mut sequence = system.sequence(start..end);
+                assert_eq!(
+                    sequence.size_hint(),
+                    ((end - start) as usize, Some((end - start) as usize))
+                );
+                for n in start..end {
+                    assert_eq!(
+                        sequence.next(),
+                        Some(system.represent(n).map(|r| r.to_string())),
+                        "unexpected representation of {n} with `{}`",
+                        named.name(),
+                    );
+                }
+                assert_eq!(sequence.next(), None);
+            }
+        }
+    }
+
+    /// Makes sure [`NumeralSystem::represent_many`] agrees with
+    /// [`NumeralSystem::represent`].
+    #[test]
+    fn test_represent_many() {
+        let numbers = [0, 1, 2, 3, 10, 11, 49, 50, 51, 999, 1000, 12_345];
+        for named in NamedNumeralSystem::iter() {
+            let system = named.system();
+            let mut out = vec![Ok(String::from("existing"))];
+            system.represent_many(&numbers, &mut out);
+            assert_eq!(out.len(), numbers.len() + 1);
+            assert_eq!(out[0], Ok(String::from("existing")));
+            for (&n, result) in numbers.iter().zip(&out[1..]) {
+                assert_eq!(
+                    result,
+                    &system.represent(n).map(|r| r.to_string()),
+                    "unexpected representation of {n} with `{}`",
+                    named.name(),
+                );
+            }
+        }
+    }
+
+    /// Makes sure cached and precomputed representations agree with
+    /// [`NumeralSystem::represent`].
+    #[test]
+    fn test_precomputed() {
+        for named in NamedNumeralSystem::iter() {
+            let system = named.system();
+            let precomputed = system.precompute(40..60);
+            for n in 0..200 {
+                let expected = system.represent(n).map(|r| r.to_string());
+                assert_eq!(
+                    precomputed.get(n),
+                    (40..60).contains(&n).then(|| expected.as_deref().map_err(|e| *e)),
+                );
+                assert_eq!(precomputed.represent(n).map(String::from), expected);
+                assert_eq!(named.represent_cached(n).map(String::from), expected);
+            }
+        }
+
+        let arabic = NamedNumeralSystem::Arabic.system().precompute(..);
+        let last = NumeralSystem::MAX_PRECOMPUTED as u64 - 1;
+        assert_eq!(arabic.get(last), Some(Ok(last.to_string().as_str())));
+        assert_eq!(arabic.get(last + 1), None);
+        assert_eq!(arabic.represent(u64::MAX).as_deref(), Ok("18446744073709551615"));
+    }
+
+    /// Makes sure [`NumeralSystem::represent_const`] agrees with
+    /// [`NumeralSystem::represent`].
+    #[test]
+    fn test_represent_const() {
+        for named in NamedNumeralSystem::iter() {
+            let system = named.system();
+            for n in 0..2000 {
+                let expected = system.represent(n).map(|r| r.to_string());
+                match system.represent_const::<128>(n) {
+                    Ok(s) => assert_eq!(Ok(s.as_str()), expected.as_deref()),
+                    Err(InlineRepresentationError::Unrepresentable(err)) => {
+                        assert_eq!(Err(err), expected)
+                    }
+                    Err(InlineRepresentationError::Overflow) => {
+                        assert!(expected.unwrap().len() > 128)
+                    }
+                    Err(InlineRepresentationError::Unsupported) => {
+                        assert!(!matches!(
+                            system,
+                            NumeralSystem::Positional(_)
+                                | NumeralSystem::Bijective(_)
+                                | NumeralSystem::Symbolic(_)
+                                | NumeralSystem::Fixed(_)
+                                | NumeralSystem::ZerolessFixed(_)
+                        ))
+                    }
+                }
+            }
+        }
+        assert_eq!(
+            NamedNumeralSystem::Arabic.system().represent_const::<2>(100),
+            Err(InlineRepresentationError::Overflow),
+        );
+    }
+
+    /// Makes sure [`NumeralSystem::represent_inline`] agrees with
+    /// [`NumeralSystem::represent`].
+    #[test]
+    fn test_represent_inline() {
+        for named in NamedNumeralSystem::iter() {
+            let system = named.system();
+            for n in 0..2000 {
+                let expected = system.represent(n).map(|r| r.to_string());
+                match system.represent_inline::<64>(n) {
+                    Ok(s) => assert_eq!(Ok(s.as_str()), expected.as_deref()),
+                    Err(InlineRepresentationError::Unrepresentable(err)) => {
+                        assert_eq!(Err(err), expected)
+                    }
+                    Err(InlineRepresentationError::Overflow) => {
+                        assert!(expected.unwrap().len() > 64)
+                    }
+                    Err(InlineRepresentationError::Unsupported) => unreachable!(),
+                }
+            }
+            // Overflowing stops early instead of writing the whole numeral.
+            assert!(matches!(
+                system.represent_inline::<8>(u64::MAX),
+                Err(InlineRepresentationError::Overflow
+                    | InlineRepresentationError::Unrepresentable(
+                        RepresentationError::TooLarge
+                    ))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_represent_char() {
+        for named in NamedNumeralSystem::iter() {
+            let system = named.system();
+            for n in 0..2000 {
+                let expected = system.represent(n).ok().and_then(|r| {
+                    let s = r.to_string();
+                    let mut chars = s.chars();
+                    chars.next().filter(|_| chars.next().is_none())
+                });
+                assert_eq!(system.represent_char(n), expected, "{} {n}", named.name());
+            }
+        }
+        assert_eq!(NamedNumeralSystem::Arabic.system().represent_char(7), Some('7'));
+        assert_eq!(NumeralSystem::Symbolic(&["**"]).represent_char(1), None);
+    }
+
+    #[test]
+    fn test_parse_with_position() {
+        let parse = |named: NamedNumeralSystem, s| named.system().parse_with_position(s);
+        assert_eq!(
+            parse(NamedNumeralSystem::Arabic, "12a4"),
+            Err(Error::Parse { position: 2 })
+        );
+        assert_eq!(
+            parse(NamedNumeralSystem::Arabic, "0012"),
+            Err(Error::Parse { position: 0 })
+        );
+        assert_eq!(
+            parse(NamedNumeralSystem::Arabic, ""),
+            Err(Error::Parse { position: 0 })
+        );
+        assert_eq!(
+            parse(NamedNumeralSystem::Arabic, "99999999999999999999"),
+            Err(Error::OutOfRange)
+        );
+        assert_eq!(
+            parse(NamedNumeralSystem::LowerRoman, "xivv"),
+            Err(Error::Parse { position: 3 })
+        );
+        assert_eq!(
+            parse(NamedNumeralSystem::LowerRoman, "ic"),
+            Err(Error::Parse { position: 1 })
+        );
+        assert_eq!(parse(NamedNumeralSystem::LowerLatin, "ab"), Ok(28));
+        assert_eq!(Error::from(RepresentationError::Zero), Error::OutOfRange);
+    }
+
+    #[test]
+    fn test_represent_strict() {
+        for named in NamedNumeralSystem::iter() {
+            let Some(max) = named.strict_max() else { continue };
+            assert!(named.represent_strict(max).is_ok(), "{}", named.name());
+            assert!(matches!(named.represent_strict(max + 1), Err(Error::OutOfRange)));
+            assert!(named.system().represent(max + 1).is_ok());
+        }
+        let roman = NamedNumeralSystem::LowerRoman;
+        assert_eq!(roman.represent_strict(1999).unwrap().to_string(), "mcmxcix");
+        assert!(matches!(
+            NamedNumeralSystem::LowerLatin.represent_strict(0),
+            Err(Error::OutOfRange)
+        ));
+        assert_eq!(NamedNumeralSystem::Arabic.strict_max(), None);
+    }
+
+    #[cfg(feature = "historic")]
+    #[test]
+    fn test_greek_myriads() {
+        let lower = NamedNumeralSystem::LowerGreek;
+        let upper = NamedNumeralSystem::UpperGreek;
+        let myriad = |named: NamedNumeralSystem, n| {
+            named.represent_greek(n, GreekMyriads::Myriad).unwrap()
+        };
+        let keraia = |named: NamedNumeralSystem, n| {
+            named.represent_greek(n, GreekMyriads::Keraia).unwrap()
+        };
+        assert_eq!(myriad(lower, 9999), "͵θϡϟθ");
+        assert_eq!(keraia(lower, 9999), "͵θϡϟθ");
+        assert_eq!(myriad(lower, 10_000), "αΜ");
+        assert_eq!(keraia(lower, 10_000), "͵ι");
+        assert_eq!(myriad(lower, 1_000_000), "ρΜ");
+        assert_eq!(keraia(lower, 1_000_000), "͵͵α");
+        assert_eq!(keraia(lower, 16_006), "͵ι͵ϛστ");
+        assert_eq!(myriad(upper, 100_010_001), "ΑΜΜΑΜΑ");
+        assert_eq!(keraia(upper, 1_002_003), "͵͵Α͵ΒΓ");
+        assert!(myriad(lower, u64::MAX).ends_with("ϡνεΜ͵αχιε"));
+        assert!(keraia(lower, u64::MAX).starts_with("͵͵͵͵͵͵ι͵͵͵͵͵͵η"));
+        assert_eq!(
+            NamedNumeralSystem::Arabic
+                .represent_greek(12_345, GreekMyriads::Keraia)
+                .unwrap(),
+            "12345"
+        );
+    }
+
+    #[cfg(feature = "rtl")]
+    #[test]
+    fn test_arabic_digits() {
+        let arabic = NamedNumeralSystem::EasternArabic;
+        let persian = NamedNumeralSystem::Persian;
+        assert_eq!(
+            persian
+                .with_arabic_digits(ArabicDigits::EXTENDED)
+                .represent(1234567)
+                .unwrap()
+                .to_string(),
+            persian.system().represent(1234567).unwrap().to_string(),
+        );
+        assert_eq!(
+            arabic
+                .with_arabic_digits(ArabicDigits::ARABIC)
+                .represent(1234567)
+                .unwrap()
+                .to_string(),
+            arabic.system().represent(1234567).unwrap().to_string(),
+        );
+        let mut digits = ArabicDigits::ARABIC;
+        digits.extended_four = true;
+        digits.extended_six = true;
+        let system = persian.with_arabic_digits(digits);
+        assert_eq!(system.represent(3456).unwrap().to_string(), "۳۴٥۶");
+        assert_eq!(system.parse("۳۴٥۶"), Ok(3456));
+        assert!(matches!(
+            NamedNumeralSystem::Arabic.with_arabic_digits(digits),
+            NumeralSystem::Positional(["0", ..]),
+        ));
+        assert_eq!(NamedNumeralSystem::from_arabic_script_language("ckb"), Some(arabic));
+        assert_eq!(NamedNumeralSystem::from_arabic_script_language("ur"), Some(persian));
+        assert_eq!(NamedNumeralSystem::from_arabic_script_language("en"), None);
+    }
+
+    #[test]
+    fn test_width() {
+        for named in NamedNumeralSystem::iter() {
+            let system = named.system();
+            for n in [0, 1, 9, 10, 38, 40, 999, 12_345] {
+                let expected = system.represent(n).map(|r| r.to_string().chars().count());
+                match system.width(n) {
+                    Ok(width) => assert!(width <= expected.unwrap()),
+                    Err(err) => assert_eq!(Err(err), expected),
+                }
+            }
+        }
+        let roman = NamedNumeralSystem::UpperRoman.system();
+        assert_eq!(roman.width(40), Ok(2));
+        assert_eq!(roman.width(4000), Ok(2));
+        assert_eq!(NamedNumeralSystem::Arabic.system().width(u64::MAX), Ok(20));
+        assert_eq!(
+            NamedNumeralSystem::LowerLatin.system().width(0),
+            Err(RepresentationError::Zero)
+        );
+    }
+
+    #[test]
+    fn test_characters() {
+        for named in NamedNumeralSystem::iter() {
+            let system = named.system();
+            let characters = system.characters();
+            for n in [0, 1, 9, 10, 38, 99, 1000, 12_345, 100_000_001, u64::MAX] {
+                if let Ok(represented) = system.represent(n) {
+                    let represented = represented.to_string();
+                    assert!(represented.chars().all(|c| characters.contains(&c)));
+                }
+            }
+        }
+        let arabic = NamedNumeralSystem::Arabic.system();
+        assert_eq!(arabic.characters().into_iter().collect::<String>(), "0123456789");
+        #[cfg(feature = "cjk")]
+        assert_eq!(
+            NamedNumeralSystem::UpperSimplifiedChinese.system().characters().len(),
+            17
+        );
+    }
+
+    #[test]
+    fn test_successor() {
+        for named in NamedNumeralSystem::iter() {
+            let system = named.system();
+            for n in [1, 9, 19, 25, 26, 99, 999, 3999] {
+                let (Ok(label), Ok(next)) =
+                    (system.represent(n), system.represent(n + 1))
+                else {
+                    continue;
+                };
+                assert_eq!(system.successor(&label.to_string()), Some(next.to_string()));
+            }
+        }
+        let arabic = NamedNumeralSystem::Arabic.system();
+        assert_eq!(arabic.successor("099").as_deref(), Some("100"));
+        assert_eq!(
+            arabic.successor("18446744073709551615").as_deref(),
+            Some("18446744073709551616")
+        );
+        assert_eq!(arabic.successor(""), None);
+        assert_eq!(arabic.successor("1a"), None);
+        let symbolic = NumeralSystem::Symbolic(&["*", "†"]);
+        assert_eq!(symbolic.successor("*").as_deref(), Some("†"));
+        assert_eq!(symbolic.successor("††").as_deref(), Some("***"));
+        assert_eq!(symbolic.successor("*†"), None);
+        let unary = NumeralSystem::Bijective(&["|"]);
+        assert_eq!(unary.successor("||").as_deref(), Some("|||"));
+        assert_eq!(
+            NamedNumeralSystem::UpperRoman.system().successor("N").as_deref(),
+            Some("I")
+        );
+    }
+
+    #[test]
+    fn test_first_value() {
+        for named in NamedNumeralSystem::iter() {
+            let system = named.system();
+            let first = system.first_value().unwrap();
+            assert!(system.represent(first).is_ok());
+            assert_eq!(system.supports_zero(), first == 0);
+        }
+        assert_eq!(NamedNumeralSystem::UpperRoman.system().first_value(), Some(0));
+        assert_eq!(NumeralSystem::Fixed(&["a"]).first_value(), Some(0));
+        assert_eq!(NumeralSystem::ZerolessFixed(&["a"]).first_value(), Some(1));
+        assert_eq!(NumeralSystem::Positional(&[]).first_value(), None);
+        assert_eq!(NumeralSystem::Additive(&[("I", 1)]).first_value(), Some(1));
+    }
+
+    #[test]
+    fn test_represent_parts() {
+        for named in NamedNumeralSystem::iter() {
+            let system = named.system();
+            for n in [0, 1, 10, 38, 1234, 50_000, 100_000_001] {
+                let parts = system.represent_parts(n);
+                let expected = system.represent(n).map(|r| r.to_string());
+                assert_eq!(
+                    parts.map(|parts| parts.into_iter().map(|part| part.text).collect()),
+                    expected,
+                );
+            }
+        }
+        let parts = NamedNumeralSystem::Arabic.system().represent_parts(12).unwrap();
+        assert_eq!(parts.len(), 2);
+        assert!(parts.iter().all(|part| part.kind == NumeralPartKind::Digit));
+        assert_eq!(
+            NamedNumeralSystem::LowerLatin.system().represent_parts(0),
+            Err(RepresentationError::Zero),
+        );
+        #[cfg(feature = "cjk")]
+        {
+            let chinese = NamedNumeralSystem::LowerSimplifiedChinese.system();
+            let kinds: Vec<_> = chinese
+                .represent_parts(20_005)
+                .unwrap()
+                .into_iter()
+                .map(|part| (part.kind, part.text))
+                .collect();
+            assert_eq!(
+                kinds,
+                [
+                    (NumeralPartKind::Digit, "二".into()),
+                    (NumeralPartKind::Unit, "万".into()),
+                    (NumeralPartKind::Digit, "零".into()),
+                    (NumeralPartKind::Digit, "五".into()),
+                ],
+            );
+        }
+    }
+
+    #[test]
+    fn test_truncate_numeral() {
+        assert_eq!(truncate_numeral("abc", 3), "abc");
+        assert_eq!(truncate_numeral("abcd", 3), "ab…");
+        assert_eq!(truncate_numeral("abcd", 1), "…");
+        assert_eq!(truncate_numeral("abcd", 0), "");
+        assert_eq!(truncate_numeral("", 2), "");
+        assert_eq!(truncate_numeral("I̅V̅X̅", 2), "I̅…");
+        assert_eq!(truncate_numeral("I̅V̅", 2), "I̅V̅");
+        let symbols = NumeralSystem::Symbolic(&["*"]).represent(100).unwrap().to_string();
+        assert_eq!(truncate_numeral(&symbols, 4), "***…");
+    }
+
+    #[test]
+    fn test_normalize_roman() {
+        assert_eq!(normalize_roman("Ⅰ"), "I");
+        assert_eq!(normalize_roman("ⅫI"), "XIII");
+        assert_eq!(normalize_roman("ⅿ"), "m");
+        assert_eq!(normalize_roman("Ⅼⅼ"), "Ll");
+        assert_eq!(normalize_roman("ⅰⅴ?"), "iv?");
+        assert!(matches!(normalize_roman("XIV"), std::borrow::Cow::Borrowed("XIV")));
+        let roman = NamedNumeralSystem::LowerRoman.system();
+        assert_eq!(roman.parse(&normalize_roman("ⅹⅳ")), Ok(14));
+        assert_eq!(roman.parse(&normalize_roman("ⅳⅰ")), Err(ParseError::Invalid));
+
+        // Roman numeral systems normalize their input themselves.
+        assert_eq!(roman.parse("ⅹⅳ"), Ok(14));
+        assert_eq!(roman.parse("xⅳ"), Ok(14));
+        assert_eq!(roman.parse("ⅻ"), Ok(12));
+        assert_eq!(roman.parse("ⅫI"), Err(ParseError::Invalid));
+        assert_eq!(roman.parse_with_position("ⅹⅳ"), Ok(14));
+        let upper = NamedNumeralSystem::UpperRoman.system();
+        assert_eq!(upper.parse("ⅯⅯⅩⅩⅣ"), Ok(2024));
+        assert_eq!(upper.successor("Ⅻ").as_deref(), Some("XIII"));
+        // Positions refer to the original characters.
+        assert_eq!(upper.parse_with_position("ⅫZ"), Err(Error::Parse { position: 3 }));
+        assert_eq!(upper.parse_with_position("ⅩⅢⅡ"), Err(Error::Parse { position: 3 }));
+        assert_eq!(upper.parse_with_position("XⅣⅠ"), Err(Error::Parse { position: 1 }));
+        assert_eq!(
+            NamedNumeralSystem::Arabic.system().parse("Ⅻ"),
+            Err(ParseError::Invalid)
+        );
+    }
+
+    #[test]
+    fn test_samples() {
+        for named in NamedNumeralSystem::iter() {
+            let samples = named.system().samples(5);
+            assert_eq!(samples.len(), 5);
+            assert!(samples.iter().all(|sample| named.system().parse(sample).is_ok()));
+        }
+        assert_eq!(NamedNumeralSystem::UpperRoman.system().samples(2), ["N", "I"]);
+        assert_eq!(NumeralSystem::ZerolessFixed(&["x", "y"]).samples(3), ["x", "y"]);
+        assert!(NumeralSystem::Bijective(&[]).samples(3).is_empty());
+        assert!(NamedNumeralSystem::Arabic.system().samples(0).is_empty());
+    }
+
+    #[test]
+    fn test_widest_in_range() {
+        for named in NamedNumeralSystem::iter() {
+            let system = named.system();
+            let expected = (0..200u64)
+                .filter_map(|n| Some((system.width(n).ok()?, n)))
+                .max()
+                .map(|(_, n)| n);
+            assert_eq!(system.widest_in_range(0..200), expected, "{}", named.name());
+        }
+        let roman = NamedNumeralSystem::LowerRoman.system();
+        assert_eq!(roman.widest_in_range(1..=100), Some(88));
+        assert_eq!(roman.widest_in_range(10..10), None);
+        assert_eq!(NamedNumeralSystem::LowerLatin.system().widest_in_range(0..=0), None);
+        assert_eq!(
+            NamedNumeralSystem::Arabic.system().widest_in_range(..),
+            Some(u64::MAX)
+        );
+
+        // Unbounded ranges are laid out without representing every number.
+        let symbolic = NumeralSystem::Symbolic(&["*", "**", "*"]);
+        assert_eq!(symbolic.widest_in_range(..), Some(29_999));
+        assert_eq!(symbolic.widest_in_range(..=29_998), Some(29_996));
+        assert_eq!(symbolic.widest_in_range(5..=6), Some(5));
+        let unary = NumeralSystem::Bijective(&["|"]);
+        assert_eq!(unary.widest_in_range(5..), Some(NumeralSystem::MAX_REPETITIONS));
+        let widest = roman.widest_in_range(..).unwrap();
+        assert!(roman.width(widest).unwrap() > NumeralSystem::MAX_REPETITIONS as usize);
+        #[cfg(feature = "cjk")]
+        {
+            let chinese = NamedNumeralSystem::LowerSimplifiedChinese.system();
+            assert!(chinese.widest_in_range(..).is_some());
+        }
+    }
+
+    #[test]
+    fn test_tabular() {
+        let roman = NamedNumeralSystem::UpperRoman.system();
+        let tabular = Tabular::for_range(roman, 1..=10);
+        assert_eq!(tabular.width, 4);
+        assert_eq!(
+            tabular.represent(5).unwrap().to_string(),
+            "\u{2007}\u{2007}\u{2007}V"
+        );
+        assert_eq!(tabular.represent(8).unwrap().to_string(), "VIII");
+        assert_eq!(tabular.represent(38).unwrap().to_string(), "XXXVIII");
+        assert_eq!(
+            tabular.represent(0).unwrap().to_string(),
+            "\u{2007}\u{2007}\u{2007}N"
+        );
+        let latin = Tabular::new(NamedNumeralSystem::LowerLatin.system(), 2);
+        assert!(matches!(latin.represent(0), Err(RepresentationError::Zero)));
+
+        let mut tabular = Tabular::new(roman, 3);
+        tabular.padding = " ";
+        assert_eq!(tabular.represent(4000).unwrap().to_string(), " I\u{305}V\u{305}");
+        assert_eq!(Tabular::for_range(roman, 5..5).width, 0);
+    }
+
+    /// Makes sure [`NumeralSystem::sequence`] handles range bounds properly.
+    #[test]
+    fn test_sequence_bounds() {
+        let system = NamedNumeralSystem::Arabic.system();
+        let collect =
+            |sequence: super::Sequence| sequence.map(Result::unwrap).collect::<Vec<_>>();
+        assert_eq!(collect(system.sequence(8..=10)), ["8", "9", "10"]);
+        assert_eq!(collect(system.sequence(..3)), ["0", "1", "2"]);
+        assert_eq!(collect(system.sequence(3..3)), Vec::<String>::new());
+        assert_eq!(collect(system.sequence(u64::MAX..)), [u64::MAX.to_string()]);
+        assert_eq!(collect(system.sequence(..0)), Vec::<String>::new());
+    }
+
     /// Compares the hashes of the first 50 thousand integers represented in
     /// each numeral system to pre-computed values.
     #[test]
@@ -1436,35 +5674,80 @@ mod tests {
         fn expected_hash(system: NamedNumeralSystem) -> u128 {
             match system {
                 NamedNumeralSystem::Arabic => 233363652923672209674688099512602556474,
+                #[cfg(feature = "symbols")]
                 NamedNumeralSystem::CircledArabic => 14788096368351499805674874468259519865,
+                #[cfg(feature = "symbols")]
                 NamedNumeralSystem::DoubleCircledArabic => 84846816834872732753601089381949808193,
+                #[cfg(feature = "cjk")]
+                NamedNumeralSystem::FullwidthArabic => 327811724770325206501324826984359074580,
                 NamedNumeralSystem::LowerLatin => 338462384600087330263193927875970822818,
                 NamedNumeralSystem::UpperLatin => 63389938855801182654207252735381557455,
+                #[cfg(feature = "symbols")]
+                NamedNumeralSystem::CircledLowerLatin => 191255068334759159432987952236995594386,
+                #[cfg(feature = "symbols")]
+                NamedNumeralSystem::CircledUpperLatin => 221967322463266293185662572796901773197,
+                #[cfg(feature = "symbols")]
+                NamedNumeralSystem::NegativeCircledLatin => 100903886285539825726855038115035034291,
+                #[cfg(feature = "symbols")]
+                NamedNumeralSystem::SquaredLatin => 73864802383632854032637036170956253102,
+                #[cfg(feature = "symbols")]
+                NamedNumeralSystem::NegativeSquaredLatin => 274415995709355629581302934195926214069,
                 NamedNumeralSystem::LowerRoman => 320120650624228984391933034556134697794,
                 NamedNumeralSystem::UpperRoman => 179137825631358807472580756311985798892,
+                #[cfg(feature = "historic")]
                 NamedNumeralSystem::LowerGreek => 286426313636684184647936794996618738517,
+                #[cfg(feature = "historic")]
                 NamedNumeralSystem::UpperGreek => 266767054320463395696526156316564222710,
+                #[cfg(feature = "historic")]
                 NamedNumeralSystem::LowerArmenian => 118575058866853099370711220898739682550,
+                #[cfg(feature = "historic")]
                 NamedNumeralSystem::UpperArmenian => 14157728964774965650431335537322548529,
+                #[cfg(feature = "rtl")]
                 NamedNumeralSystem::Hebrew => 206914675362605565607546884904163595545,
+                #[cfg(feature = "cjk")]
                 NamedNumeralSystem::LowerSimplifiedChinese => 111467758380137268027180550654359765178,
+                #[cfg(feature = "cjk")]
                 NamedNumeralSystem::UpperSimplifiedChinese => 245480392218028497842549251253255025420,
+                #[cfg(feature = "cjk")]
                 NamedNumeralSystem::LowerTraditionalChinese => 335477487643271707320761870063839694075,
+                #[cfg(feature = "cjk")]
                 NamedNumeralSystem::UpperTraditionalChinese => 97580884915630322847859767213149399933,
+                #[cfg(feature = "cjk")]
                 NamedNumeralSystem::HiraganaAiueo => 228263127493940549113355043662499568034,
+                #[cfg(feature = "cjk")]
                 NamedNumeralSystem::HiraganaIroha => 223752166294897561554884466357640039672,
+                #[cfg(feature = "cjk")]
                 NamedNumeralSystem::KatakanaAiueo => 159989562581792168649789815071020535332,
+                #[cfg(feature = "cjk")]
                 NamedNumeralSystem::KatakanaIroha => 199999534019736521402858209442755367027,
+                #[cfg(feature = "cjk")]
+                NamedNumeralSystem::KatakanaHalfwidth => 165827513398591865117120763756447155102,
+                #[cfg(feature = "cjk")]
                 NamedNumeralSystem::KoreanJamo => 65477685939649764827530478995838083425, // 21
+                #[cfg(feature = "cjk")]
                 NamedNumeralSystem::KoreanSyllable => 24217153056183571894327643661698510954,
+                #[cfg(feature = "rtl")]
                 NamedNumeralSystem::EasternArabic => 277754701051910363703826860323053920831,
+                #[cfg(feature = "rtl")]
                 NamedNumeralSystem::ArabicAbjad => 170607069918421736382595569105894253021,
+                #[cfg(feature = "rtl")]
                 NamedNumeralSystem::Persian => 6232158096065129450489636457808686806,
+                #[cfg(feature = "indic")]
                 NamedNumeralSystem::Devanagari => 327133969362282954753636774557232534052,
+                #[cfg(feature = "indic")]
                 NamedNumeralSystem::Tibetan => 87580519645280744681237273097105390953,
+                #[cfg(feature = "indic")]
                 NamedNumeralSystem::Bengali => 79096832028418218544110224478554962928,
+                #[cfg(feature = "indic")]
                 NamedNumeralSystem::BengaliLetters => 269999388716378396079918080520770981179,
+                #[cfg(feature = "symbols")]
                 NamedNumeralSystem::Symbols => 88780534058354093087932015985325954737,
+                #[cfg(feature = "symbols")]
+                NamedNumeralSystem::GermanSymbols => 141980753879355130797571671965879564084,
+                #[cfg(feature = "symbols")]
+                NamedNumeralSystem::FrenchSymbols => 91857857666538917279891041886300816023,
+                #[cfg(feature = "symbols")]
+                NamedNumeralSystem::ChineseSymbols => 261994019725211420438601135819309542255,
             }
         }
 
@@ -0,0 +1,45 @@
+//! Implementations of [`Arbitrary`] for numeral systems, for fuzzing.
+
+use ::arbitrary::{Arbitrary, Result, Unstructured};
+
+use super::{NamedNumeralSystem, NumeralSystem};
+
+/// Numeral systems with unusual parameters, which are not covered by the
+/// named numeral systems.
+static EDGE_CASES: &[NumeralSystem<'static>] = &[
+    NumeralSystem::Positional(&[]),
+    NumeralSystem::Positional(&["0"]),
+    NumeralSystem::Positional(&["", "1"]),
+    NumeralSystem::Bijective(&[]),
+    NumeralSystem::Bijective(&["I"]),
+    NumeralSystem::Additive(&[]),
+    NumeralSystem::Additive(&[("V", 5)]),
+    NumeralSystem::Additive(&[("I", 1), ("Z", 0)]),
+    NumeralSystem::Additive(&[("M", u64::MAX), ("I", 1)]),
+    NumeralSystem::Symbolic(&[]),
+    NumeralSystem::Symbolic(&["*"]),
+    NumeralSystem::Fixed(&[]),
+    NumeralSystem::ZerolessFixed(&[]),
+];
+
+impl<'a> Arbitrary<'a> for NamedNumeralSystem {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let index = u.choose_index(Self::COUNT)?;
+        Ok(Self::iter().nth(index).unwrap())
+    }
+}
+
+/// Either a named numeral system or one with unusual parameters.
+///
+/// Since numeral systems borrow their symbols, generating arbitrary symbols
+/// would require leaking them. Instead, the systems are chosen among a fixed
+/// set.
+impl<'a> Arbitrary<'a> for NumeralSystem<'static> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        if u.arbitrary()? {
+            Ok(u.arbitrary::<NamedNumeralSystem>()?.system())
+        } else {
+            u.choose(EDGE_CASES).copied()
+        }
+    }
+}
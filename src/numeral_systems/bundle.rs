@@ -0,0 +1,418 @@
+//! A compact binary format for custom numeral systems.
+//!
+//! Applications can [`encode`] their custom numeral systems once, for
+//! example in a build script, and ship the resulting bytes. Decoding a
+//! [`Bundle`] does not copy the symbols: they are borrowed from the bytes,
+//! which can thus be included with [`include_bytes!`] or memory-mapped.
+//!
+//! ## Example
+//!
+//! ```
+//! use codex::numeral_systems::NumeralSystem;
+//! use codex::numeral_systems::bundle::{self, Bundle};
+//!
+//! let bytes = bundle::encode([
+//!     ("binary", NumeralSystem::Positional(&["0", "1"])),
+//!     ("dice", NumeralSystem::ZerolessFixed(&["⚀", "⚁", "⚂", "⚃", "⚄", "⚅"])),
+//! ]);
+//! let bundle = Bundle::decode(&bytes).unwrap();
+//! let dice = bundle.get("dice").unwrap();
+//! assert_eq!(dice.represent(3).unwrap().to_string(), "⚂");
+//! ```
+//!
+//! ## Format
+//!
+//! All integers are little-endian, and strings are prefixed with their length
+//! in bytes, as a `u32`. A bundle starts with the magic bytes `CXNS`, a format
+//! version byte (currently 1), and the number of numeral systems as a `u32`.
+//! Each numeral system then consists of its name, a byte for its kind, and:
+//! - for Chinese systems, a byte for the variant and a byte for the case;
+//! - for other systems, the number of symbols as a `u32`, followed by the
+//!   symbols. In additive systems, each symbol is followed by its value, as a
+//!   `u64`.
+//!
+//! A single numeral system can also be encoded with
+//! [`NumeralSystem::to_bytes`], and hashed with
+//! [`NumeralSystem::stable_hash`], for example to cache its representations.
+
+use std::ops::Range;
+
+#[cfg(feature = "cjk")]
+use chinese_number::{ChineseCase, ChineseVariant};
+
+use super::{NumeralSystem, RegistrationError, register};
+
+/// The bytes a bundle starts with.
+const MAGIC: &[u8; 4] = b"CXNS";
+
+/// The version of the format written by [`encode`].
+const VERSION: u8 = 1;
+
+/// Encodes numeral systems and their names into a bundle.
+///
+/// For example, the [registered](super::registered) numeral systems can be
+/// encoded with `encode(numeral_systems::registered())`.
+pub fn encode<'a>(
+    systems: impl IntoIterator<Item = (&'a str, NumeralSystem<'a>)>,
+) -> Vec<u8> {
+    let systems: Vec<_> = systems.into_iter().collect();
+    let mut out = MAGIC.to_vec();
+    out.push(VERSION);
+    write_len(&mut out, systems.len());
+    for (name, system) in systems {
+        write_str(&mut out, name);
+        write_system(&mut out, system);
+    }
+    out
+}
+
+impl NumeralSystem<'_> {
+    /// Encodes this numeral system, as in a bundle, without a name.
+    ///
+    /// The result starts with the version of the format, and only depends on
+    /// the kind and the symbols of the numeral system, so it is the same
+    /// across runs, platforms, and releases with the same version. This makes
+    /// it suitable as a cache key for representations, which is invalidated
+    /// whenever a custom numeral system changes.
+    pub fn to_bytes(self) -> Vec<u8> {
+        let mut out = vec![VERSION];
+        write_system(&mut out, self);
+        out
+    }
+
+    /// Returns a hash of this numeral system that is the same across runs,
+    /// platforms, and releases with the same version of the bundle format.
+    ///
+    /// This is the 64-bit FNV-1a hash of [`to_bytes`](Self::to_bytes). Unlike
+    /// the hashes of [`std::hash::Hash`], it can be stored, for example to
+    /// cache representations keyed on the numeral system and the number.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use codex::numeral_systems::NumeralSystem;
+    ///
+    /// let binary = NumeralSystem::Positional(&["0", "1"]);
+    /// assert_eq!(binary.stable_hash(), NumeralSystem::Positional(&["0", "1"]).stable_hash());
+    /// assert_ne!(binary.stable_hash(), NumeralSystem::Bijective(&["0", "1"]).stable_hash());
+    /// ```
+    pub fn stable_hash(self) -> u64 {
+        self.to_bytes().iter().fold(0xcbf2_9ce4_8422_2325, |hash, &byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+        })
+    }
+}
+
+/// Numeral systems decoded from bytes produced by [`encode`].
+#[derive(Debug, Clone)]
+pub struct Bundle<'a> {
+    entries: Vec<Entry<'a>>,
+    /// The symbols of the non-additive numeral systems.
+    symbols: Vec<&'a str>,
+    /// The numerals of the additive numeral systems.
+    numerals: Vec<(&'a str, u64)>,
+}
+
+/// A numeral system of a bundle.
+#[derive(Debug, Clone)]
+struct Entry<'a> {
+    name: &'a str,
+    kind: Kind,
+    /// The range of the symbols or numerals of the numeral system.
+    range: Range<usize>,
+}
+
+/// The kind of a numeral system of a bundle.
+#[derive(Debug, Copy, Clone)]
+enum Kind {
+    Positional,
+    Bijective,
+    Additive,
+    Symbolic,
+    Fixed,
+    ZerolessFixed,
+    #[cfg(feature = "cjk")]
+    Chinese(ChineseVariant, ChineseCase),
+}
+
+impl<'a> Bundle<'a> {
+    /// Decodes a bundle.
+    ///
+    /// The symbols are borrowed from the bytes, so this only allocates the
+    /// lists of symbols of the numeral systems.
+    pub fn decode(bytes: &'a [u8]) -> Result<Self, DecodeError> {
+        let mut reader = Reader(bytes);
+        if reader.take(MAGIC.len())? != MAGIC {
+            return Err(DecodeError::InvalidHeader);
+        }
+        let version = reader.u8()?;
+        if version != VERSION {
+            return Err(DecodeError::UnsupportedVersion(version));
+        }
+        let mut bundle = Self { entries: vec![], symbols: vec![], numerals: vec![] };
+        for _ in 0..reader.u32()? {
+            let name = reader.str()?;
+            let kind = match reader.u8()? {
+                0 => Kind::Positional,
+                1 => Kind::Bijective,
+                2 => Kind::Additive,
+                3 => Kind::Symbolic,
+                4 => Kind::Fixed,
+                5 => Kind::ZerolessFixed,
+                #[cfg(feature = "cjk")]
+                6 => {
+                    let variant = match reader.u8()? {
+                        0 => ChineseVariant::Traditional,
+                        1 => ChineseVariant::Simple,
+                        _ => return Err(DecodeError::InvalidKind(6)),
+                    };
+                    let case = match reader.u8()? {
+                        0 => ChineseCase::Upper,
+                        1 => ChineseCase::Lower,
+                        _ => return Err(DecodeError::InvalidKind(6)),
+                    };
+                    let range = 0..0;
+                    let kind = Kind::Chinese(variant, case);
+                    bundle.entries.push(Entry { name, kind, range });
+                    continue;
+                }
+                kind => return Err(DecodeError::InvalidKind(kind)),
+            };
+            let len = reader.u32()? as usize;
+            let range = if let Kind::Additive = kind {
+                let start = bundle.numerals.len();
+                for _ in 0..len {
+                    let symbol = reader.str()?;
+                    let value = u64::from_le_bytes(reader.take(8)?.try_into().unwrap());
+                    bundle.numerals.push((symbol, value));
+                }
+                start..bundle.numerals.len()
+            } else {
+                let start = bundle.symbols.len();
+                for _ in 0..len {
+                    bundle.symbols.push(reader.str()?);
+                }
+                start..bundle.symbols.len()
+            };
+            bundle.entries.push(Entry { name, kind, range });
+        }
+        if !reader.0.is_empty() {
+            return Err(DecodeError::TrailingBytes);
+        }
+        Ok(bundle)
+    }
+
+    /// Returns the numeral system of this bundle with the given name, if any.
+    pub fn get(&self, name: &str) -> Option<NumeralSystem<'_>> {
+        self.iter().find(|&(n, _)| n == name).map(|(_, system)| system)
+    }
+
+    /// Returns an iterator over the numeral systems of this bundle and their
+    /// names, in the order in which they were encoded.
+    pub fn iter(&self) -> impl Iterator<Item = (&'a str, NumeralSystem<'_>)> {
+        self.entries.iter().map(|entry| (entry.name, self.system(entry)))
+    }
+
+    /// [Registers](register) the numeral systems of this bundle, for the rest
+    /// of the program.
+    ///
+    /// The bundle is leaked, as registered numeral systems live for the rest
+    /// of the program. If a numeral system cannot be registered, the error is
+    /// returned and the following numeral systems are not registered.
+    pub fn register(self) -> Result<(), RegistrationError>
+    where
+        'a: 'static,
+    {
+        let bundle: &'static Bundle<'static> = Box::leak(Box::new(self));
+        bundle.iter().try_for_each(|(name, system)| register(name, system))
+    }
+
+    /// Returns the numeral system of an entry.
+    fn system(&self, entry: &Entry) -> NumeralSystem<'_> {
+        let symbols = || &self.symbols[entry.range.clone()];
+        match entry.kind {
+            Kind::Positional => NumeralSystem::Positional(symbols()),
+            Kind::Bijective => NumeralSystem::Bijective(symbols()),
+            Kind::Additive => {
+                NumeralSystem::Additive(&self.numerals[entry.range.clone()])
+            }
+            Kind::Symbolic => NumeralSystem::Symbolic(symbols()),
+            Kind::Fixed => NumeralSystem::Fixed(symbols()),
+            Kind::ZerolessFixed => NumeralSystem::ZerolessFixed(symbols()),
+            #[cfg(feature = "cjk")]
+            Kind::Chinese(variant, case) => NumeralSystem::Chinese(variant, case),
+        }
+    }
+}
+
+/// A reason why a bundle cannot be decoded.
+///
+/// Values of this type are returned by [`Bundle::decode`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum DecodeError {
+    /// The bytes do not start with the magic bytes of bundles.
+    InvalidHeader,
+    /// The bundle was encoded with an unsupported version of the format.
+    UnsupportedVersion(u8),
+    /// The bytes end in the middle of the bundle.
+    Truncated,
+    /// The bytes continue after the end of the bundle.
+    TrailingBytes,
+    /// A name or symbol is not valid UTF-8.
+    InvalidUtf8,
+    /// A numeral system is of an unknown kind, or of a kind whose cargo
+    /// feature is disabled.
+    InvalidKind(u8),
+}
+
+/// Reads the parts of a bundle.
+struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+    /// Reads a number of bytes.
+    fn take(&mut self, len: usize) -> Result<&'a [u8], DecodeError> {
+        let (bytes, rest) = self.0.split_at_checked(len).ok_or(DecodeError::Truncated)?;
+        self.0 = rest;
+        Ok(bytes)
+    }
+
+    fn u8(&mut self) -> Result<u8, DecodeError> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u32(&mut self) -> Result<u32, DecodeError> {
+        Ok(u32::from_le_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    /// Reads a string prefixed with its length.
+    fn str(&mut self) -> Result<&'a str, DecodeError> {
+        let len = self.u32()? as usize;
+        std::str::from_utf8(self.take(len)?).map_err(|_| DecodeError::InvalidUtf8)
+    }
+}
+
+/// Writes the kind and the symbols of a numeral system.
+fn write_system(out: &mut Vec<u8>, system: NumeralSystem) {
+    let (kind, symbols) = match system {
+        NumeralSystem::Positional(symbols) => (0, symbols),
+        NumeralSystem::Bijective(symbols) => (1, symbols),
+        NumeralSystem::Symbolic(symbols) => (3, symbols),
+        NumeralSystem::Fixed(symbols) => (4, symbols),
+        NumeralSystem::ZerolessFixed(symbols) => (5, symbols),
+        NumeralSystem::Additive(numerals) => {
+            out.push(2);
+            write_len(out, numerals.len());
+            for &(symbol, value) in numerals {
+                write_str(out, symbol);
+                out.extend_from_slice(&value.to_le_bytes());
+            }
+            return;
+        }
+        #[cfg(feature = "cjk")]
+        NumeralSystem::Chinese(variant, case) => {
+            out.push(6);
+            out.push(match variant {
+                ChineseVariant::Traditional => 0,
+                ChineseVariant::Simple => 1,
+            });
+            out.push(match case {
+                ChineseCase::Upper => 0,
+                ChineseCase::Lower => 1,
+            });
+            return;
+        }
+    };
+    out.push(kind);
+    write_len(out, symbols.len());
+    for symbol in symbols {
+        write_str(out, symbol);
+    }
+}
+
+/// Writes a length as a `u32`.
+fn write_len(out: &mut Vec<u8>, len: usize) {
+    let len = u32::try_from(len).expect("bundles are limited to 4 GiB");
+    out.extend_from_slice(&len.to_le_bytes());
+}
+
+/// Writes a string prefixed with its length.
+fn write_str(out: &mut Vec<u8>, s: &str) {
+    write_len(out, s.len());
+    out.extend_from_slice(s.as_bytes());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Bundle, DecodeError, encode};
+    use crate::numeral_systems::{NamedNumeralSystem, NumeralSystem, lookup_registered};
+
+    #[test]
+    fn test_round_trip() {
+        let systems: Vec<_> = NamedNumeralSystem::iter()
+            .map(|named| (named.name(), named.system()))
+            .collect();
+        let bytes = encode(systems.iter().copied());
+        let bundle = Bundle::decode(&bytes).unwrap();
+        assert_eq!(bundle.iter().count(), systems.len());
+        for ((name, system), (decoded_name, decoded)) in systems.iter().zip(bundle.iter())
+        {
+            assert_eq!(*name, decoded_name);
+            for n in [0, 1, 2, 9, 10, 27, 1234, 99999] {
+                assert_eq!(
+                    system.represent(n).map(|r| r.to_string()),
+                    decoded.represent(n).map(|r| r.to_string()),
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_register() {
+        static BYTES: std::sync::OnceLock<Vec<u8>> = std::sync::OnceLock::new();
+        let bytes = BYTES.get_or_init(|| {
+            encode([("test-bundle-ternary", NumeralSystem::Positional(&["0", "1", "2"]))])
+        });
+        Bundle::decode(bytes).unwrap().register().unwrap();
+        let ternary = lookup_registered("test-bundle-ternary").unwrap();
+        assert_eq!(ternary.represent(5).unwrap().to_string(), "12");
+    }
+
+    #[test]
+    fn test_stable_hash() {
+        let binary = NumeralSystem::Positional(&["0", "1"]);
+        assert_eq!(binary.to_bytes(), b"\x01\x00\x02\0\0\0\x01\0\0\x000\x01\0\0\x001");
+        assert_eq!(binary.stable_hash(), 1_252_490_635_750_132_111);
+        let bytes = encode([("binary", binary)]);
+        assert!(bytes.ends_with(&binary.to_bytes()[1..]));
+        assert_ne!(
+            binary.stable_hash(),
+            NumeralSystem::Positional(&["0", "1", "2"]).stable_hash()
+        );
+        assert_ne!(
+            NamedNumeralSystem::UpperRoman.system().stable_hash(),
+            NamedNumeralSystem::LowerRoman.system().stable_hash(),
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        let bytes = encode([("binary", NumeralSystem::Positional(&["0", "1"]))]);
+        assert_eq!(Bundle::decode(b"CXN").unwrap_err(), DecodeError::Truncated);
+        assert_eq!(Bundle::decode(b"JSON").unwrap_err(), DecodeError::InvalidHeader);
+        assert_eq!(
+            Bundle::decode(b"CXNS\x02").unwrap_err(),
+            DecodeError::UnsupportedVersion(2),
+        );
+        assert_eq!(
+            Bundle::decode(&bytes[..bytes.len() - 1]).unwrap_err(),
+            DecodeError::Truncated,
+        );
+        let mut trailing = bytes.clone();
+        trailing.push(0);
+        assert_eq!(Bundle::decode(&trailing).unwrap_err(), DecodeError::TrailingBytes);
+        let mut invalid = bytes.clone();
+        let kind = 4 + 1 + 4 + 4 + "binary".len();
+        invalid[kind] = 42;
+        assert_eq!(Bundle::decode(&invalid).unwrap_err(), DecodeError::InvalidKind(42));
+    }
+}
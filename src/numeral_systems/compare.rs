@@ -0,0 +1,214 @@
+//! Comparison of numerals of possibly different named numeral systems by their
+//! values.
+
+use std::cmp::Ordering;
+
+use super::{NamedNumeralSystem, NumeralSystem, digit_indices};
+
+/// Returns the value of a numeral of any named numeral system, if any.
+///
+/// A numeral can be valid in several numeral systems, such as `ii`, which is
+/// both a Roman numeral and a sequence of Latin letters. It is then read as
+/// an [Arabic](NamedNumeralSystem::Arabic) numeral if possible, as a
+/// [Roman](NamedNumeralSystem::LowerRoman) numeral otherwise, and else in the
+/// first of the other named numeral systems, in the order of
+/// [`NamedNumeralSystem::ALL`], that can parse it. Roman numeral characters
+/// such as `Ⅻ` are read as Roman numerals, as [parsing](NumeralSystem::parse)
+/// [normalizes](super::normalize_roman) them.
+///
+/// ## Example
+///
+/// ```
+/// use codex::numeral_systems::numeral_value;
+///
+/// assert_eq!(numeral_value("42"), Some(42));
+/// assert_eq!(numeral_value("ii"), Some(2));
+/// assert_eq!(numeral_value("AB"), Some(28));
+/// assert_eq!(numeral_value("Ⅻ"), Some(12));
+/// assert_eq!(numeral_value("?"), None);
+/// ```
+pub fn numeral_value(numeral: &str) -> Option<u64> {
+    const PREFERRED: [NamedNumeralSystem; 3] = [
+        NamedNumeralSystem::Arabic,
+        NamedNumeralSystem::LowerRoman,
+        NamedNumeralSystem::UpperRoman,
+    ];
+    PREFERRED
+        .into_iter()
+        .chain(NamedNumeralSystem::iter().filter(|named| !PREFERRED.contains(named)))
+        .find_map(|named| named.system().parse(numeral).ok())
+}
+
+/// Compares two numerals, possibly of different named numeral systems, by
+/// their values.
+///
+/// The values are read with [`numeral_value`]. Numerals without a value are
+/// greater than all others, and equal to each other.
+///
+/// ## Example
+///
+/// ```
+/// use std::cmp::Ordering;
+/// use codex::numeral_systems::compare_numerals;
+///
+/// assert_eq!(compare_numerals("ii", "3"), Ordering::Less);
+/// assert_eq!(compare_numerals("IV", "4"), Ordering::Equal);
+/// assert_eq!(compare_numerals("?", "1000"), Ordering::Greater);
+/// ```
+pub fn compare_numerals(a: &str, b: &str) -> Ordering {
+    key(a).cmp(&key(b))
+}
+
+/// Sorts numerals, possibly of different named numeral systems, by their
+/// values.
+///
+/// This is the order of [`compare_numerals`]. The sort is stable, so
+/// numerals with the same value, as well as numerals without a value, keep
+/// their relative order. Each numeral is only parsed once.
+///
+/// ## Example
+///
+/// ```
+/// use codex::numeral_systems::sort_numerals;
+///
+/// let mut numerals = ["IV", "?", "3", "ii", "V"];
+/// sort_numerals(&mut numerals);
+/// assert_eq!(numerals, ["ii", "3", "IV", "V", "?"]);
+/// ```
+pub fn sort_numerals<S: AsRef<str>>(numerals: &mut [S]) {
+    numerals.sort_by_cached_key(|numeral| key(numeral.as_ref()));
+}
+
+/// Returns a key of a numeral of a numeral system, such that the keys of
+/// numerals of the same numeral system sort bytewise in the order of the
+/// numerals, or `None` if the numeral is invalid.
+///
+/// This allows databases and indexes to sort numbered headings without
+/// storing their values. The numerals are ordered as follows:
+/// - In positional numeral systems, by value, even beyond `u64::MAX`. Leading
+///   zeros are ignored.
+/// - In bijective numeral systems, lexicographically by digit, so that `a`
+///   comes before `aa`, which comes before `b`. Labels of any length are
+///   supported.
+/// - In other numeral systems, by value, since their numerals are
+///   [parsed](NumeralSystem::parse).
+///
+/// Keys of numerals of different numeral systems are not comparable.
+///
+/// ## Example
+///
+/// ```
+/// use codex::numeral_systems::{NamedNumeralSystem, collation_key};
+///
+/// let roman = NamedNumeralSystem::LowerRoman.system();
+/// assert!(collation_key(roman, "ii") < collation_key(roman, "x"));
+///
+/// let latin = NamedNumeralSystem::LowerLatin.system();
+/// assert!(collation_key(latin, "a") < collation_key(latin, "aa"));
+/// assert!(collation_key(latin, "aa") < collation_key(latin, "b"));
+/// ```
+pub fn collation_key(system: NumeralSystem, numeral: &str) -> Option<Vec<u8>> {
+    let digits = match system {
+        NumeralSystem::Positional(digits) | NumeralSystem::Bijective(digits)
+            if digits.len() >= 2 =>
+        {
+            digits
+        }
+        _ => return system.parse(numeral).ok().map(|value| value.to_be_bytes().to_vec()),
+    };
+    let mut indices = digit_indices(digits, numeral)?;
+    let mut key = vec![];
+    if let NumeralSystem::Positional(_) = system {
+        // Longer numbers are larger, once leading zeros are removed.
+        let zeros = indices.iter().take_while(|&&index| index == 0).count();
+        indices.drain(..zeros);
+        key.extend_from_slice(&(indices.len() as u64).to_be_bytes());
+    }
+    for index in indices {
+        if digits.len() > 256 {
+            key.extend_from_slice(&(index as u32).to_be_bytes());
+        } else {
+            key.push(index as u8);
+        }
+    }
+    Some(key)
+}
+
+/// Returns the sort key of a numeral, such that numerals without a value come
+/// last.
+fn key(numeral: &str) -> (bool, u64) {
+    match numeral_value(numeral) {
+        Some(value) => (false, value),
+        None => (true, 0),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+
+    use super::{collation_key, compare_numerals, numeral_value, sort_numerals};
+    use crate::numeral_systems::{NamedNumeralSystem, NumeralSystem};
+
+    #[test]
+    fn test_numeral_value() {
+        assert_eq!(numeral_value("0"), Some(0));
+        assert_eq!(numeral_value("i"), Some(1));
+        assert_eq!(numeral_value("MCMXCIX"), Some(1999));
+        assert_eq!(numeral_value("iiii"), Some(164_511));
+        assert_eq!(numeral_value("1a"), None);
+        assert_eq!(numeral_value("z"), Some(26));
+        assert_eq!(numeral_value("ⅯⅭⅯⅩⅭⅨ"), Some(1999));
+        assert_eq!(numeral_value("ⅹiv"), Some(14));
+        assert_eq!(numeral_value(""), None);
+        #[cfg(feature = "symbols")]
+        assert_eq!(numeral_value("④"), Some(4));
+        #[cfg(feature = "cjk")]
+        assert_eq!(numeral_value("十二"), Some(12));
+    }
+
+    #[test]
+    fn test_sort_numerals() {
+        assert_eq!(compare_numerals("x", "9"), Ordering::Greater);
+        assert_eq!(compare_numerals("", "?"), Ordering::Equal);
+        let mut numerals = vec![
+            String::from("c"),
+            "b".into(),
+            "?".into(),
+            "V".into(),
+            "v".into(),
+            "!".into(),
+            "1".into(),
+        ];
+        sort_numerals(&mut numerals);
+        assert_eq!(numerals, ["1", "b", "V", "v", "c", "?", "!"]);
+    }
+
+    #[test]
+    fn test_collation_key() {
+        let arabic = NamedNumeralSystem::Arabic.system();
+        let key = |numeral| collation_key(arabic, numeral).unwrap();
+        assert!(key("9") < key("10"));
+        assert!(key("99999999999999999999") < key("100000000000000000000"));
+        assert_eq!(key("007"), key("7"));
+        assert!(key("0") < key("1"));
+        assert_eq!(collation_key(arabic, "1a"), None);
+        assert_eq!(collation_key(arabic, ""), None);
+
+        let latin = NamedNumeralSystem::UpperLatin.system();
+        let mut labels = ["B", "AA", "A", "ZZZZZZZZZZZZZZZ", "Z"];
+        labels.sort_by_key(|label| collation_key(latin, label).unwrap());
+        assert_eq!(labels, ["A", "AA", "B", "Z", "ZZZZZZZZZZZZZZZ"]);
+
+        let roman = NamedNumeralSystem::UpperRoman.system();
+        let mut numerals = ["X", "IX", "N", "MM", "C"];
+        numerals.sort_by_key(|numeral| collation_key(roman, numeral).unwrap());
+        assert_eq!(numerals, ["N", "IX", "X", "C", "MM"]);
+        assert_eq!(collation_key(roman, "IIII"), None);
+
+        let digits: Vec<String> = (0..300).map(|i| i.to_string()).collect();
+        let digits: Vec<&str> = digits.iter().map(String::as_str).collect();
+        let wide = NumeralSystem::Bijective(&digits);
+        assert!(collation_key(wide, "299").unwrap() > collation_key(wide, "1").unwrap());
+    }
+}
@@ -0,0 +1,401 @@
+//! The state of hierarchical counters, which can be persisted between runs.
+
+use super::bundle::DecodeError;
+use super::{Preset, Reference, RepresentationError};
+
+/// The bytes an encoded counter state starts with.
+const MAGIC: &[u8; 4] = b"CXCT";
+
+/// The version of the format written by [`CounterState::to_bytes`].
+const VERSION: u8 = 2;
+
+/// The state of a hierarchical counter, such as that of the headings of a
+/// document: the current number of each level, from the outermost to the
+/// innermost.
+///
+/// Multi-pass document builds, such as those generating a table of contents,
+/// can persist the state between runs with [`to_bytes`](Self::to_bytes) and
+/// restore it with [`from_bytes`](Self::from_bytes). With the `serde`
+/// feature, the state can also be serialized.
+///
+/// Like the counters of word processors and CSS, levels start at
+/// [a configurable value](Self::with_start), and restart at each step of
+/// their parent level unless
+/// [configured otherwise](Self::with_restart). These options are not part of
+/// the persisted state, and neither are the values that levels
+/// [skip](Self::with_skipped).
+///
+/// ## Example
+///
+/// ```
+/// use codex::numeral_systems::{CounterState, Preset};
+///
+/// let mut state = CounterState::new();
+/// state.step(1);
+/// state.step(2);
+/// state.step(2);
+/// let legal = Preset::from_name("legal").unwrap();
+/// assert_eq!(state.represent(&legal).unwrap(), "1.2");
+///
+/// let restored = CounterState::from_bytes(&state.to_bytes()).unwrap();
+/// assert_eq!(restored, state);
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct CounterState {
+    /// The numbers of the current levels, followed by those of the deeper
+    /// levels kept by [`RestartPolicy::Continue`].
+    levels: Vec<u64>,
+    /// Invariant: At most the number of levels.
+    depth: usize,
+    start: u64,
+    restart: RestartPolicy,
+    /// Invariant: Sorted and without duplicates.
+    skipped: Vec<u64>,
+}
+
+impl CounterState {
+    /// Creates a counter state without any level, whose levels start at one
+    /// and restart at each step of their parent level.
+    pub const fn new() -> Self {
+        Self {
+            levels: Vec::new(),
+            depth: 0,
+            start: 1,
+            restart: RestartPolicy::Parent,
+            skipped: Vec::new(),
+        }
+    }
+
+    /// Creates a counter state from the numbers of its levels.
+    pub fn from_levels(levels: Vec<u64>) -> Self {
+        Self { depth: levels.len(), levels, ..Self::new() }
+    }
+
+    /// Creates a counter state from the numbers of its levels and of the
+    /// deeper levels kept by [`RestartPolicy::Continue`].
+    #[cfg(feature = "serde")]
+    pub(super) fn from_parts(mut levels: Vec<u64>, kept: &[u64]) -> Self {
+        let depth = levels.len();
+        levels.extend_from_slice(kept);
+        Self { levels, depth, ..Self::new() }
+    }
+
+    /// Returns the numbers of the deeper levels kept by
+    /// [`RestartPolicy::Continue`].
+    #[cfg(feature = "serde")]
+    pub(super) fn kept(&self) -> &[u64] {
+        &self.levels[self.depth..]
+    }
+
+    /// Sets the number of a level when it is first stepped, which is one by
+    /// default.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use codex::numeral_systems::CounterState;
+    ///
+    /// let mut state = CounterState::new().with_start(0);
+    /// state.step(1);
+    /// state.step(2);
+    /// assert_eq!(state.levels(), [0, 0]);
+    /// ```
+    pub fn with_start(mut self, start: u64) -> Self {
+        self.start = start;
+        self
+    }
+
+    /// Sets when the levels restart.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use codex::numeral_systems::{CounterState, RestartPolicy};
+    ///
+    /// let mut state = CounterState::new().with_restart(RestartPolicy::Continue);
+    /// state.step(1);
+    /// state.step(2);
+    /// state.step(2);
+    /// state.step(1);
+    /// state.step(2);
+    /// assert_eq!(state.levels(), [2, 3]);
+    /// ```
+    pub fn with_restart(mut self, restart: RestartPolicy) -> Self {
+        self.restart = restart;
+        self
+    }
+
+    /// Sets the values that levels skip when they are stepped, such as 13 for
+    /// hotel floors, or the numbers of repealed sections.
+    ///
+    /// The values are only skipped by [`step`](Self::step), so that callers
+    /// keep stepping levels one at a time.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use codex::numeral_systems::CounterState;
+    ///
+    /// let mut state = CounterState::new().with_skipped([13]);
+    /// let floors: Vec<u64> = (0..14)
+    ///     .map(|_| {
+    ///         state.step(1);
+    ///         state.levels()[0]
+    ///     })
+    ///     .collect();
+    /// assert_eq!(floors[11..], [12, 14, 15]);
+    /// ```
+    pub fn with_skipped(mut self, skipped: impl IntoIterator<Item = u64>) -> Self {
+        self.skipped = skipped.into_iter().collect();
+        self.skipped.sort_unstable();
+        self.skipped.dedup();
+        self
+    }
+
+    /// Returns the values that levels skip when they are stepped, in
+    /// ascending order.
+    pub fn skipped(&self) -> &[u64] {
+        &self.skipped
+    }
+
+    /// Returns the number of a level when it is first stepped.
+    pub fn start(&self) -> u64 {
+        self.start
+    }
+
+    /// Returns when the levels restart.
+    pub fn restart(&self) -> RestartPolicy {
+        self.restart
+    }
+
+    /// Returns the numbers of the levels of this counter state.
+    pub fn levels(&self) -> &[u64] {
+        &self.levels[..self.depth]
+    }
+
+    /// Steps the level at a depth, counting from one.
+    ///
+    /// A level that is stepped for the first time, or for the first time
+    /// since it restarted, gets the [start value](Self::with_start). The
+    /// missing shallower levels are added with the number before the start
+    /// value, as if they had never been stepped. The deeper levels are
+    /// removed, and restart unless the [restart policy](Self::with_restart)
+    /// is [`Continue`](RestartPolicy::Continue). [Skipped](Self::with_skipped)
+    /// values are jumped over, unless no greater value is left.
+    pub fn step(&mut self, depth: usize) {
+        if depth == 0 {
+            return;
+        }
+        if self.restart == RestartPolicy::Parent {
+            self.levels.truncate(depth);
+        }
+        let mut number = if self.levels.len() < depth {
+            self.levels.resize(depth, self.start.saturating_sub(1));
+            self.start
+        } else {
+            self.levels[depth - 1].saturating_add(1)
+        };
+        while number < u64::MAX && self.skipped.binary_search(&number).is_ok() {
+            number += 1;
+        }
+        self.levels[depth - 1] = number;
+        self.depth = depth;
+    }
+
+    /// Tries to represent the levels of this counter state with a preset.
+    pub fn represent(&self, preset: &Preset) -> Result<String, RepresentationError> {
+        preset.represent(self.levels())
+    }
+
+    /// Tries to represent the levels of this counter state with a preset,
+    /// along with a key to refer to them. See [`Preset::reference`].
+    pub fn reference(
+        &self,
+        preset: &Preset,
+        kind: &str,
+    ) -> Result<Reference, RepresentationError> {
+        preset.reference(kind, self.levels())
+    }
+
+    /// Encodes this counter state.
+    ///
+    /// The state starts with the magic bytes `CXCT` and a format version byte
+    /// (currently 2), followed by the number of levels and the number of
+    /// deeper levels kept by [`RestartPolicy::Continue`] as little-endian
+    /// `u32`s, and the number of each of these levels as a little-endian
+    /// `u64`. Version 1 lacks the number of deeper levels.
+    ///
+    /// The start value and the restart policy are not encoded.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = MAGIC.to_vec();
+        out.push(VERSION);
+        for len in [self.depth, self.levels.len() - self.depth] {
+            let len = u32::try_from(len).expect("too many levels");
+            out.extend_from_slice(&len.to_le_bytes());
+        }
+        for level in &self.levels {
+            out.extend_from_slice(&level.to_le_bytes());
+        }
+        out
+    }
+
+    /// Decodes a counter state encoded with [`to_bytes`](Self::to_bytes).
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, DecodeError> {
+        let rest = bytes.strip_prefix(MAGIC).ok_or(if bytes.len() < MAGIC.len() {
+            DecodeError::Truncated
+        } else {
+            DecodeError::InvalidHeader
+        })?;
+        let (&version, rest) = rest.split_first().ok_or(DecodeError::Truncated)?;
+        if !(1..=VERSION).contains(&version) {
+            return Err(DecodeError::UnsupportedVersion(version));
+        }
+        let (depth, rest) =
+            rest.split_first_chunk::<4>().ok_or(DecodeError::Truncated)?;
+        let depth = u32::from_le_bytes(*depth) as usize;
+        let (kept, rest) = match version {
+            1 => (0, rest),
+            _ => {
+                let (kept, rest) =
+                    rest.split_first_chunk::<4>().ok_or(DecodeError::Truncated)?;
+                (u32::from_le_bytes(*kept) as usize, rest)
+            }
+        };
+        let len = depth.saturating_add(kept);
+        let (levels, rest) = rest.as_chunks::<8>();
+        if levels.len() < len {
+            return Err(DecodeError::Truncated);
+        }
+        if levels.len() > len || !rest.is_empty() {
+            return Err(DecodeError::TrailingBytes);
+        }
+        Ok(Self {
+            levels: levels.iter().map(|&level| u64::from_le_bytes(level)).collect(),
+            depth,
+            ..Self::new()
+        })
+    }
+}
+
+impl Default for CounterState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// When the levels of a [`CounterState`] restart at their
+/// [start value](CounterState::with_start).
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default)]
+pub enum RestartPolicy {
+    /// The levels restart at each step of their parent level, as in "1.1,
+    /// 1.2, 2.1". This is the default.
+    #[default]
+    Parent,
+    /// The levels continue across the steps of their parent level, as in
+    /// "1.1, 1.2, 2.3", as for figures numbered throughout a document.
+    Continue,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CounterState, RestartPolicy};
+    use crate::numeral_systems::Preset;
+    use crate::numeral_systems::bundle::DecodeError;
+
+    #[test]
+    fn test_step() {
+        let mut state = CounterState::new();
+        state.step(2);
+        assert_eq!(state.levels(), [0, 1]);
+        state.step(1);
+        assert_eq!(state.levels(), [1]);
+        state.step(3);
+        state.step(3);
+        assert_eq!(state.levels(), [1, 0, 2]);
+        state.step(0);
+        assert_eq!(state.levels(), [1, 0, 2]);
+        let preset: Preset = "x: {Roman}-{latin}".parse().unwrap();
+        assert_eq!(
+            CounterState::from_levels(vec![4, 2]).represent(&preset).as_deref(),
+            Ok("IV-b"),
+        );
+    }
+
+    #[test]
+    fn test_bytes() {
+        let state = CounterState::from_levels(vec![3, u64::MAX, 0]);
+        let bytes = state.to_bytes();
+        assert_eq!(bytes.len(), 4 + 1 + 2 * 4 + 3 * 8);
+        assert_eq!(CounterState::from_bytes(&bytes), Ok(state));
+        assert_eq!(
+            CounterState::from_bytes(&CounterState::new().to_bytes()),
+            Ok(CounterState::new()),
+        );
+        assert_eq!(CounterState::from_bytes(b"CX"), Err(DecodeError::Truncated));
+        assert_eq!(
+            CounterState::from_bytes(b"CXNS\x01"),
+            Err(DecodeError::InvalidHeader)
+        );
+        assert_eq!(
+            CounterState::from_bytes(b"CXCT\x03"),
+            Err(DecodeError::UnsupportedVersion(3)),
+        );
+        assert_eq!(
+            CounterState::from_bytes(b"CXCT\x01\x01\0\0\0\x07\0\0\0\0\0\0\0"),
+            Ok(CounterState::from_levels(vec![7])),
+        );
+        let mut kept = CounterState::new().with_restart(RestartPolicy::Continue);
+        kept.step(2);
+        kept.step(1);
+        let decoded = CounterState::from_bytes(&kept.to_bytes()).unwrap();
+        assert_eq!(decoded.with_restart(RestartPolicy::Continue), kept);
+        assert_eq!(
+            CounterState::from_bytes(&bytes[..bytes.len() - 1]),
+            Err(DecodeError::Truncated),
+        );
+        let mut trailing = bytes.clone();
+        trailing.push(0);
+        assert_eq!(CounterState::from_bytes(&trailing), Err(DecodeError::TrailingBytes));
+    }
+
+    #[test]
+    fn test_policies() {
+        let mut state = CounterState::new().with_start(5);
+        state.step(2);
+        assert_eq!(state.levels(), [4, 5]);
+        state.step(1);
+        state.step(2);
+        assert_eq!(state.levels(), [5, 5]);
+
+        let mut state = CounterState::new().with_restart(RestartPolicy::Continue);
+        state.step(1);
+        state.step(2);
+        state.step(3);
+        state.step(1);
+        assert_eq!(state.levels(), [2]);
+        state.step(2);
+        assert_eq!(state.levels(), [2, 2]);
+        state.step(3);
+        assert_eq!(state.levels(), [2, 2, 2]);
+        assert_eq!(CounterState::default(), CounterState::new());
+        assert_eq!(state.start(), 1);
+        assert_eq!(state.restart(), RestartPolicy::Continue);
+    }
+
+    #[test]
+    fn test_skipped() {
+        let mut state = CounterState::new().with_start(3).with_skipped([5, 3, 4, 3, 7]);
+        assert_eq!(state.skipped(), [3, 4, 5, 7]);
+        state.step(1);
+        assert_eq!(state.levels(), [6]);
+        state.step(2);
+        assert_eq!(state.levels(), [6, 6]);
+        state.step(1);
+        assert_eq!(state.levels(), [8]);
+
+        let mut state =
+            CounterState::from_levels(vec![u64::MAX - 1]).with_skipped([u64::MAX]);
+        state.step(1);
+        assert_eq!(state.levels(), [u64::MAX]);
+    }
+}
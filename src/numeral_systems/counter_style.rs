@@ -0,0 +1,183 @@
+//! Numeral systems with the descriptors of CSS counter styles, such as a
+//! negative sign, padding, and a range.
+
+use std::fmt::Write;
+
+use super::{Counter, NamedNumeralSystem, NumeralSystem};
+use crate::Error;
+
+/// A numeral system with the descriptors of a
+/// [CSS counter style](https://www.w3.org/TR/css-counter-styles-3/), so that
+/// styles imported from CSS represent numbers as browsers do.
+///
+/// The defaults of [`new`](Self::new) are those of CSS: the range is
+/// [`auto`](Self::auto_range), and numbers out of it are represented in
+/// decimal, such as "-2" in an alphabetic counter style.
+///
+/// A number is represented as follows:
+/// 1. If the number is out of the [range](Self::range), or the numeral
+///    system cannot represent its absolute value, the [fallback](Self::fallback)
+///    numeral system is used instead, if any.
+/// 2. The absolute value of the number is represented in the numeral system.
+/// 3. The representation is [padded](Self::pad) to the minimum width. The
+///    negative sign counts towards the width.
+/// 4. If the number is negative, the representation is wrapped in the
+///    [negative sign](Self::negative).
+/// 5. The [prefix](Self::prefix) and the [suffix](Self::suffix) are added.
+///
+/// ## Example
+///
+/// ```
+/// use codex::numeral_systems::{CounterStyle, NamedNumeralSystem};
+///
+/// let mut style = CounterStyle::new(NamedNumeralSystem::Arabic.system());
+/// style.pad = (3, "0");
+/// style.suffix = ". ";
+/// assert_eq!(style.represent(7).unwrap(), "007. ");
+/// assert_eq!(style.represent(-7).unwrap(), "-07. ");
+///
+/// let mut style = CounterStyle::new(NamedNumeralSystem::LowerLatin.system());
+/// assert_eq!(style.represent(2).unwrap(), "b");
+/// assert_eq!(style.represent(-2).unwrap(), "-2");
+/// assert_eq!(style.represent(0).unwrap(), "0");
+/// style.range = (i64::MIN, i64::MAX);
+/// style.negative = ("(", ")");
+/// assert_eq!(style.represent(-2).unwrap(), "(b)");
+/// ```
+#[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
+pub struct CounterStyle<'a> {
+    /// The numeral system in which absolute values are represented.
+    pub system: NumeralSystem<'a>,
+    /// The text before and after the representations of negative numbers.
+    /// Defaults to a hyphen-minus before them.
+    pub negative: (&'a str, &'a str),
+    /// The text before representations. Defaults to nothing.
+    pub prefix: &'a str,
+    /// The text after representations. Defaults to nothing.
+    pub suffix: &'a str,
+    /// The lowest and the highest number to represent in the numeral system,
+    /// inclusive. Defaults to the [`auto`](Self::auto_range) range of the
+    /// numeral system.
+    pub range: (i64, i64),
+    /// The minimum width of representations, in characters, and the text
+    /// written for each missing character. Defaults to no padding.
+    pub pad: (usize, &'a str),
+    /// The numeral system in which numbers out of the range are represented,
+    /// with the same descriptors. Defaults to
+    /// [Arabic numerals](NamedNumeralSystem::Arabic), as the `decimal` counter
+    /// style of CSS. If it is none, numbers out of the range cannot be
+    /// represented.
+    pub fallback: Option<NumeralSystem<'a>>,
+}
+
+impl<'a> CounterStyle<'a> {
+    /// Creates a counter style from a numeral system, with the default
+    /// descriptors.
+    pub const fn new(system: NumeralSystem<'a>) -> Self {
+        Self {
+            system,
+            negative: ("-", ""),
+            prefix: "",
+            suffix: "",
+            range: Self::auto_range(system),
+            pad: (0, ""),
+            fallback: Some(NamedNumeralSystem::Arabic.system()),
+        }
+    }
+
+    /// Returns the range of a numeral system when the `range` descriptor of
+    /// its CSS counter style is `auto`.
+    ///
+    /// This is all positive numbers for alphabetic and symbolic numeral
+    /// systems, which are [bijective](NumeralSystem::Bijective) and
+    /// [symbolic](NumeralSystem::Symbolic) here, all non-negative numbers for
+    /// [additive](NumeralSystem::Additive) numeral systems, and all numbers
+    /// otherwise.
+    pub const fn auto_range(system: NumeralSystem) -> (i64, i64) {
+        match system {
+            NumeralSystem::Bijective(_) | NumeralSystem::Symbolic(_) => (1, i64::MAX),
+            NumeralSystem::Additive(_) => (0, i64::MAX),
+            _ => (i64::MIN, i64::MAX),
+        }
+    }
+
+    /// Tries to represent a number with this counter style.
+    ///
+    /// Numbers that neither the numeral system nor the fallback can represent
+    /// are [out of range](Error::OutOfRange).
+    pub fn represent(self, number: i64) -> Result<String, Error> {
+        let magnitude = number.unsigned_abs();
+        let in_range = (self.range.0..=self.range.1).contains(&number);
+        let system = if in_range && self.system.check(magnitude).is_ok() {
+            self.system
+        } else {
+            self.fallback.ok_or(Error::OutOfRange)?
+        };
+        let represented = system.represent(magnitude)?;
+
+        let (before, after) = if number < 0 { self.negative } else { ("", "") };
+        let mut counter = Counter(0);
+        write!(counter, "{before}{after}{represented}").expect("counting cannot fail");
+        let (width, padding) = self.pad;
+
+        let mut out = String::from(self.prefix);
+        out.push_str(before);
+        for _ in counter.0..width {
+            out.push_str(padding);
+        }
+        write!(out, "{represented}").expect("writing to a string cannot fail");
+        out.push_str(after);
+        out.push_str(self.suffix);
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CounterStyle;
+    use crate::Error;
+    use crate::numeral_systems::{NamedNumeralSystem, NumberFormatter};
+
+    #[test]
+    fn test_counter_style() {
+        let arabic = NamedNumeralSystem::Arabic.system();
+        let roman = NamedNumeralSystem::UpperRoman.system();
+        let style = CounterStyle::new(roman);
+        assert_eq!(style.range, (0, i64::MAX));
+        assert_eq!(style.represent(4).unwrap(), roman.represent(4).unwrap().to_string());
+        assert_eq!(style.represent(-4).unwrap(), "-4");
+        assert_eq!(style.represent(i64::MIN).unwrap(), "-9223372036854775808");
+
+        let mut style = CounterStyle::new(roman);
+        style.range = (1, 3999);
+        style.prefix = "[";
+        style.suffix = "]";
+        style.pad = (4, " ");
+        assert_eq!(style.represent(9).unwrap(), "[  IX]");
+        assert_eq!(style.represent(4000).unwrap(), "[4000]");
+        assert_eq!(style.represent(-12).unwrap(), "[- 12]");
+        assert_eq!(style.represent(123_456).unwrap(), "[123456]");
+        assert_eq!(style.format_to_string(9).as_deref(), Ok("[  IX]"));
+
+        let mut style = CounterStyle::new(NamedNumeralSystem::LowerLatin.system());
+        assert_eq!(style.range, (1, i64::MAX));
+        style.negative = ("−", "");
+        assert_eq!(style.represent(0).unwrap(), "0");
+        assert_eq!(style.represent(-28).unwrap(), "−28");
+        style.range = (i64::MIN, i64::MAX);
+        assert_eq!(style.represent(-28).unwrap(), "−ab");
+        style.fallback = None;
+        assert_eq!(style.represent(0), Err(Error::OutOfRange));
+
+        let style = CounterStyle::new(arabic);
+        assert_eq!(style.range, (i64::MIN, i64::MAX));
+        assert_eq!(style.represent(-7).unwrap(), "-7");
+        #[cfg(feature = "symbols")]
+        {
+            let style = CounterStyle::new(NamedNumeralSystem::Symbols.system());
+            assert_eq!(style.represent(2).unwrap(), "†");
+            assert_eq!(style.represent(-2).unwrap(), "-2");
+        }
+    }
+}
@@ -0,0 +1,245 @@
+//! Text written before and after represented numbers, spaced and isolated
+//! according to the scripts involved.
+
+use super::{NumeralSystem, RepresentationError};
+
+/// Text written before and after a represented number, such as `§` in "§ 3",
+/// `No.` in "No. 3", or `第` and `条` in "第三条".
+///
+/// Rather than being concatenated as is, the prefix and the suffix are joined
+/// to the number as follows:
+/// - A no-break space (U+00A0) separates the prefix from the number if the
+///   prefix ends with a letter, a digit, a period, or one of `§`, `¶`, and
+///   `№`, and the suffix from the number if the suffix starts with a letter
+///   or a digit. No space is inserted next to CJK characters, nor next to
+///   whitespace.
+/// - If the number is written in a right-to-left script and the affixes are
+///   not, or the other way around, the number is wrapped in a first strong
+///   isolate (U+2068) and a pop directional isolate (U+2069), so that
+///   bidirectional text does not reorder it with the affixes.
+///
+/// ## Example
+///
+/// ```
+/// use codex::numeral_systems::{Decoration, NamedNumeralSystem};
+///
+/// let arabic = NamedNumeralSystem::Arabic.system();
+/// assert_eq!(Decoration::SECTION.represent(arabic, 3).unwrap(), "§\u{A0}3");
+///
+/// # #[cfg(feature = "cjk")] {
+/// let chinese = NamedNumeralSystem::LowerSimplifiedChinese.system();
+/// let article = Decoration::from_pattern("第{}条").unwrap();
+/// assert_eq!(article.represent(chinese, 3).unwrap(), "第三条");
+/// # }
+///
+/// # #[cfg(feature = "rtl")] {
+/// let hebrew = NamedNumeralSystem::Hebrew.system();
+/// let chapter = Decoration::from_pattern("Chapter {}").unwrap();
+/// assert_eq!(chapter.represent(hebrew, 3).unwrap(), "Chapter \u{2068}ג\u{2069}");
+/// # }
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub struct Decoration<'a> {
+    /// The text before the number.
+    pub prefix: &'a str,
+    /// The text after the number.
+    pub suffix: &'a str,
+}
+
+impl<'a> Decoration<'a> {
+    /// The section sign, as in "§ 3".
+    pub const SECTION: Self = Self::new("§", "");
+    /// The paragraph sign, as in "¶ 3".
+    pub const PARAGRAPH: Self = Self::new("¶", "");
+    /// The numero sign, as in "№ 3".
+    pub const NUMERO: Self = Self::new("№", "");
+    /// The abbreviation of "number", as in "No. 3".
+    pub const NUMBER: Self = Self::new("No.", "");
+
+    /// Creates a decoration from its prefix and suffix.
+    pub const fn new(prefix: &'a str, suffix: &'a str) -> Self {
+        Self { prefix, suffix }
+    }
+
+    /// Creates a decoration from a pattern in which `{}` stands for the
+    /// number, such as `第{}条`.
+    ///
+    /// Returns `None` if the pattern does not contain `{}` exactly once.
+    pub fn from_pattern(pattern: &'a str) -> Option<Self> {
+        let (prefix, suffix) = pattern.split_once("{}")?;
+        (!suffix.contains("{}")).then_some(Self::new(prefix, suffix))
+    }
+
+    /// Tries to represent a number in a numeral system, decorated with the
+    /// prefix and the suffix.
+    pub fn represent(
+        self,
+        system: NumeralSystem,
+        number: u64,
+    ) -> Result<String, RepresentationError> {
+        let number = system.represent(number)?.to_string();
+        let isolate = is_rtl(&number) != (is_rtl(self.prefix) || is_rtl(self.suffix))
+            && !(self.prefix.is_empty() && self.suffix.is_empty());
+        let first = number.chars().next();
+        let last = number.chars().next_back();
+
+        let mut out = String::from(self.prefix);
+        if let (Some(before), Some(first)) = (self.prefix.chars().next_back(), first)
+            && !is_cjk(first)
+            && (is_word(before) || matches!(before, '.' | '§' | '¶' | '№'))
+        {
+            out.push('\u{A0}');
+        }
+        if isolate {
+            out.push('\u{2068}');
+        }
+        out.push_str(&number);
+        if isolate {
+            out.push('\u{2069}');
+        }
+        if let (Some(last), Some(after)) = (last, self.suffix.chars().next())
+            && !is_cjk(last)
+            && is_word(after)
+        {
+            out.push('\u{A0}');
+        }
+        out.push_str(self.suffix);
+        Ok(out)
+    }
+}
+
+/// Prepares a numeral to be embedded in Arabic-script text, so that its
+/// digits are ordered as Arabic numbers wherever it is inserted.
+///
+/// Arabic-Indic digits (U+0660 to U+0669) have the bidirectional class of
+/// Arabic numbers (AN). Western and extended Arabic-Indic digits (U+06F0 to
+/// U+06F9), such as those of [Persian](super::NamedNumeralSystem::Persian)
+/// numerals, are European numbers (EN) instead, which only become Arabic
+/// numbers after Arabic letters. At the start of a paragraph or after
+/// left-to-right text, they are ordered differently from the surrounding
+/// Arabic text, notably with separators such as `-` or `/`.
+///
+/// If the numeral contains such digits, it is thus prefixed with an Arabic
+/// letter mark (U+061C), which makes them Arabic numbers. With
+/// `letter_mark`, the numeral is also prefixed and suffixed with an Arabic
+/// letter mark in any case, so that neutral characters around it, such as
+/// brackets or periods, are ordered right to left.
+///
+/// ## Example
+///
+/// ```
+/// use codex::numeral_systems::{NamedNumeralSystem, arabic_context};
+///
+/// let persian = NamedNumeralSystem::Persian.system();
+/// let numeral = persian.represent(12).unwrap().to_string();
+/// assert_eq!(arabic_context(&numeral, false), "\u{61C}۱۲");
+///
+/// let arabic = NamedNumeralSystem::EasternArabic.system();
+/// let numeral = arabic.represent(12).unwrap().to_string();
+/// assert_eq!(arabic_context(&numeral, false), "١٢");
+/// assert_eq!(arabic_context(&numeral, true), "\u{61C}١٢\u{61C}");
+/// ```
+#[cfg(feature = "rtl")]
+pub fn arabic_context(numeral: &str, letter_mark: bool) -> String {
+    let european = numeral
+        .chars()
+        .any(|c| matches!(c, '0'..='9' | '\u{6F0}'..='\u{6F9}'));
+    let mut out = String::new();
+    if european || letter_mark {
+        out.push('\u{61C}');
+    }
+    out.push_str(numeral);
+    if letter_mark {
+        out.push('\u{61C}');
+    }
+    out
+}
+
+/// Whether a character is a letter or a digit that is not CJK, and is thus
+/// separated from numbers by a space.
+fn is_word(c: char) -> bool {
+    c.is_alphanumeric() && !is_cjk(c)
+}
+
+/// Whether a character is a CJK ideograph, kana, hangul, or fullwidth form,
+/// next to which no space is inserted.
+fn is_cjk(c: char) -> bool {
+    matches!(
+        c,
+        '\u{1100}'..='\u{11FF}'
+            | '\u{2E80}'..='\u{9FFF}'
+            | '\u{A960}'..='\u{A97F}'
+            | '\u{AC00}'..='\u{D7FF}'
+            | '\u{F900}'..='\u{FAFF}'
+            | '\u{FF00}'..='\u{FFEF}'
+            | '\u{20000}'..='\u{3FFFF}'
+    )
+}
+
+/// Whether a text contains characters of a right-to-left script.
+pub(super) fn is_rtl(s: &str) -> bool {
+    s.chars().any(|c| {
+        matches!(
+            c,
+            '\u{590}'..='\u{8FF}' | '\u{FB1D}'..='\u{FDFF}' | '\u{FE70}'..='\u{FEFF}'
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Decoration;
+    #[cfg(feature = "rtl")]
+    use super::arabic_context;
+    use crate::numeral_systems::{NamedNumeralSystem, RepresentationError};
+
+    #[test]
+    fn test_decoration() {
+        let arabic = NamedNumeralSystem::Arabic.system();
+        let roman = NamedNumeralSystem::UpperRoman.system();
+        assert_eq!(Decoration::NUMBER.represent(arabic, 12).unwrap(), "No.\u{A0}12");
+        assert_eq!(Decoration::NUMERO.represent(roman, 4).unwrap(), "№\u{A0}IV");
+        let decorate = |pattern, number| {
+            Decoration::from_pattern(pattern).unwrap().represent(roman, number)
+        };
+        assert_eq!(decorate("({})", 4).unwrap(), "(IV)");
+        assert_eq!(decorate("{}.", 4).unwrap(), "IV.");
+        assert_eq!(decorate("Part {}", 4).unwrap(), "Part IV");
+        assert_eq!(decorate("{}bis", 4).unwrap(), "IV\u{A0}bis");
+        assert_eq!(decorate("{}", 4).unwrap(), "IV");
+        assert_eq!(
+            Decoration::new("a", "")
+                .represent(NamedNumeralSystem::LowerLatin.system(), 0),
+            Err(RepresentationError::Zero),
+        );
+        assert_eq!(Decoration::from_pattern("§"), None);
+        assert_eq!(Decoration::from_pattern("{}{}"), None);
+        #[cfg(feature = "rtl")]
+        {
+            let hebrew = NamedNumeralSystem::Hebrew.system();
+            assert_eq!(decorate("פרק {}", 2).unwrap(), "פרק \u{2068}II\u{2069}");
+            assert_eq!(
+                Decoration::from_pattern("פרק {}")
+                    .unwrap()
+                    .represent(hebrew, 2)
+                    .unwrap(),
+                "פרק ב",
+            );
+            assert_eq!(
+                Decoration::from_pattern("{}").unwrap().represent(hebrew, 2).unwrap(),
+                "ב",
+            );
+        }
+    }
+
+    #[cfg(feature = "rtl")]
+    #[test]
+    fn test_arabic_context() {
+        assert_eq!(arabic_context("٣.٢", false), "٣.٢");
+        assert_eq!(arabic_context("3.2", false), "\u{61C}3.2");
+        assert_eq!(arabic_context("۳-۲", true), "\u{61C}۳-۲\u{61C}");
+        assert_eq!(arabic_context("ب", true), "\u{61C}ب\u{61C}");
+        assert_eq!(arabic_context("ب", false), "ب");
+    }
+}
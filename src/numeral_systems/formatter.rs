@@ -0,0 +1,183 @@
+//! A trait for anything that represents numbers, so that code can be generic
+//! over numeral systems and the formatters built on top of them.
+
+use std::fmt::{self, Display, Write};
+
+use super::rbnf::RuleSet;
+use super::{CounterStyle, Dual, NamedNumeralSystem, NumeralSystem, Preset, Tabular};
+use crate::Error;
+
+/// Something that represents numbers, such as a [`NumeralSystem`], a
+/// [`Preset`], or a spellout [`RuleSet`].
+///
+/// Downstream code can be generic over this trait instead of matching on the
+/// concrete types of this crate, and implement it for its own formatters.
+///
+/// ## Example
+///
+/// ```
+/// use codex::numeral_systems::{NamedNumeralSystem, NumberFormatter, Preset};
+///
+/// fn label(formatter: &dyn NumberFormatter, number: u64) -> String {
+///     formatter.format_to_string(number).unwrap_or_else(|_| number.to_string())
+/// }
+///
+/// assert_eq!(label(&NamedNumeralSystem::UpperRoman, 4), "IV");
+/// assert_eq!(label(&Preset::from_name("appendix").unwrap(), 2), "Appendix B");
+/// assert_eq!(label(&NamedNumeralSystem::LowerLatin.system(), 0), "0");
+/// ```
+pub trait NumberFormatter {
+    /// Tries to write the representation of a number.
+    ///
+    /// If the number cannot be represented, nothing is written.
+    fn format(&self, number: u64, out: &mut dyn Write) -> Result<(), Error>;
+
+    /// Returns the name of this formatter, if it has one, such as the name of
+    /// a named numeral system or of a preset.
+    fn formatter_name(&self) -> Option<&str> {
+        None
+    }
+
+    /// Returns whether a number can be represented by this formatter.
+    ///
+    /// By default, this formats the number and discards the result.
+    fn can_format(&self, number: u64) -> bool {
+        self.format(number, &mut Discard).is_ok()
+    }
+
+    /// Tries to represent a number as a string.
+    fn format_to_string(&self, number: u64) -> Result<String, Error> {
+        let mut out = String::new();
+        self.format(number, &mut out)?;
+        Ok(out)
+    }
+}
+
+impl<T: NumberFormatter + ?Sized> NumberFormatter for &T {
+    fn format(&self, number: u64, out: &mut dyn Write) -> Result<(), Error> {
+        (**self).format(number, out)
+    }
+
+    fn formatter_name(&self) -> Option<&str> {
+        (**self).formatter_name()
+    }
+
+    fn can_format(&self, number: u64) -> bool {
+        (**self).can_format(number)
+    }
+}
+
+impl NumberFormatter for NumeralSystem<'_> {
+    fn format(&self, number: u64, out: &mut dyn Write) -> Result<(), Error> {
+        write_display(self.represent(number)?, out)
+    }
+
+    fn can_format(&self, number: u64) -> bool {
+        self.check(number).is_ok()
+    }
+}
+
+impl NumberFormatter for NamedNumeralSystem {
+    fn format(&self, number: u64, out: &mut dyn Write) -> Result<(), Error> {
+        self.system().format(number, out)
+    }
+
+    fn formatter_name(&self) -> Option<&str> {
+        Some(NamedNumeralSystem::name(*self))
+    }
+
+    fn can_format(&self, number: u64) -> bool {
+        self.system().can_format(number)
+    }
+}
+
+impl NumberFormatter for Preset {
+    fn format(&self, number: u64, out: &mut dyn Write) -> Result<(), Error> {
+        Ok(out.write_str(&self.represent(&[number])?)?)
+    }
+
+    fn formatter_name(&self) -> Option<&str> {
+        Some(Preset::name(self))
+    }
+}
+
+impl NumberFormatter for RuleSet<'_> {
+    fn format(&self, number: u64, out: &mut dyn Write) -> Result<(), Error> {
+        Ok(out.write_str(&self.represent(number)?)?)
+    }
+
+    fn formatter_name(&self) -> Option<&str> {
+        Some(RuleSet::name(*self))
+    }
+}
+
+impl NumberFormatter for Dual<'_> {
+    fn format(&self, number: u64, out: &mut dyn Write) -> Result<(), Error> {
+        write_display(self.represent(number)?, out)
+    }
+}
+
+impl NumberFormatter for Tabular<'_> {
+    fn format(&self, number: u64, out: &mut dyn Write) -> Result<(), Error> {
+        write_display(self.represent(number)?, out)
+    }
+}
+
+impl NumberFormatter for CounterStyle<'_> {
+    fn format(&self, number: u64, out: &mut dyn Write) -> Result<(), Error> {
+        let number = i64::try_from(number).map_err(|_| Error::OutOfRange)?;
+        Ok(out.write_str(&self.represent(number)?)?)
+    }
+}
+
+/// Writes a representation to a writer.
+fn write_display(represented: impl Display, out: &mut dyn Write) -> Result<(), Error> {
+    Ok(write!(out, "{represented}")?)
+}
+
+/// A writer that discards what is written to it.
+struct Discard;
+
+impl Write for Discard {
+    fn write_str(&mut self, _: &str) -> fmt::Result {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::NumberFormatter;
+    use crate::Error;
+    use crate::numeral_systems::rbnf::RuleSets;
+    use crate::numeral_systems::{Dual, NamedNumeralSystem, Preset, Tabular};
+
+    /// Formats the numbers from one to three, generically.
+    fn first_three(formatter: impl NumberFormatter) -> Vec<String> {
+        (1..=3).map(|n| formatter.format_to_string(n).unwrap()).collect()
+    }
+
+    #[test]
+    fn test_formatters() {
+        let roman = NamedNumeralSystem::LowerRoman;
+        assert_eq!(first_three(roman), ["i", "ii", "iii"]);
+        assert_eq!(first_three(roman.system()), ["i", "ii", "iii"]);
+        assert_eq!(roman.formatter_name(), Some("roman"));
+        assert_eq!(roman.system().formatter_name(), None);
+        assert!(!NamedNumeralSystem::LowerLatin.can_format(0));
+
+        let arabic = NamedNumeralSystem::Arabic.system();
+        assert_eq!(first_three(Dual::new(arabic, roman.system()))[1], "2 (ii)");
+        assert_eq!(first_three(Tabular::new(arabic, 2))[2], "\u{2007}3");
+
+        let preset = Preset::from_name("frontmatter").unwrap();
+        assert_eq!(first_three(&preset), ["i", "ii", "iii"]);
+        assert_eq!(preset.formatter_name(), Some("frontmatter"));
+
+        let sets = RuleSets::parse("%main:\n1: one;\n").unwrap();
+        let set = sets.get("main").unwrap();
+        assert_eq!(set.format_to_string(1).as_deref(), Ok("one"));
+        assert_eq!(set.format_to_string(0), Err(Error::OutOfRange));
+        assert!(set.can_format(5));
+        assert!(!set.can_format(0));
+    }
+}
@@ -0,0 +1,207 @@
+//! Golden samples of numeral systems, for regression tests.
+//!
+//! Crates defining custom numeral systems, or registering them, can
+//! [`generate`] the representations of representative numbers once, store
+//! them in a file, and [`compare`] them with the current output in their
+//! tests, so that any change to their numbering output is noticed.
+//!
+//! Samples are stored as text, one line per numeral system and number, as
+//! the name of the numeral system, the number, and its representation,
+//! separated by tabs. Numbers that cannot be represented are written as
+//! `!zero` or `!too-large`.
+//!
+//! ## Example
+//!
+//! ```
+//! use codex::numeral_systems::NumeralSystem;
+//! use codex::numeral_systems::golden::{compare, generate};
+//!
+//! let binary = NumeralSystem::Positional(&["0", "1"]);
+//! let golden = generate([("binary", binary)]);
+//! assert!(golden.starts_with("binary\t0\t0\nbinary\t1\t1\nbinary\t2\t10\n"));
+//! assert_eq!(compare(&golden, &generate([("binary", binary)])), Ok(()));
+//! ```
+
+use std::fmt::Write;
+use std::path::Path;
+
+use super::{NumeralSystem, RepresentationError};
+
+/// The numbers whose representations are generated: the edges of the ranges
+/// of the named numeral systems, and where their representations change
+/// shape.
+pub const SAMPLES: &[u64] = &[
+    0,
+    1,
+    2,
+    3,
+    4,
+    5,
+    9,
+    10,
+    11,
+    19,
+    20,
+    26,
+    27,
+    38,
+    40,
+    49,
+    50,
+    99,
+    100,
+    499,
+    999,
+    1000,
+    1999,
+    3999,
+    4000,
+    9999,
+    10_000,
+    12_345,
+    100_000,
+    1_000_000,
+    100_000_001,
+    u64::MAX,
+];
+
+/// Generates the golden samples of numeral systems and their names, with the
+/// representations of the [`SAMPLES`].
+///
+/// For example, the [registered](super::registered) numeral systems can be
+/// sampled with `generate(numeral_systems::registered())`.
+pub fn generate<'a>(
+    systems: impl IntoIterator<Item = (&'a str, NumeralSystem<'a>)>,
+) -> String {
+    let mut out = String::new();
+    for (name, system) in systems {
+        for &number in SAMPLES {
+            let represented = match system.represent(number) {
+                Ok(represented) => represented.to_string(),
+                Err(RepresentationError::Zero) => "!zero".into(),
+                Err(RepresentationError::TooLarge) => "!too-large".into(),
+            };
+            writeln!(out, "{name}\t{number}\t{represented}")
+                .expect("writing to a string cannot fail");
+        }
+    }
+    out
+}
+
+/// Compares golden samples with the current ones, line by line.
+///
+/// Returns the first line that differs, if any.
+pub fn compare(expected: &str, actual: &str) -> Result<(), Mismatch> {
+    let mut expected_lines = expected.lines();
+    let mut actual_lines = actual.lines();
+    for line in 1.. {
+        match (expected_lines.next(), actual_lines.next()) {
+            (None, None) => return Ok(()),
+            (expected, actual) if expected == actual => {}
+            (expected, actual) => {
+                return Err(Mismatch {
+                    line,
+                    expected: expected.map(Into::into),
+                    actual: actual.map(Into::into),
+                });
+            }
+        }
+    }
+    unreachable!()
+}
+
+/// Compares the golden samples of a file with the current ones, and panics
+/// with the first line that differs, if any.
+///
+/// If the file does not exist, or the `UPDATE_GOLDEN` environment variable
+/// is set, the file is written with the current samples instead, so that
+/// intended changes can be accepted by running the tests again with it.
+#[track_caller]
+pub fn assert_golden(path: impl AsRef<Path>, actual: &str) {
+    check_golden(path.as_ref(), actual, std::env::var_os("UPDATE_GOLDEN").is_some());
+}
+
+/// Compares the golden samples of a file with the current ones, as described
+/// in [`assert_golden`], updating the file if `update` is set.
+#[track_caller]
+fn check_golden(path: &Path, actual: &str, update: bool) {
+    if update || !path.exists() {
+        std::fs::write(path, actual).expect("failed to write the golden file");
+        return;
+    }
+    let expected = std::fs::read_to_string(path).expect("failed to read the golden file");
+    if let Err(mismatch) = compare(&expected, actual) {
+        panic!(
+            "{} differs from the golden samples at line {}:\n  expected: {}\n  actual:   {}\n\
+             run again with UPDATE_GOLDEN=1 to accept the changes",
+            path.display(),
+            mismatch.line,
+            mismatch.expected.as_deref().unwrap_or("(end of file)"),
+            mismatch.actual.as_deref().unwrap_or("(end of file)"),
+        );
+    }
+}
+
+/// A difference between golden samples and the current ones, as returned by
+/// [`compare`].
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub struct Mismatch {
+    /// The number of the line that differs, counting from one.
+    pub line: usize,
+    /// The expected line, or `None` if there are more lines than expected.
+    pub expected: Option<String>,
+    /// The current line, or `None` if there are fewer lines than expected.
+    pub actual: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Mismatch, SAMPLES, check_golden, compare, generate};
+    use crate::numeral_systems::{NamedNumeralSystem, NumeralSystem};
+
+    #[test]
+    fn test_generate() {
+        let systems =
+            NamedNumeralSystem::iter().map(|named| (named.name(), named.system()));
+        let golden = generate(systems);
+        assert_eq!(
+            golden.lines().count(),
+            NamedNumeralSystem::iter().count() * SAMPLES.len()
+        );
+        assert!(golden.contains("roman\t4\tiv\n"));
+        assert!(golden.contains("latin\t0\t!zero\n"));
+        assert!(SAMPLES.is_sorted());
+    }
+
+    #[test]
+    fn test_compare() {
+        let old = generate([("ab", NumeralSystem::Bijective(&["a", "b"]))]);
+        let new = generate([("ab", NumeralSystem::Bijective(&["a", "c"]))]);
+        assert_eq!(compare(&old, &old), Ok(()));
+        assert_eq!(
+            compare(&old, &new),
+            Err(Mismatch {
+                line: 3,
+                expected: Some("ab\t2\tb".into()),
+                actual: Some("ab\t2\tc".into()),
+            }),
+        );
+        assert_eq!(
+            compare("x\n", ""),
+            Err(Mismatch { line: 1, expected: Some("x".into()), actual: None }),
+        );
+
+        // The path is unique to this process, so that concurrent test runs do
+        // not share it, and the environment is not read, so that the test
+        // passes even when `UPDATE_GOLDEN` is set.
+        let path = std::env::temp_dir()
+            .join(format!("codex-golden-test-{}.txt", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        check_golden(&path, &old, false);
+        check_golden(&path, &old, false);
+        assert!(std::panic::catch_unwind(|| check_golden(&path, &new, false)).is_err());
+        check_golden(&path, &new, true);
+        check_golden(&path, &new, false);
+        std::fs::remove_file(&path).unwrap();
+    }
+}
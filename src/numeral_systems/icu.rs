@@ -0,0 +1,254 @@
+//! Interoperability with [ICU4X](https://github.com/unicode-org/icu4x).
+//!
+//! Named numeral systems that correspond to a
+//! [CLDR numbering system](https://github.com/unicode-org/cldr/blob/main/common/bcp47/number.xml)
+//! can be converted to and from ICU4X's [`NumberingSystem`] preference, and
+//! [`GroupedFormatter`] combines the locale-dependent grouping of ICU4X's
+//! [`DecimalFormatter`] with the digits of a numeral system.
+
+use std::fmt::{self, Display, Formatter, Write};
+
+use icu_decimal::input::Decimal;
+use icu_decimal::options::DecimalFormatterOptions;
+use icu_decimal::preferences::NumberingSystem;
+use icu_decimal::{DecimalFormatter, DecimalFormatterPreferences};
+use icu_locale_core::extensions::unicode::{Value, value};
+use icu_provider::DataError;
+
+use super::{NamedNumeralSystem, NumeralSystem, RepresentationError};
+
+impl NamedNumeralSystem {
+    /// Returns the named numeral system corresponding to a CLDR numbering
+    /// system identifier, such as `"roman"` or `"deva"`, if any.
+    ///
+    /// Note that CLDR identifiers differ from the [names](Self::name) of named
+    /// numeral systems: `"roman"` is the identifier of
+    /// [upper Roman numerals](Self::UpperRoman).
+    pub fn from_cldr(id: &str) -> Option<Self> {
+        Self::iter().find(|system| system.cldr() == Some(id))
+    }
+
+    /// Returns the identifier of the CLDR numbering system corresponding to
+    /// this named numeral system, if any.
+    pub const fn cldr(self) -> Option<&'static str> {
+        match self {
+            Self::Arabic => Some("latn"),
+            Self::LowerRoman => Some("romanlow"),
+            Self::UpperRoman => Some("roman"),
+            #[cfg(feature = "historic")]
+            Self::LowerGreek => Some("greklow"),
+            #[cfg(feature = "historic")]
+            Self::UpperGreek => Some("grek"),
+            #[cfg(feature = "historic")]
+            Self::LowerArmenian => Some("armnlow"),
+            #[cfg(feature = "historic")]
+            Self::UpperArmenian => Some("armn"),
+            #[cfg(feature = "rtl")]
+            Self::Hebrew => Some("hebr"),
+            #[cfg(feature = "rtl")]
+            Self::EasternArabic => Some("arab"),
+            #[cfg(feature = "rtl")]
+            Self::Persian => Some("arabext"),
+            #[cfg(feature = "cjk")]
+            Self::LowerSimplifiedChinese => Some("hans"),
+            #[cfg(feature = "cjk")]
+            Self::UpperSimplifiedChinese => Some("hansfin"),
+            #[cfg(feature = "cjk")]
+            Self::LowerTraditionalChinese => Some("hant"),
+            #[cfg(feature = "cjk")]
+            Self::UpperTraditionalChinese => Some("hantfin"),
+            #[cfg(feature = "indic")]
+            Self::Devanagari => Some("deva"),
+            #[cfg(feature = "indic")]
+            Self::Tibetan => Some("tibt"),
+            #[cfg(feature = "indic")]
+            Self::Bengali => Some("beng"),
+            _ => None,
+        }
+    }
+
+    /// Returns the named numeral system corresponding to an ICU4X numbering
+    /// system preference, if any.
+    pub fn from_numbering_system(numbering_system: &NumberingSystem) -> Option<Self> {
+        Self::from_cldr(numbering_system.as_str())
+    }
+
+    /// Returns the ICU4X numbering system preference corresponding to this
+    /// named numeral system, if any.
+    pub fn numbering_system(self) -> Option<NumberingSystem> {
+        let id = Value::try_from_str(self.cldr()?).ok()?;
+        NumberingSystem::try_from(id).ok()
+    }
+}
+
+/// Formats numbers with the grouping of an ICU4X [`DecimalFormatter`] and the
+/// digits of a numeral system.
+///
+/// Decimal [positional](NumeralSystem::Positional) numeral systems, which have
+/// ten digits, are grouped according to the locale, such as `1,234,567` in
+/// English or `12,34,567` in Hindi. Other numeral systems, such as Roman
+/// numerals, have no notion of grouping, so numbers are represented in them as
+/// with [`NumeralSystem::represent`].
+///
+/// ## Example
+///
+/// ```
+/// use codex::numeral_systems::NamedNumeralSystem;
+/// use codex::numeral_systems::icu::GroupedFormatter;
+/// use icu_locale_core::locale;
+///
+/// let formatter = GroupedFormatter::try_new(
+///     locale!("en").into(),
+///     Default::default(),
+///     NamedNumeralSystem::Devanagari.system(),
+/// )
+/// .unwrap();
+/// assert_eq!(formatter.format(1234567).unwrap().to_string(), "१,२३४,५६७");
+/// ```
+#[derive(Debug)]
+pub struct GroupedFormatter<'a> {
+    formatter: DecimalFormatter,
+    system: NumeralSystem<'a>,
+}
+
+impl<'a> GroupedFormatter<'a> {
+    /// Creates a formatter for a numeral system, with the grouping of the
+    /// given locale preferences.
+    ///
+    /// The numbering system of the preferences is ignored: the digits are
+    /// always those of `system`.
+    pub fn try_new(
+        mut prefs: DecimalFormatterPreferences,
+        options: DecimalFormatterOptions,
+        system: NumeralSystem<'a>,
+    ) -> Result<Self, DataError> {
+        prefs.numbering_system = NumberingSystem::try_from(value!("latn")).ok();
+        let formatter = DecimalFormatter::try_new(prefs, options)?;
+        Ok(Self { formatter, system })
+    }
+
+    /// Returns the numeral system of this formatter.
+    pub fn system(&self) -> NumeralSystem<'a> {
+        self.system
+    }
+
+    /// Tries to format a number.
+    ///
+    /// If `Ok(r)` is returned, `r` is a value of a type that implements
+    /// [`Display`] by printing the number as represented in the numeral system
+    /// of this formatter, grouped if the system is decimal.
+    pub fn format(&self, number: u64) -> Result<impl Display + '_, RepresentationError> {
+        match self.system {
+            NumeralSystem::Positional(digits) if digits.len() == 10 => {
+                Ok(Formatted::Grouped { formatter: self, digits, number })
+            }
+            system => {
+                system.check(number)?;
+                Ok(Formatted::Ungrouped { system, number })
+            }
+        }
+    }
+}
+
+/// A number formatted by a [`GroupedFormatter`].
+enum Formatted<'f, 'a> {
+    Grouped {
+        formatter: &'f GroupedFormatter<'a>,
+        digits: &'a [&'a str],
+        number: u64,
+    },
+    /// Invariant: The system must be able to represent the number.
+    Ungrouped {
+        system: NumeralSystem<'a>,
+        number: u64,
+    },
+}
+
+impl Display for Formatted<'_, '_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::Grouped { formatter, digits, number } => {
+                let decimal = Decimal::from(number);
+                let grouped = formatter.formatter.format(&decimal);
+                write!(DigitReplacer { digits, inner: f }, "{grouped}")
+            }
+            Self::Ungrouped { system, number } => system.write_unchecked(number, f),
+        }
+    }
+}
+
+/// Replaces ASCII digits with those of a decimal numeral system.
+struct DigitReplacer<'f, 'a, W> {
+    digits: &'a [&'a str],
+    inner: &'f mut W,
+}
+
+impl<W: Write> Write for DigitReplacer<'_, '_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for part in s.split_inclusive(|c: char| c.is_ascii_digit()) {
+            match part.as_bytes().last() {
+                Some(&d @ b'0'..=b'9') => {
+                    self.inner.write_str(&part[..part.len() - 1])?;
+                    self.inner.write_str(self.digits[usize::from(d - b'0')])?;
+                }
+                _ => self.inner.write_str(part)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use icu_locale_core::locale;
+
+    use super::GroupedFormatter;
+    use crate::numeral_systems::{NamedNumeralSystem, RepresentationError};
+
+    #[test]
+    fn test_cldr() {
+        for named in NamedNumeralSystem::iter() {
+            if let Some(id) = named.cldr() {
+                assert_eq!(NamedNumeralSystem::from_cldr(id), Some(named));
+                let numbering_system = named.numbering_system().unwrap();
+                assert_eq!(
+                    NamedNumeralSystem::from_numbering_system(&numbering_system),
+                    Some(named),
+                );
+            }
+        }
+        assert_eq!(
+            NamedNumeralSystem::from_cldr("roman"),
+            Some(NamedNumeralSystem::UpperRoman)
+        );
+        assert_eq!(NamedNumeralSystem::from_cldr("thai"), None);
+        assert_eq!(NamedNumeralSystem::LowerLatin.cldr(), None);
+    }
+
+    #[test]
+    fn test_grouped_formatter() {
+        let format = |locale: icu_locale_core::Locale, named: NamedNumeralSystem, n| {
+            GroupedFormatter::try_new(locale.into(), Default::default(), named.system())
+                .unwrap()
+                .format(n)
+                .map(|r| r.to_string())
+        };
+        let arabic = NamedNumeralSystem::Arabic;
+        assert_eq!(format(locale!("en"), arabic, 1234567).unwrap(), "1,234,567");
+        assert_eq!(format(locale!("de"), arabic, 1234567).unwrap(), "1.234.567");
+        assert_eq!(format(locale!("en-u-nu-arab"), arabic, 1234).unwrap(), "1,234");
+        assert_eq!(format(locale!("en"), arabic, 0).unwrap(), "0");
+
+        #[cfg(feature = "indic")]
+        {
+            let devanagari = NamedNumeralSystem::Devanagari;
+            assert_eq!(format(locale!("hi"), devanagari, 1234567).unwrap(), "१२,३४,५६७");
+        }
+
+        let roman = NamedNumeralSystem::UpperRoman;
+        assert_eq!(format(locale!("en"), roman, 1234).unwrap(), "MCCXXXIV");
+
+        let latin = NamedNumeralSystem::LowerLatin;
+        assert_eq!(format(locale!("en"), latin, 0), Err(RepresentationError::Zero));
+    }
+}
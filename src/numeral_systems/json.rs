@@ -0,0 +1,120 @@
+//! Export of the named numeral systems as JSON.
+
+use std::fmt::Write;
+
+use super::{NamedNumeralSystem, NumeralSystem};
+
+/// The numbers whose representations are exported as samples.
+const SAMPLES: std::ops::RangeInclusive<u64> = 1..=30;
+
+/// Exports the named numeral systems as a JSON array.
+///
+/// Each named numeral system is described by an object with the following
+/// fields:
+/// - `name`: the [name](NamedNumeralSystem::name) of the system.
+/// - `shorthand`: its [shorthand](NamedNumeralSystem::shorthand), or `null`.
+/// - `kind`: the kind of its [underlying system](NamedNumeralSystem::system),
+///   such as `"positional"` or `"additive"`.
+/// - `zero`: the representation of zero, or `null` if zero cannot be
+///   represented.
+/// - `samples`: the representations of the numbers from 1 to 30, with `null`
+///   for those that cannot be represented.
+///
+/// This allows documentation and user interfaces listing the numeral systems
+/// to be generated from this crate.
+pub fn export_json() -> String {
+    let mut out = String::from("[");
+    for (i, named) in NamedNumeralSystem::iter().enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        let system = named.system();
+        out.push_str("\n  {\"name\": ");
+        write_string(&mut out, named.name());
+        out.push_str(", \"shorthand\": ");
+        write_optional(&mut out, named.shorthand());
+        out.push_str(", \"kind\": ");
+        write_string(&mut out, kind(system));
+        out.push_str(", \"zero\": ");
+        write_sample(&mut out, system, 0);
+        out.push_str(", \"samples\": [");
+        for n in SAMPLES {
+            if n > *SAMPLES.start() {
+                out.push_str(", ");
+            }
+            write_sample(&mut out, system, n);
+        }
+        out.push_str("]}");
+    }
+    out.push_str("\n]\n");
+    out
+}
+
+/// Returns the name of the kind of a numeral system.
+fn kind(system: NumeralSystem) -> &'static str {
+    match system {
+        NumeralSystem::Positional(_) => "positional",
+        NumeralSystem::Bijective(_) => "bijective",
+        NumeralSystem::Additive(_) => "additive",
+        NumeralSystem::Symbolic(_) => "symbolic",
+        NumeralSystem::Fixed(_) => "fixed",
+        NumeralSystem::ZerolessFixed(_) => "zeroless-fixed",
+        #[cfg(feature = "cjk")]
+        NumeralSystem::Chinese(_, _) => "chinese",
+    }
+}
+
+/// Writes the representation of a number as a JSON string, or `null`.
+fn write_sample(out: &mut String, system: NumeralSystem, n: u64) {
+    match system.represent(n) {
+        Ok(r) => write_string(out, &r.to_string()),
+        Err(_) => out.push_str("null"),
+    }
+}
+
+/// Writes an optional string as a JSON string, or `null`.
+fn write_optional(out: &mut String, s: Option<&str>) {
+    match s {
+        Some(s) => write_string(out, s),
+        None => out.push_str("null"),
+    }
+}
+
+/// Writes a JSON string literal.
+fn write_string(out: &mut String, s: &str) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            c if c.is_control() => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{export_json, write_string};
+    use crate::numeral_systems::NamedNumeralSystem;
+
+    #[test]
+    fn test_export_json() {
+        let json = export_json();
+        assert!(json.starts_with("[\n  {\"name\": \"arabic\", \"shorthand\": \"1\""));
+        assert!(json.contains(
+            "{\"name\": \"latin\", \"shorthand\": \"a\", \"kind\": \"bijective\", \
+             \"zero\": null, \"samples\": [\"a\", \"b\", \"c\""
+        ));
+        assert_eq!(json.matches("\"name\"").count(), NamedNumeralSystem::iter().count());
+    }
+
+    #[test]
+    fn test_write_string() {
+        let mut out = String::new();
+        write_string(&mut out, "a\"b\\c\nd\u{1}é");
+        assert_eq!(out, r#""a\"b\\c\nd\u0001é""#);
+    }
+}
@@ -0,0 +1,209 @@
+//! Correspondence between named numeral systems and the numbering formats of
+//! HTML and OOXML.
+
+use super::NamedNumeralSystem;
+
+/// A translation of a numbering format from one vocabulary to another.
+///
+/// Values of this type are returned by [`NamedNumeralSystem::html_type`],
+/// [`NamedNumeralSystem::ooxml_num_fmt`], and
+/// [`NamedNumeralSystem::from_ooxml_num_fmt`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Translation<T> {
+    /// The translated format represents numbers in the same way.
+    Exact(T),
+    /// There is no equivalent format, and this is the closest one. For
+    /// example, it uses other symbols, or represents some numbers
+    /// differently.
+    Nearest(T),
+}
+
+impl<T> Translation<T> {
+    /// Returns the translated format, whether it is exact or not.
+    pub fn into_inner(self) -> T {
+        match self {
+            Self::Exact(value) | Self::Nearest(value) => value,
+        }
+    }
+
+    /// Whether the translation is exact.
+    pub fn is_exact(&self) -> bool {
+        matches!(self, Self::Exact(_))
+    }
+}
+
+impl NamedNumeralSystem {
+    /// Returns the named numeral system corresponding to the `type` attribute
+    /// of an HTML `<ol>` element, if any.
+    ///
+    /// The valid values are `"1"`, `"a"`, `"A"`, `"i"`, and `"I"`.
+    pub fn from_html_type(ty: &str) -> Option<Self> {
+        match ty {
+            "1" => Some(Self::Arabic),
+            "a" => Some(Self::LowerLatin),
+            "A" => Some(Self::UpperLatin),
+            "i" => Some(Self::LowerRoman),
+            "I" => Some(Self::UpperRoman),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of the `type` attribute of an HTML `<ol>` element
+    /// corresponding to this named numeral system.
+    ///
+    /// Numeral systems without an equivalent are translated to decimal
+    /// numbers.
+    pub const fn html_type(self) -> Translation<&'static str> {
+        match self {
+            Self::Arabic => Translation::Exact("1"),
+            Self::LowerLatin => Translation::Exact("a"),
+            Self::UpperLatin => Translation::Exact("A"),
+            Self::LowerRoman => Translation::Exact("i"),
+            Self::UpperRoman => Translation::Exact("I"),
+            // Unreachable when all optional systems are disabled.
+            #[allow(unreachable_patterns)]
+            _ => Translation::Nearest("1"),
+        }
+    }
+
+    /// Returns the named numeral system corresponding to an OOXML numbering
+    /// format, that is, a value of the `w:val` attribute of a `w:numFmt`
+    /// element, if any.
+    ///
+    /// Formats without an equivalent are translated to the closest named
+    /// numeral system, if there is a reasonable one. For example,
+    /// `"lowerLetter"` repeats letters (`aa`, `bb`, …) instead of using
+    /// bijective numeration, and thus corresponds to
+    /// [lower Latin letters](Self::LowerLatin) only approximately.
+    pub fn from_ooxml_num_fmt(num_fmt: &str) -> Option<Translation<Self>> {
+        if let Some(exact) = Self::iter()
+            .find(|system| system.ooxml_num_fmt() == Translation::Exact(num_fmt))
+        {
+            return Some(Translation::Exact(exact));
+        }
+        let nearest = match num_fmt {
+            "decimalZero"
+            | "decimalHalfWidth"
+            | "decimalFullWidth"
+            | "decimalFullWidth2"
+            | "decimalEnclosedFullstop"
+            | "decimalEnclosedParen" => Self::Arabic,
+            "lowerLetter" => Self::LowerLatin,
+            "upperLetter" => Self::UpperLatin,
+            #[cfg(feature = "rtl")]
+            "hebrew2" => Self::Hebrew,
+            #[cfg(feature = "cjk")]
+            "chineseCounting" => Self::LowerSimplifiedChinese,
+            #[cfg(feature = "cjk")]
+            "taiwaneseCounting" => Self::LowerTraditionalChinese,
+            #[cfg(feature = "cjk")]
+            "iroha" => Self::KatakanaIroha,
+            #[cfg(feature = "symbols")]
+            "chicago" => Self::Symbols,
+            _ => return None,
+        };
+        Some(Translation::Nearest(nearest))
+    }
+
+    /// Returns the OOXML numbering format corresponding to this named numeral
+    /// system, that is, a value of the `w:val` attribute of a `w:numFmt`
+    /// element.
+    ///
+    /// Numeral systems without an equivalent are translated to the closest
+    /// format, which is `"decimal"` if there is no better one.
+    pub const fn ooxml_num_fmt(self) -> Translation<&'static str> {
+        match self {
+            Self::Arabic => Translation::Exact("decimal"),
+            #[cfg(feature = "symbols")]
+            Self::CircledArabic => Translation::Exact("decimalEnclosedCircle"),
+            #[cfg(feature = "symbols")]
+            Self::DoubleCircledArabic => Translation::Nearest("decimalEnclosedCircle"),
+            #[cfg(feature = "cjk")]
+            Self::FullwidthArabic => Translation::Exact("decimalFullWidth"),
+            Self::LowerLatin => Translation::Nearest("lowerLetter"),
+            Self::UpperLatin => Translation::Nearest("upperLetter"),
+            Self::LowerRoman => Translation::Exact("lowerRoman"),
+            Self::UpperRoman => Translation::Exact("upperRoman"),
+            #[cfg(feature = "rtl")]
+            Self::Hebrew => Translation::Exact("hebrew1"),
+            #[cfg(feature = "rtl")]
+            Self::EasternArabic => Translation::Exact("hindiNumbers"),
+            #[cfg(feature = "rtl")]
+            Self::ArabicAbjad => Translation::Exact("arabicAbjad"),
+            #[cfg(feature = "rtl")]
+            Self::Persian => Translation::Nearest("hindiNumbers"),
+            #[cfg(feature = "cjk")]
+            Self::LowerSimplifiedChinese => Translation::Exact("chineseCountingThousand"),
+            #[cfg(feature = "cjk")]
+            Self::UpperSimplifiedChinese => Translation::Exact("chineseLegalSimplified"),
+            #[cfg(feature = "cjk")]
+            Self::LowerTraditionalChinese => {
+                Translation::Exact("taiwaneseCountingThousand")
+            }
+            #[cfg(feature = "cjk")]
+            Self::UpperTraditionalChinese => {
+                Translation::Exact("ideographLegalTraditional")
+            }
+            #[cfg(feature = "cjk")]
+            Self::HiraganaAiueo => Translation::Nearest("aiueoFullWidth"),
+            #[cfg(feature = "cjk")]
+            Self::HiraganaIroha => Translation::Nearest("irohaFullWidth"),
+            #[cfg(feature = "cjk")]
+            Self::KatakanaAiueo => Translation::Exact("aiueoFullWidth"),
+            #[cfg(feature = "cjk")]
+            Self::KatakanaIroha => Translation::Exact("irohaFullWidth"),
+            #[cfg(feature = "cjk")]
+            Self::KatakanaHalfwidth => Translation::Exact("aiueo"),
+            #[cfg(feature = "cjk")]
+            Self::KoreanJamo => Translation::Exact("chosung"),
+            #[cfg(feature = "cjk")]
+            Self::KoreanSyllable => Translation::Exact("ganada"),
+            #[cfg(feature = "symbols")]
+            Self::Symbols => Translation::Nearest("chicago"),
+            #[allow(unreachable_patterns)]
+            _ => Translation::Nearest("decimal"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Translation;
+    use crate::numeral_systems::NamedNumeralSystem;
+
+    #[test]
+    fn test_html_type() {
+        for named in NamedNumeralSystem::iter() {
+            if let Translation::Exact(ty) = named.html_type() {
+                assert_eq!(NamedNumeralSystem::from_html_type(ty), Some(named));
+            }
+        }
+        assert_eq!(NamedNumeralSystem::from_html_type("x"), None);
+        assert_eq!(NamedNumeralSystem::LowerLatin.html_type(), Translation::Exact("a"));
+    }
+
+    #[test]
+    fn test_ooxml_num_fmt() {
+        for named in NamedNumeralSystem::iter() {
+            match named.ooxml_num_fmt() {
+                Translation::Exact(num_fmt) => assert_eq!(
+                    NamedNumeralSystem::from_ooxml_num_fmt(num_fmt),
+                    Some(Translation::Exact(named)),
+                ),
+                Translation::Nearest(num_fmt) => {
+                    assert!(NamedNumeralSystem::from_ooxml_num_fmt(num_fmt).is_some())
+                }
+            }
+        }
+        assert_eq!(
+            NamedNumeralSystem::from_ooxml_num_fmt("lowerLetter"),
+            Some(Translation::Nearest(NamedNumeralSystem::LowerLatin)),
+        );
+        assert_eq!(NamedNumeralSystem::from_ooxml_num_fmt("bullet"), None);
+        #[cfg(feature = "cjk")]
+        assert_eq!(
+            NamedNumeralSystem::from_ooxml_num_fmt("aiueo"),
+            Some(Translation::Exact(NamedNumeralSystem::KatakanaHalfwidth)),
+        );
+    }
+}
@@ -0,0 +1,276 @@
+//! Options to represent numbers with, gathered in a single structure.
+
+use std::fmt::Write;
+
+use super::decoration::is_rtl;
+use super::{Counter, NamedNumeralSystem, NumeralPartKind, NumeralSystem};
+use crate::Error;
+
+/// Options to represent numbers with, as used by
+/// [`NumeralSystem::represent_with`] and [`NamedNumeralSystem::represent_with`].
+///
+/// The [default](Self::DEFAULT) options represent numbers as
+/// [`NumeralSystem::represent`] does, so options can be set one by one
+/// without affecting the others.
+///
+/// ## Example
+///
+/// ```
+/// use codex::numeral_systems::{NamedNumeralSystem, NumeralOptions};
+///
+/// let mut options = NumeralOptions::DEFAULT;
+/// options.grouping = Some((3, ","));
+/// options.pad = (10, "\u{2007}");
+/// let arabic = NamedNumeralSystem::Arabic.system();
+/// assert_eq!(arabic.represent_with(1234567, &options).unwrap(), "\u{2007}1,234,567");
+///
+/// let mut options = NumeralOptions::DEFAULT;
+/// options.zero = Some("–");
+/// options.overflow = Some(arabic);
+/// let latin = NamedNumeralSystem::LowerLatin.system();
+/// assert_eq!(latin.represent_with(0, &options).unwrap(), "–");
+/// assert_eq!(latin.represent_with(2, &options).unwrap(), "b");
+/// ```
+#[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
+pub struct NumeralOptions<'a> {
+    /// The numeral system in which numbers that cannot be represented are
+    /// represented instead. Defaults to none, in which case they are
+    /// [out of range](Error::OutOfRange).
+    pub overflow: Option<NumeralSystem<'a>>,
+    /// The text written for zero if the numeral system cannot represent it.
+    /// This takes precedence over the overflow numeral system. Defaults to
+    /// none.
+    pub zero: Option<&'a str>,
+    /// The minimum width of representations, in characters, and the text
+    /// written before them for each missing character. Defaults to no
+    /// padding.
+    pub pad: (usize, &'a str),
+    /// The number of digits per group and the separator between groups,
+    /// counting from the least significant digit. This only applies to
+    /// positional numeral systems. Defaults to no grouping.
+    pub grouping: Option<(usize, &'a str)>,
+    /// Whether representations in right-to-left scripts are wrapped in a
+    /// first strong isolate (U+2068) and a pop directional isolate (U+2069),
+    /// so that bidirectional text does not reorder them with the surrounding
+    /// text. Defaults to `false`.
+    pub isolate: bool,
+    /// The maximum number of times a symbol is repeated, as in
+    /// [`NumeralSystem::represent_with_max_repetitions`]. Defaults to
+    /// [`NumeralSystem::MAX_REPETITIONS`].
+    pub max_repetitions: u64,
+    /// Whether numbers that cannot be represented are
+    /// [out of range](Error::OutOfRange) rather than represented in the
+    /// overflow numeral system, so that the style of a numbering never
+    /// changes silently. With [`NamedNumeralSystem::represent_with`], numbers
+    /// above the [conventional maximum](NamedNumeralSystem::strict_max) are
+    /// also out of range. The zero text still applies. Defaults to `false`.
+    pub strict: bool,
+}
+
+impl NumeralOptions<'_> {
+    /// The default options.
+    pub const DEFAULT: Self = Self {
+        overflow: None,
+        zero: None,
+        pad: (0, ""),
+        grouping: None,
+        isolate: false,
+        max_repetitions: NumeralSystem::MAX_REPETITIONS,
+        strict: false,
+    };
+}
+
+impl Default for NumeralOptions<'_> {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl NumeralSystem<'_> {
+    /// Tries to represent a number in this numeral system, with options.
+    ///
+    /// With the [default](NumeralOptions::DEFAULT) options, this is the same
+    /// as [`represent`](Self::represent), but allocates a string.
+    ///
+    /// The options apply in this order: the number is represented in this
+    /// numeral system, or else as the zero text or in the overflow numeral
+    /// system, then its digits are grouped if it was represented in a
+    /// positional numeral system, it is padded, and it is isolated.
+    pub fn represent_with(
+        self,
+        number: u64,
+        options: &NumeralOptions,
+    ) -> Result<String, Error> {
+        let mut out = String::new();
+        let system =
+            match self.check_with_max_repetitions(number, options.max_repetitions) {
+                Ok(()) => Some(self),
+                Err(_) if number == 0 && options.zero.is_some() => None,
+                Err(err) => match options.overflow {
+                    Some(overflow) if !options.strict => Some(overflow),
+                    _ => return Err(err.into()),
+                },
+            };
+        match (system, options.grouping) {
+            (None, _) => out.push_str(options.zero.unwrap_or_default()),
+            (Some(system @ NumeralSystem::Positional(_)), Some((size, separator)))
+                if size > 0 =>
+            {
+                let parts = system.represent_parts(number)?;
+                let digits = parts
+                    .iter()
+                    .filter(|part| part.kind == NumeralPartKind::Digit)
+                    .count();
+                let mut written = 0;
+                for part in parts {
+                    if part.kind == NumeralPartKind::Digit {
+                        if written > 0 && (digits - written).is_multiple_of(size) {
+                            out.push_str(separator);
+                        }
+                        written += 1;
+                    }
+                    out.push_str(&part.text);
+                }
+            }
+            (Some(system), _) => write!(
+                out,
+                "{}",
+                system.represent_with_max_repetitions(number, options.max_repetitions)?
+            )?,
+        }
+
+        let (width, padding) = options.pad;
+        let mut counter = Counter(0);
+        counter.write_str(&out)?;
+        if counter.0 < width {
+            out.insert_str(0, &padding.repeat(width - counter.0));
+        }
+        if options.isolate && is_rtl(&out) {
+            out.insert(0, '\u{2068}');
+            out.push('\u{2069}');
+        }
+        Ok(out)
+    }
+}
+
+impl NamedNumeralSystem {
+    /// Tries to represent a number in this named numeral system, with
+    /// options.
+    ///
+    /// This is the same as [`NumeralSystem::represent_with`], except that
+    /// with the [`strict`](NumeralOptions::strict) option, numbers above the
+    /// [conventional maximum](Self::strict_max) are
+    /// [out of range](Error::OutOfRange), as with
+    /// [`represent_strict`](Self::represent_strict).
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use codex::Error;
+    /// use codex::numeral_systems::{NamedNumeralSystem, NumeralOptions};
+    ///
+    /// let mut options = NumeralOptions::DEFAULT;
+    /// options.overflow = Some(NamedNumeralSystem::Arabic.system());
+    /// options.strict = true;
+    /// let roman = NamedNumeralSystem::UpperRoman;
+    /// assert_eq!(roman.represent_with(12, &options).unwrap(), "XII");
+    /// assert_eq!(roman.represent_with(4_000_000, &options), Err(Error::OutOfRange));
+    /// let latin = NamedNumeralSystem::LowerLatin;
+    /// assert_eq!(latin.represent_with(0, &options), Err(Error::OutOfRange));
+    /// ```
+    pub fn represent_with(
+        self,
+        number: u64,
+        options: &NumeralOptions,
+    ) -> Result<String, Error> {
+        if options.strict && self.strict_max().is_some_and(|max| number > max) {
+            return Err(Error::OutOfRange);
+        }
+        self.system().represent_with(number, options)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::NumeralOptions;
+    use crate::Error;
+    use crate::numeral_systems::{NamedNumeralSystem, NumeralSystem};
+
+    #[test]
+    fn test_represent_with() {
+        let defaults = NumeralOptions::default();
+        for named in NamedNumeralSystem::iter() {
+            let system = named.system();
+            for n in [0, 1, 10, 1234, 1_000_000] {
+                assert_eq!(
+                    system.represent_with(n, &defaults),
+                    system.represent(n).map(|r| r.to_string()).map_err(Error::from),
+                );
+            }
+        }
+
+        let mut options = NumeralOptions::DEFAULT;
+        options.grouping = Some((2, " "));
+        let arabic = NamedNumeralSystem::Arabic.system();
+        let roman = NamedNumeralSystem::UpperRoman.system();
+        assert_eq!(arabic.represent_with(12345, &options).unwrap(), "1 23 45");
+        assert_eq!(arabic.represent_with(12, &options).unwrap(), "12");
+        assert_eq!(roman.represent_with(1234, &options).unwrap(), "MCCXXXIV");
+
+        let mut options = NumeralOptions::DEFAULT;
+        options.max_repetitions = 3;
+        assert_eq!(
+            NumeralSystem::Symbolic(&["*"]).represent_with(4, &options),
+            Err(Error::OutOfRange),
+        );
+        options.overflow = Some(arabic);
+        options.pad = (3, "0");
+        assert_eq!(
+            NumeralSystem::Symbolic(&["*"]).represent_with(4, &options).unwrap(),
+            "004"
+        );
+        assert_eq!(
+            NumeralSystem::Symbolic(&["*"]).represent_with(2, &options).unwrap(),
+            "0**"
+        );
+
+        // The output of the overflow numeral system is grouped too.
+        options.grouping = Some((3, ","));
+        assert_eq!(
+            NumeralSystem::Symbolic(&["*"])
+                .represent_with(12345, &options)
+                .unwrap(),
+            "12,345"
+        );
+
+        #[cfg(feature = "rtl")]
+        {
+            let mut options = NumeralOptions::DEFAULT;
+            options.isolate = true;
+            let hebrew = NamedNumeralSystem::Hebrew.system();
+            assert_eq!(hebrew.represent_with(2, &options).unwrap(), "\u{2068}ב\u{2069}");
+            assert_eq!(arabic.represent_with(2, &options).unwrap(), "2");
+        }
+    }
+
+    #[test]
+    fn test_strict() {
+        let mut options = NumeralOptions::DEFAULT;
+        options.overflow = Some(NamedNumeralSystem::Arabic.system());
+        options.strict = true;
+        let latin = NamedNumeralSystem::LowerLatin;
+        assert_eq!(latin.system().represent_with(2, &options).unwrap(), "b");
+        assert_eq!(latin.system().represent_with(0, &options), Err(Error::OutOfRange));
+        assert_eq!(latin.represent_with(0, &options), Err(Error::OutOfRange));
+        options.zero = Some("–");
+        assert_eq!(latin.represent_with(0, &options).unwrap(), "–");
+
+        let roman = NamedNumeralSystem::UpperRoman;
+        assert!(roman.represent_with(3_999_999, &options).is_ok());
+        assert_eq!(roman.represent_with(4_000_000, &options), Err(Error::OutOfRange));
+        assert_eq!(roman.system().represent_with(4_000_000, &options).unwrap(), "M̅M̅M̅M̅");
+        options.strict = false;
+        assert_eq!(roman.represent_with(4_000_000, &options).unwrap(), "M̅M̅M̅M̅");
+    }
+}
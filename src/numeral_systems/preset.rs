@@ -0,0 +1,560 @@
+//! Numbering styles bundling numeral systems, surrounding text, and a
+//! fallback, which can be looked up by name and stored as text.
+
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+use super::{NamedNumeralSystem, NumeralSystem, RepresentationError, find};
+
+/// The built-in presets, in the text format of presets.
+const BUILT_IN: &[&str] = &[
+    "appendix: Appendix {Latin}",
+    "footnote: {symbol} | arabic",
+    "frontmatter: {roman}",
+    "legal: {arabic}.{arabic}",
+    "outline (last): {Roman}.{Latin}.{arabic}.{latin}.{(arabic)}.{(latin)}.{roman}",
+];
+
+/// A numbering style: the numeral systems of each level, the text around
+/// them, and how to represent numbers they cannot represent.
+///
+/// A preset is described by a pattern, in which each `{name}` placeholder
+/// stands for a level, numbered with the named or
+/// [registered](super::register) numeral system of that name, as returned by
+/// [`find`]. The text before the first placeholder is written before the
+/// numbers, the text after the last one after them, and the text between the
+/// first two between the numbers of consecutive levels, or a dot if there is
+/// a single placeholder. Deeper levels than
+/// there are placeholders use the numeral system of the last one. For example,
+/// `Appendix {Latin}` numbers appendices as "Appendix A", and
+/// `{arabic}.{arabic}` numbers sections as "1.2.3".
+///
+/// Within a placeholder, the text before the first alphanumeric character of
+/// the name and after its last one is written around the number of that
+/// level only, such as the parentheses of `{(arabic)}`, unless the whole
+/// placeholder is the name of a numeral system.
+///
+/// Presets are written as text as `name: pattern`, followed by ` | fallback`
+/// if numbers that cannot be represented are represented with the numeral
+/// system named `fallback` instead. The name is followed by ` (last)` if only
+/// the number of the innermost level is represented, as set by
+/// [`with_full`](Self::with_full). This is the format of [`Display`] and
+/// [`FromStr`], so that applications can store their house styles.
+///
+/// ## Example
+///
+/// ```
+/// use codex::numeral_systems::Preset;
+///
+/// let appendix = Preset::from_name("appendix").unwrap();
+/// assert_eq!(appendix.represent(&[2]).unwrap(), "Appendix B");
+///
+/// let legal: Preset = "legal: § {arabic}.{latin}".parse().unwrap();
+/// assert_eq!(legal.represent(&[3, 2, 1]).unwrap(), "§ 3.b.a");
+/// assert_eq!(legal.to_string(), "legal: § {arabic}.{latin}");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Preset {
+    name: String,
+    pattern: String,
+    prefix: String,
+    separator: String,
+    suffix: String,
+    /// Invariant: There is at least one level.
+    levels: Vec<Level>,
+    fallback: Option<(String, NumeralSystem<'static>)>,
+    full: bool,
+}
+
+impl Preset {
+    /// Creates a preset from its name, its pattern, and the name of the
+    /// numeral system to fall back to, if any.
+    pub fn new(
+        name: &str,
+        pattern: &str,
+        fallback: Option<&str>,
+    ) -> Result<Self, PresetError> {
+        if name.is_empty() || name.contains([':', '|', '(', ')']) {
+            return Err(PresetError::InvalidName(name.into()));
+        }
+        if pattern.contains('|') {
+            return Err(PresetError::InvalidPattern(pattern.into()));
+        }
+        let mut texts = vec![];
+        let mut levels = vec![];
+        let mut rest = pattern;
+        while let Some((text, placeholder)) = rest.split_once('{') {
+            let (name, after) = placeholder
+                .split_once('}')
+                .ok_or_else(|| PresetError::InvalidPattern(pattern.into()))?;
+            if text.contains('}') || name.contains('{') {
+                return Err(PresetError::InvalidPattern(pattern.into()));
+            }
+            texts.push(text);
+            levels.push(Level::new(name)?);
+            rest = after;
+        }
+        if levels.is_empty() || rest.contains('}') {
+            return Err(PresetError::InvalidPattern(pattern.into()));
+        }
+        let fallback = match fallback {
+            Some(name) => Some((name.into(), system(name)?)),
+            None => None,
+        };
+        Ok(Self {
+            name: name.into(),
+            pattern: pattern.into(),
+            prefix: texts[0].into(),
+            separator: texts.get(1).copied().unwrap_or(".").into(),
+            suffix: rest.into(),
+            levels,
+            fallback,
+            full: true,
+        })
+    }
+
+    /// Returns the built-in preset with the given name, if any.
+    ///
+    /// The built-in presets are:
+    /// - `appendix`: `Appendix {Latin}`, such as "Appendix A".
+    /// - `footnote`: `{symbol}`, falling back to `arabic`, such as "†".
+    /// - `frontmatter`: `{roman}`, such as "iv".
+    /// - `legal`: `{arabic}.{arabic}`, such as "1.2.3".
+    /// - `outline`: the formal outline style, in which each level is numbered
+    ///   on its own, as in "I", "A", "1", "a", "(1)", "(a)", and "i" from the
+    ///   outermost level.
+    ///
+    /// Only the built-in presets are looked up. Applications that define
+    /// their own presets, such as `outline (last): {Roman}.{arabic}.{latin}`,
+    /// look them up by name before falling back to this function.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::built_in().find(|preset| preset.name == name)
+    }
+
+    /// Returns an iterator over the built-in presets, in lexicographic order
+    /// of their names.
+    ///
+    /// Presets whose numeral systems are disabled by cargo features are
+    /// skipped.
+    pub fn built_in() -> impl Iterator<Item = Self> {
+        BUILT_IN.iter().filter_map(|preset| preset.parse().ok())
+    }
+
+    /// Returns the name of this preset.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the pattern of this preset.
+    pub fn pattern(&self) -> &str {
+        &self.pattern
+    }
+
+    /// Returns the name of the numeral system this preset falls back to, if
+    /// any.
+    pub fn fallback(&self) -> Option<&str> {
+        self.fallback.as_ref().map(|(name, _)| name.as_str())
+    }
+
+    /// Returns whether this preset represents the numbers of all the levels,
+    /// as in "1.2.3", rather than only that of the innermost one, as in "3".
+    ///
+    /// This is the case by default.
+    pub fn full(&self) -> bool {
+        self.full
+    }
+
+    /// Sets whether this preset represents the numbers of all the levels, as
+    /// in "1.2.3", rather than only that of the innermost one, as in "3".
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use codex::numeral_systems::Preset;
+    ///
+    /// let legal = Preset::from_name("legal").unwrap();
+    /// assert_eq!(legal.represent(&[1, 2, 3]).unwrap(), "1.2.3");
+    /// let last = legal.with_full(false);
+    /// assert_eq!(last.represent(&[1, 2, 3]).unwrap(), "3");
+    /// assert_eq!(last.to_string(), "legal (last): {arabic}.{arabic}");
+    /// ```
+    pub fn with_full(mut self, full: bool) -> Self {
+        self.full = full;
+        self
+    }
+
+    /// Tries to represent the numbers of each level with this preset, from
+    /// the outermost to the innermost level.
+    ///
+    /// Unless the preset is [full](Self::full), only the number of the
+    /// innermost level is represented, with the numeral system of its level.
+    ///
+    /// If a number cannot be represented by the numeral system of its level,
+    /// it is represented with the fallback numeral system. If there is none,
+    /// or it cannot represent the number either, its error is returned.
+    pub fn represent(&self, numbers: &[u64]) -> Result<String, RepresentationError> {
+        self.represent_impl(numbers, false)
+    }
+
+    /// Tries to represent the numbers of each level with this preset, without
+    /// changing style.
+    ///
+    /// This is the same as [`represent`](Self::represent), except that
+    /// numbers are never represented with the fallback numeral system, nor
+    /// above the [conventional maximum](NamedNumeralSystem::strict_max) of the
+    /// numeral system of their level. A numbering that silently changes style
+    /// in the middle of a list is a mistake for publishers, so such numbers
+    /// are reported as [too large](RepresentationError::TooLarge) instead.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use codex::numeral_systems::{Preset, RepresentationError};
+    ///
+    /// let preset: Preset = "chapter: {Roman} | arabic".parse().unwrap();
+    /// assert_eq!(preset.represent(&[4_000_000]).unwrap(), "M̅M̅M̅M̅");
+    /// assert_eq!(preset.represent_strict(&[4]).unwrap(), "IV");
+    /// assert_eq!(
+    ///     preset.represent_strict(&[4_000_000]),
+    ///     Err(RepresentationError::TooLarge),
+    /// );
+    /// ```
+    pub fn represent_strict(
+        &self,
+        numbers: &[u64],
+    ) -> Result<String, RepresentationError> {
+        self.represent_impl(numbers, true)
+    }
+
+    /// Tries to represent the numbers of each level with this preset, strictly
+    /// or not.
+    fn represent_impl(
+        &self,
+        numbers: &[u64],
+        strict: bool,
+    ) -> Result<String, RepresentationError> {
+        let mut out = self.prefix.clone();
+        let skipped = if self.full { 0 } else { numbers.len().saturating_sub(1) };
+        for (i, &number) in numbers.iter().enumerate().skip(skipped) {
+            if i > skipped {
+                out.push_str(&self.separator);
+            }
+            let level = &self.levels[i.min(self.levels.len() - 1)];
+            if strict && level.max.is_some_and(|max| number > max) {
+                return Err(RepresentationError::TooLarge);
+            }
+            let fallback = self.fallback.as_ref().filter(|_| !strict);
+            let represented = match (level.system.represent(number), fallback) {
+                (Ok(represented), _) => represented.to_string(),
+                (Err(_), Some((_, fallback))) => fallback.represent(number)?.to_string(),
+                (Err(err), None) => return Err(err),
+            };
+            out.push_str(&level.prefix);
+            out.push_str(&represented);
+            out.push_str(&level.suffix);
+        }
+        out.push_str(&self.suffix);
+        Ok(out)
+    }
+
+    /// Tries to represent the numbers of each level with this preset, along
+    /// with a key to refer to them, such as `sec-2.3.1`.
+    ///
+    /// The key consists of the kind of the numbered element, a hyphen, and
+    /// the numbers of the levels in Arabic numerals, separated by dots. It
+    /// thus only depends on the numbers, and not on how they are represented,
+    /// so that cross-references and anchors are the same across output
+    /// formats and numbering styles. If the kind is empty, the key is only
+    /// the numbers.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use codex::numeral_systems::Preset;
+    ///
+    /// let appendix = Preset::from_name("appendix").unwrap();
+    /// let reference = appendix.reference("app", &[2, 1]).unwrap();
+    /// assert_eq!(reference.display, "Appendix B.A");
+    /// assert_eq!(reference.key, "app-2.1");
+    /// ```
+    pub fn reference(
+        &self,
+        kind: &str,
+        numbers: &[u64],
+    ) -> Result<Reference, RepresentationError> {
+        let display = self.represent(numbers)?;
+        let mut key = String::from(kind);
+        for (i, number) in numbers.iter().enumerate() {
+            key.push_str(match (i, kind.is_empty()) {
+                (0, true) => "",
+                (0, false) => "-",
+                _ => ".",
+            });
+            key.push_str(&number.to_string());
+        }
+        Ok(Reference { display, key })
+    }
+}
+
+/// Returns the named or registered numeral system with the given name.
+fn system(name: &str) -> Result<NumeralSystem<'static>, PresetError> {
+    find(name).ok_or_else(|| PresetError::UnknownSystem(name.into()))
+}
+
+/// A level of a preset.
+#[derive(Debug, Clone)]
+struct Level {
+    system: NumeralSystem<'static>,
+    /// The [conventional maximum](NamedNumeralSystem::strict_max) of the
+    /// numeral system.
+    max: Option<u64>,
+    /// The text before the number of this level.
+    prefix: String,
+    /// The text after the number of this level.
+    suffix: String,
+}
+
+impl Level {
+    /// Creates a level from the contents of its placeholder.
+    fn new(placeholder: &str) -> Result<Self, PresetError> {
+        let (prefix, name, suffix) = if find(placeholder).is_some() {
+            ("", placeholder, "")
+        } else {
+            let name = placeholder.trim_matches(|c: char| !c.is_alphanumeric());
+            let start = placeholder.len()
+                - placeholder.trim_start_matches(|c: char| !c.is_alphanumeric()).len();
+            let end = start + name.len();
+            (&placeholder[..start], name, &placeholder[end..])
+        };
+        Ok(Self {
+            system: system(name)?,
+            max: NamedNumeralSystem::from_name(name)
+                .and_then(NamedNumeralSystem::strict_max),
+            prefix: prefix.into(),
+            suffix: suffix.into(),
+        })
+    }
+}
+
+impl Display for Preset {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)?;
+        if !self.full {
+            f.write_str(" (last)")?;
+        }
+        write!(f, ": {}", self.pattern)?;
+        if let Some(fallback) = self.fallback() {
+            write!(f, " | {fallback}")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Preset {
+    type Err = PresetError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, rest) =
+            s.split_once(": ").ok_or_else(|| PresetError::InvalidName(s.into()))?;
+        let (name, full) = match name.strip_suffix(" (last)") {
+            Some(name) => (name, false),
+            None => (name, true),
+        };
+        let preset = match rest.split_once(" | ") {
+            Some((pattern, fallback)) => Self::new(name, pattern, Some(fallback)),
+            None => Self::new(name, rest, None),
+        };
+        Ok(preset?.with_full(full))
+    }
+}
+
+/// The representation of numbers with a preset, and a stable key to refer to
+/// them, as returned by [`Preset::reference`].
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub struct Reference {
+    /// The representation of the numbers, such as "Appendix B.A".
+    pub display: String,
+    /// The key to refer to the numbers, such as `app-2.1`.
+    pub key: String,
+}
+
+/// A reason why a preset cannot be created.
+///
+/// Values of this type are returned by [`Preset::new`] and by parsing a
+/// [`Preset`].
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub enum PresetError {
+    /// The name is empty or contains `:`, `|`, or parentheses.
+    InvalidName(String),
+    /// The pattern has no placeholder, an unbalanced brace, or a `|`.
+    InvalidPattern(String),
+    /// No named or registered numeral system has this name.
+    UnknownSystem(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BUILT_IN, Preset, PresetError, Reference};
+    use crate::numeral_systems::RepresentationError;
+
+    #[test]
+    fn test_built_in() {
+        assert!(BUILT_IN.is_sorted());
+        #[cfg(feature = "symbols")]
+        assert_eq!(Preset::built_in().count(), BUILT_IN.len());
+        for preset in Preset::built_in() {
+            assert_eq!(
+                preset.to_string().parse::<Preset>().unwrap().to_string(),
+                preset.to_string()
+            );
+            assert_eq!(
+                Preset::from_name(preset.name()).unwrap().pattern(),
+                preset.pattern()
+            );
+        }
+        let represent =
+            |name, numbers: &[u64]| Preset::from_name(name).unwrap().represent(numbers);
+        assert_eq!(represent("frontmatter", &[4]).as_deref(), Ok("iv"));
+        assert_eq!(represent("legal", &[1, 2, 3]).as_deref(), Ok("1.2.3"));
+        let outline: Vec<_> = (1..=8)
+            .map(|depth| represent("outline", &vec![1; depth]).unwrap())
+            .collect();
+        assert_eq!(outline, ["I", "A", "1", "a", "(1)", "(a)", "i", "i"]);
+        assert!(!Preset::from_name("outline").unwrap().full());
+        #[cfg(feature = "symbols")]
+        {
+            assert_eq!(represent("footnote", &[2]).as_deref(), Ok("†"));
+            assert_eq!(represent("footnote", &[1_000_000]).as_deref(), Ok("1000000"));
+        }
+        assert_eq!(represent("appendix", &[0]), Err(RepresentationError::Zero));
+        assert!(Preset::from_name("unknown").is_none());
+    }
+
+    #[test]
+    fn test_parse() {
+        let preset: Preset = "chapter: Chapter {Roman}: ".parse().unwrap();
+        assert_eq!(preset.name(), "chapter");
+        assert_eq!(preset.fallback(), None);
+        assert_eq!(preset.represent(&[4, 1]).as_deref(), Ok("Chapter IV.I: "));
+        assert_eq!(preset.represent(&[]).as_deref(), Ok("Chapter : "));
+        assert_eq!(
+            "a: {arabic}".parse::<Preset>().unwrap().represent(&[10]).as_deref(),
+            Ok("10"),
+        );
+        assert_eq!(
+            "x: {unknown}".parse::<Preset>().unwrap_err(),
+            PresetError::UnknownSystem("unknown".into()),
+        );
+        assert_eq!(
+            "x: {arabic} | unknown".parse::<Preset>().unwrap_err(),
+            PresetError::UnknownSystem("unknown".into()),
+        );
+        assert!(matches!(
+            "x: text".parse::<Preset>(),
+            Err(PresetError::InvalidPattern(_))
+        ));
+        assert!(matches!(
+            "x: {arabic".parse::<Preset>(),
+            Err(PresetError::InvalidPattern(_))
+        ));
+        assert!(matches!(
+            "x: }{arabic}".parse::<Preset>(),
+            Err(PresetError::InvalidPattern(_))
+        ));
+        assert!(matches!("{arabic}".parse::<Preset>(), Err(PresetError::InvalidName(_))));
+    }
+
+    #[test]
+    fn test_reference() {
+        let legal = Preset::from_name("legal").unwrap();
+        assert_eq!(
+            legal.reference("sec", &[2, 3, 1]),
+            Ok(Reference { display: "2.3.1".into(), key: "sec-2.3.1".into() }),
+        );
+        assert_eq!(legal.reference("", &[2, 3]).unwrap().key, "2.3");
+        assert_eq!(legal.reference("sec", &[]).unwrap().key, "sec");
+        let appendix = Preset::from_name("appendix").unwrap();
+        assert_eq!(appendix.reference("app", &[0]), Err(RepresentationError::Zero));
+    }
+
+    #[test]
+    fn test_strict() {
+        let chapter: Preset = "chapter: {roman}.{arabic} | arabic".parse().unwrap();
+        assert_eq!(chapter.represent_strict(&[4, 2]).as_deref(), Ok("iv.2"));
+        assert_eq!(chapter.represent(&[4_000_000]).as_deref(), Ok("m̅m̅m̅m̅"));
+        assert_eq!(
+            chapter.represent_strict(&[4_000_000]),
+            Err(RepresentationError::TooLarge)
+        );
+        assert_eq!(chapter.represent_strict(&[1, 4_000_000]).as_deref(), Ok("i.4000000"));
+
+        // The fallback is suppressed.
+        let appendix: Preset = "appendix: {Latin} | arabic".parse().unwrap();
+        assert_eq!(appendix.represent(&[0]).as_deref(), Ok("0"));
+        assert_eq!(appendix.represent_strict(&[0]), Err(RepresentationError::Zero));
+        #[cfg(feature = "symbols")]
+        {
+            let footnote = Preset::from_name("footnote").unwrap();
+            assert_eq!(footnote.represent_strict(&[2]).as_deref(), Ok("†"));
+            assert_eq!(
+                footnote.represent_strict(&[1_000_000]),
+                Err(RepresentationError::TooLarge)
+            );
+        }
+    }
+
+    #[test]
+    fn test_levels() {
+        let outline: Preset = "outline: {Roman}.{Latin}.{arabic}".parse().unwrap();
+        assert!(outline.full());
+        assert_eq!(outline.represent(&[1]).as_deref(), Ok("I"));
+        assert_eq!(outline.represent(&[1, 2]).as_deref(), Ok("I.B"));
+        assert_eq!(outline.represent(&[1, 2, 3, 4, 5, 6]).as_deref(), Ok("I.B.3.4.5.6"));
+
+        let last: Preset = "outline (last): ({Roman}.{Latin}.{arabic})".parse().unwrap();
+        assert!(!last.full());
+        assert_eq!(last.name(), "outline");
+        assert_eq!(last.represent(&[1]).as_deref(), Ok("(I)"));
+        assert_eq!(last.represent(&[1, 2]).as_deref(), Ok("(B)"));
+        assert_eq!(last.represent(&[1, 2, 3, 4, 5, 6]).as_deref(), Ok("(6)"));
+        assert_eq!(last.represent(&[]).as_deref(), Ok("()"));
+        assert_eq!(
+            last.to_string().parse::<Preset>().unwrap().to_string(),
+            last.to_string()
+        );
+        assert_eq!(
+            Preset::new("a (b)", "{arabic}", None).unwrap_err(),
+            PresetError::InvalidName("a (b)".into()),
+        );
+    }
+
+    #[test]
+    fn test_affixes() {
+        let outline = Preset::from_name("outline").unwrap().with_full(true);
+        assert_eq!(
+            outline.represent(&[1, 2, 3, 4, 5, 6]).as_deref(),
+            Ok("I.B.3.d.(5).(f)")
+        );
+        let preset: Preset = "x: §{arabic}.{[latin]}".parse().unwrap();
+        assert_eq!(preset.represent(&[2, 3, 4]).as_deref(), Ok("§2.[c].[d]"));
+        assert_eq!(preset.to_string(), "x: §{arabic}.{[latin]}");
+        #[cfg(feature = "symbols")]
+        {
+            let preset: Preset = "x: {‹symbol.de›}".parse().unwrap();
+            assert_eq!(preset.represent(&[1]).as_deref(), Ok("‹*›"));
+        }
+        assert_eq!(
+            "x: {(unknown)}".parse::<Preset>().unwrap_err(),
+            PresetError::UnknownSystem("unknown".into()),
+        );
+        assert_eq!(
+            "x: {()}".parse::<Preset>().unwrap_err(),
+            PresetError::UnknownSystem("".into()),
+        );
+        assert!(matches!(
+            "x: {{arabic}".parse::<Preset>(),
+            Err(PresetError::InvalidPattern(_))
+        ));
+    }
+}
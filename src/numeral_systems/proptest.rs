@@ -0,0 +1,108 @@
+//! Strategies for property testing with [`proptest`](mod@proptest).
+//!
+//! These strategies generate named numeral systems, numbers that can be
+//! represented in a numeral system, and valid numerals, so that crates
+//! depending on this one can test their numbering logic against all the
+//! numeral systems.
+//!
+//! ## Example
+//!
+//! ```
+//! use codex::numeral_systems::proptest::{numeral_system, representable};
+//! use proptest::prelude::*;
+//!
+//! proptest!(|((system, n) in numeral_system().prop_flat_map(|system| {
+//!     (Just(system), representable(system))
+//! }))| {
+//!     let numeral = system.represent(n).unwrap().to_string();
+//!     prop_assert_eq!(system.parse(&numeral), Ok(n));
+//! });
+//! ```
+
+use ::proptest::prelude::*;
+use ::proptest::sample::select;
+
+use super::{NamedNumeralSystem, NumeralSystem};
+
+/// Generates named numeral systems.
+///
+/// This is the strategy of [`any::<NamedNumeralSystem>()`](any).
+pub fn named_numeral_system() -> impl Strategy<Value = NamedNumeralSystem> {
+    select(NamedNumeralSystem::iter().collect::<Vec<_>>())
+}
+
+/// Generates the underlying numeral systems of named numeral systems.
+pub fn numeral_system() -> impl Strategy<Value = NumeralSystem<'static>> {
+    named_numeral_system().prop_map(NamedNumeralSystem::system)
+}
+
+/// Generates numbers that can be represented in a numeral system.
+///
+/// Small numbers are generated more often than large ones. If the system
+/// cannot represent any number, generation fails.
+pub fn representable(system: NumeralSystem<'static>) -> impl Strategy<Value = u64> {
+    let max = upper_bound(system);
+    prop_oneof![0..=max.min(100), 0..=max]
+        .prop_filter("number cannot be represented", move |&n| {
+            system.represent(n).is_ok()
+        })
+}
+
+/// Generates valid numerals of a numeral system, that is, representations of
+/// numbers in it.
+pub fn numeral(system: NumeralSystem<'static>) -> impl Strategy<Value = String> {
+    representable(system).prop_map(move |n| system.represent(n).unwrap().to_string())
+}
+
+impl Arbitrary for NamedNumeralSystem {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with((): ()) -> Self::Strategy {
+        named_numeral_system().boxed()
+    }
+}
+
+/// Returns a number such that most numbers up to it can be represented in a
+/// numeral system, so that few generated numbers are rejected.
+fn upper_bound(system: NumeralSystem) -> u64 {
+    let repetitions = NumeralSystem::MAX_REPETITIONS;
+    let len = |symbols: &[&str]| symbols.len() as u64;
+    match system {
+        NumeralSystem::Positional(digits) if digits.len() < 2 => {
+            len(digits).saturating_sub(1)
+        }
+        NumeralSystem::Bijective(digits) if digits.len() < 2 => repetitions,
+        NumeralSystem::Symbolic(symbols) => len(symbols).saturating_mul(repetitions),
+        NumeralSystem::Additive(numerals) => numerals
+            .first()
+            .map_or(0, |&(_, weight)| weight.saturating_mul(repetitions)),
+        NumeralSystem::Fixed(symbols) => len(symbols).saturating_sub(1),
+        NumeralSystem::ZerolessFixed(symbols) => len(symbols),
+        _ => u64::MAX,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::proptest::prelude::*;
+
+    use super::{named_numeral_system, numeral};
+    use crate::numeral_systems::NamedNumeralSystem;
+
+    proptest! {
+        #[test]
+        fn test_numerals_parse(
+            (named, s) in named_numeral_system()
+                .prop_flat_map(|named| (Just(named), numeral(named.system())))
+        ) {
+            let n = named.system().parse(&s).unwrap();
+            prop_assert_eq!(named.system().represent(n).unwrap().to_string(), s);
+        }
+
+        #[test]
+        fn test_arbitrary(named in any::<NamedNumeralSystem>()) {
+            prop_assert_eq!(NamedNumeralSystem::from_name(named.name()), Some(named));
+        }
+    }
+}
@@ -0,0 +1,831 @@
+//! Importing spell-out systems from ICU
+//! [rule-based number format](https://unicode-org.github.io/icu/userguide/format_parse/numbers/rbnf.html)
+//! (RBNF) rule sets.
+//!
+//! CLDR provides RBNF rule sets for many languages, such as
+//! `%spellout-cardinal` or `%spellout-ordinal`. Rather than describing each
+//! language in Rust, their rules can be [parsed](RuleSets::parse) and used to
+//! represent numbers.
+//!
+//! Only the rules for non-negative integers are supported: negative number,
+//! fraction, infinity, and NaN rules are ignored. Plural substitutions
+//! (`$(…)$`) are not supported, and decimal format substitutions (such as
+//! `=#,##0=`) always use Western Arabic digits, with commas as group
+//! separators if the pattern contains a comma.
+//!
+//! ## Example
+//!
+//! ```
+//! use codex::numeral_systems::rbnf::RuleSets;
+//!
+//! let rule_sets = RuleSets::parse(
+//!     "%spellout:
+//!         0: zero; one; two; three; four; five; six; seven; eight; nine;
+//!         ten; eleven; twelve; thirteen; fourteen; fifteen; sixteen;
+//!         seventeen; eighteen; nineteen;
+//!         20: twenty[->>];
+//!         30: thirty[->>];
+//!         100: << hundred[ >>];",
+//! )
+//! .unwrap();
+//! let spellout = rule_sets.get("spellout").unwrap();
+//! assert_eq!(spellout.represent(123).unwrap(), "one hundred twenty-three");
+//! ```
+
+use super::{NumberForm, RepresentationError};
+
+/// The maximum number of nested substitutions when representing a number.
+///
+/// Rules that refer to each other without decreasing the number would
+/// otherwise recurse indefinitely.
+const MAX_DEPTH: u32 = 64;
+
+/// A collection of RBNF rule sets, parsed from their textual description.
+#[derive(Debug, Clone)]
+pub struct RuleSets {
+    sets: Vec<RuleSetData>,
+}
+
+/// A rule set, with its rules for non-negative integers.
+#[derive(Debug, Clone)]
+struct RuleSetData {
+    /// The name of the rule set, without the leading `%` or `%%`.
+    name: String,
+    /// Whether the name of the rule set starts with `%%`, in which case it can
+    /// only be used by other rule sets.
+    private: bool,
+    /// Invariant: The rules are sorted by strictly increasing base value.
+    rules: Vec<Rule>,
+}
+
+/// A rule for non-negative integers.
+#[derive(Debug, Clone)]
+struct Rule {
+    /// The smallest number this rule applies to.
+    base: u64,
+    /// The number by which numbers are divided for `<<` and `>>`
+    /// substitutions.
+    divisor: u64,
+    /// Whether the rule contains a `>>` or `>>>` substitution.
+    has_remainder: bool,
+    body: Vec<Part>,
+}
+
+/// A part of the body of a rule.
+#[derive(Debug, Clone)]
+enum Part {
+    Text(String),
+    /// Parts that are omitted if the number is a multiple of the divisor of
+    /// the rule.
+    Optional(Vec<Part>),
+    Substitution(Operand, Target),
+}
+
+/// The number that is substituted.
+#[derive(Debug, Copy, Clone)]
+enum Operand {
+    /// The number divided by the divisor of the rule, as in `<<`.
+    Quotient,
+    /// The remainder of the division by the divisor of the rule, as in `>>`.
+    Remainder,
+    /// The number itself, as in `==`.
+    Whole,
+}
+
+/// How the substituted number is represented.
+#[derive(Debug, Copy, Clone)]
+enum Target {
+    /// With the rule set of the rule.
+    Current,
+    /// With the rule preceding the rule, as in `>>>`.
+    Preceding,
+    /// With the rule set at this index.
+    Set(usize),
+    /// With Western Arabic digits.
+    Decimal { grouped: bool },
+}
+
+impl RuleSets {
+    /// Parses RBNF rule sets, in ICU's textual syntax.
+    ///
+    /// Each rule set starts with its name, such as `%spellout-cardinal:`.
+    /// Rule sets whose name starts with `%%` are private: they can be used by
+    /// the other rule sets, but cannot be [retrieved](Self::get).
+    pub fn parse(text: &str) -> Result<Self, RbnfError> {
+        // Rule sets are first split into rules, so that the names of all rule
+        // sets are known when parsing substitutions.
+        let mut raw: Vec<(&str, bool, Vec<&str>)> = vec![];
+        for rule in text.split(';') {
+            let rule = rule.trim_start();
+            if let Some(header) = rule.strip_prefix('%')
+                && let Some((name, rest)) = header.split_once(':')
+                && !name.contains(char::is_whitespace)
+            {
+                let private = name.starts_with('%');
+                let rest = rest.trim_start();
+                let rules = if rest.is_empty() { vec![] } else { vec![rest] };
+                raw.push((name.trim_start_matches('%'), private, rules));
+            } else if let Some((_, _, rules)) = raw.last_mut() {
+                if !rule.is_empty() {
+                    rules.push(rule);
+                }
+            } else if !rule.is_empty() {
+                return Err(RbnfError::MissingName);
+            }
+        }
+
+        let names: Vec<&str> = raw.iter().map(|&(name, _, _)| name).collect();
+        let mut sets = Vec::with_capacity(raw.len());
+        for &(name, private, ref texts) in &raw {
+            let mut rules: Vec<Rule> = vec![];
+            for &text in texts {
+                let next = rules.last().map_or(Some(0), |rule| rule.base.checked_add(1));
+                let Some(rule) = parse_rule(text, next, &names)? else { continue };
+                if rules.last().is_some_and(|last| last.base >= rule.base) {
+                    return Err(RbnfError::InvalidRule(text.into()));
+                }
+                if rules.is_empty()
+                    && substitutions(&rule.body)
+                        .any(|(_, target)| matches!(target, Target::Preceding))
+                {
+                    return Err(RbnfError::InvalidRule(text.into()));
+                }
+                rules.push(rule);
+            }
+            sets.push(RuleSetData { name: name.into(), private, rules });
+        }
+        Ok(Self { sets })
+    }
+
+    /// Returns the public rule set with the given name, if any.
+    ///
+    /// The name may be given with or without its leading `%`.
+    pub fn get(&self, name: &str) -> Option<RuleSet<'_>> {
+        let name = name.strip_prefix('%').unwrap_or(name);
+        self.iter().find(|set| set.name() == name)
+    }
+
+    /// Returns the public rule set that spells numbers out in the given form,
+    /// if any.
+    ///
+    /// This is the `%spellout-cardinal`, `%spellout-ordinal`, or
+    /// `%spellout-numbering-year` rule set. For
+    /// languages where these depend on grammatical gender, such as French,
+    /// this is the first rule set whose name starts with one of these
+    /// followed by a hyphen, such as `%spellout-ordinal-masculine`.
+    pub fn spellout(&self, form: NumberForm) -> Option<RuleSet<'_>> {
+        let name = match form {
+            NumberForm::Cardinal => "spellout-cardinal",
+            NumberForm::Ordinal => "spellout-ordinal",
+            NumberForm::Year => "spellout-numbering-year",
+        };
+        self.get(name).or_else(|| {
+            self.iter().find(|set| {
+                set.name()
+                    .strip_prefix(name)
+                    .is_some_and(|rest| rest.starts_with('-'))
+            })
+        })
+    }
+
+    /// Returns the public rule set that spells numbers out with the given
+    /// grammatical agreement, if any.
+    ///
+    /// Rule sets are looked up by their CLDR names, such as
+    /// `%spellout-ordinal-feminine` or
+    /// `%spellout-cardinal-masculine-genitive`. The gender is ignored if no
+    /// rule set of the form has a gender, and the case if none has a case,
+    /// as for languages that do not distinguish them. If neither is left,
+    /// this is the same as [`spellout`](Self::spellout). Otherwise, `None` is
+    /// returned if there is no rule set for the requested gender and case,
+    /// rather than spelling numbers out in another one.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use codex::numeral_systems::NumberForm;
+    /// use codex::numeral_systems::rbnf::{Gender, RuleSets, SpelloutOptions};
+    ///
+    /// let rule_sets = RuleSets::parse(
+    ///     "%spellout-cardinal-masculine: 0: ноль; один; два;
+    ///      %spellout-cardinal-feminine: 0: ноль; одна; две;",
+    /// )
+    /// .unwrap();
+    /// let mut options = SpelloutOptions::new(NumberForm::Cardinal);
+    /// options.gender = Some(Gender::Feminine);
+    /// let spellout = rule_sets.spellout_with(options).unwrap();
+    /// assert_eq!(spellout.represent(1).unwrap(), "одна");
+    ///
+    /// options.gender = Some(Gender::Neuter);
+    /// assert!(rule_sets.spellout_with(options).is_none());
+    /// ```
+    pub fn spellout_with(&self, options: SpelloutOptions) -> Option<RuleSet<'_>> {
+        let form = match options.form {
+            NumberForm::Cardinal => "spellout-cardinal",
+            NumberForm::Ordinal => "spellout-ordinal",
+            NumberForm::Year => "spellout-numbering-year",
+        };
+        let gender = options.gender.map(|gender| match gender {
+            Gender::Masculine => "masculine",
+            Gender::Feminine => "feminine",
+            Gender::Neuter => "neuter",
+        });
+        let case = match options.case {
+            Case::Nominative => None,
+            Case::Genitive => Some("genitive"),
+            Case::Dative => Some("dative"),
+            Case::Accusative => Some("accusative"),
+            Case::Instrumental => Some("instrumental"),
+            Case::Locative => Some("locative"),
+        };
+        // Whether some rule set of the form is suffixed with one of the words.
+        let distinguishes = |words: &[&str]| {
+            self.iter().any(|set| {
+                set.name()
+                    .strip_prefix(form)
+                    .is_some_and(|rest| rest.split('-').any(|word| words.contains(&word)))
+            })
+        };
+        let gender =
+            gender.filter(|_| distinguishes(&["masculine", "feminine", "neuter"]));
+        let case = case.filter(|_| {
+            distinguishes(&[
+                "genitive",
+                "dative",
+                "accusative",
+                "instrumental",
+                "locative",
+            ])
+        });
+        match (gender, case) {
+            (None, None) => self.spellout(options.form),
+            (Some(gender), None) => self.get(&format!("{form}-{gender}")),
+            (None, Some(case)) => self.get(&format!("{form}-{case}")),
+            (Some(gender), Some(case)) => self.get(&format!("{form}-{gender}-{case}")),
+        }
+    }
+
+    /// Returns an iterator over the public rule sets, in the order in which
+    /// they are defined.
+    pub fn iter(&self) -> impl Iterator<Item = RuleSet<'_>> {
+        (0..self.sets.len())
+            .filter(|&index| !self.sets[index].private)
+            .map(|index| RuleSet { sets: self, index })
+    }
+
+    /// Writes a number with the rule set at the given index.
+    fn write(
+        &self,
+        set: usize,
+        n: u64,
+        depth: u32,
+        out: &mut String,
+    ) -> Result<(), RepresentationError> {
+        let rules = &self.sets[set].rules;
+        let Some(mut index) = rules.partition_point(|rule| rule.base <= n).checked_sub(1)
+        else {
+            return Err(if n == 0 {
+                RepresentationError::Zero
+            } else {
+                RepresentationError::TooLarge
+            });
+        };
+        // A rule whose base value is not a multiple of its divisor does not
+        // apply to multiples of its divisor if it has a remainder
+        // substitution, as the remainder would be zero.
+        let rule = &rules[index];
+        if index > 0
+            && rule.has_remainder
+            && n.is_multiple_of(rule.divisor)
+            && !rule.base.is_multiple_of(rule.divisor)
+        {
+            index -= 1;
+        }
+        self.write_rule(set, index, n, depth, out)
+    }
+
+    /// Writes a number with a specific rule of the rule set at the given
+    /// index.
+    fn write_rule(
+        &self,
+        set: usize,
+        index: usize,
+        n: u64,
+        depth: u32,
+        out: &mut String,
+    ) -> Result<(), RepresentationError> {
+        if depth >= MAX_DEPTH {
+            return Err(RepresentationError::TooLarge);
+        }
+        let rule = &self.sets[set].rules[index];
+        self.write_parts(set, index, &rule.body, n, depth, out)
+    }
+
+    /// Writes the parts of the body of a rule.
+    fn write_parts(
+        &self,
+        set: usize,
+        index: usize,
+        parts: &[Part],
+        n: u64,
+        depth: u32,
+        out: &mut String,
+    ) -> Result<(), RepresentationError> {
+        let divisor = self.sets[set].rules[index].divisor;
+        for part in parts {
+            match part {
+                Part::Text(text) => out.push_str(text),
+                Part::Optional(parts) => {
+                    if !n.is_multiple_of(divisor) {
+                        self.write_parts(set, index, parts, n, depth, out)?;
+                    }
+                }
+                &Part::Substitution(operand, target) => {
+                    let n = match operand {
+                        Operand::Quotient => n / divisor,
+                        Operand::Remainder => n % divisor,
+                        Operand::Whole => n,
+                    };
+                    match target {
+                        Target::Current => self.write(set, n, depth + 1, out)?,
+                        Target::Preceding => {
+                            self.write_rule(set, index - 1, n, depth + 1, out)?
+                        }
+                        Target::Set(other) => self.write(other, n, depth + 1, out)?,
+                        Target::Decimal { grouped } => write_decimal(n, grouped, out),
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A public rule set of some [`RuleSets`].
+#[derive(Debug, Copy, Clone)]
+pub struct RuleSet<'a> {
+    sets: &'a RuleSets,
+    index: usize,
+}
+
+impl<'a> RuleSet<'a> {
+    /// Returns the name of this rule set, without the leading `%`.
+    pub fn name(self) -> &'a str {
+        &self.sets.sets[self.index].name
+    }
+
+    /// Tries to represent a number with this rule set.
+    ///
+    /// If no rule applies to the number, [`RepresentationError::Zero`] is
+    /// returned for zero, and [`RepresentationError::TooLarge`] otherwise. The
+    /// latter is also returned if the rules nest too many substitutions, which
+    /// notably happens when they refer to each other without decreasing the
+    /// number.
+    pub fn represent(self, number: u64) -> Result<String, RepresentationError> {
+        let mut out = String::new();
+        self.sets.write(self.index, number, 0, &mut out)?;
+        Ok(out)
+    }
+}
+
+/// The grammatical agreement of spelled out numbers, as used by
+/// [`RuleSets::spellout_with`].
+///
+/// Headings such as "Erste Auflage" require numbers to agree with the noun
+/// they qualify.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default)]
+#[non_exhaustive]
+pub struct SpelloutOptions {
+    /// Whether the number is cardinal or ordinal.
+    pub form: NumberForm,
+    /// The grammatical gender, if any.
+    pub gender: Option<Gender>,
+    /// The grammatical case.
+    pub case: Case,
+}
+
+impl SpelloutOptions {
+    /// Creates options for the given form, without gender, in the nominative
+    /// case.
+    pub const fn new(form: NumberForm) -> Self {
+        Self { form, gender: None, case: Case::Nominative }
+    }
+}
+
+/// A grammatical gender.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Gender {
+    /// The masculine gender, such as in Russian "один".
+    Masculine,
+    /// The feminine gender, such as in Russian "одна".
+    Feminine,
+    /// The neuter gender, such as in Russian "одно".
+    Neuter,
+}
+
+/// A grammatical case.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default)]
+pub enum Case {
+    /// The nominative case, which is that of the rule sets whose name does
+    /// not mention a case.
+    #[default]
+    Nominative,
+    /// The genitive case.
+    Genitive,
+    /// The dative case.
+    Dative,
+    /// The accusative case.
+    Accusative,
+    /// The instrumental case.
+    Instrumental,
+    /// The locative case.
+    Locative,
+}
+
+/// A reason why RBNF rule sets cannot be parsed.
+///
+/// Values of this type are returned by [`RuleSets::parse`].
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub enum RbnfError {
+    /// Rules appear before the name of the first rule set.
+    MissingName,
+    /// The rule is malformed, or its base value is not larger than that of
+    /// the previous rule.
+    InvalidRule(String),
+    /// The rule refers to a rule set that does not exist.
+    UnknownRuleSet(String),
+    /// The rule uses a feature that is not supported, such as plural
+    /// substitutions.
+    Unsupported(String),
+}
+
+/// Parses a rule, given the base value implied by the previous rule, if any.
+///
+/// Returns `Ok(None)` for rules that do not apply to non-negative integers.
+fn parse_rule(
+    text: &str,
+    implied_base: Option<u64>,
+    names: &[&str],
+) -> Result<Option<Rule>, RbnfError> {
+    let invalid = || RbnfError::InvalidRule(text.into());
+    let (base, radix, tweaks, body) = match text.split_once(':') {
+        Some((descriptor, body)) => {
+            let descriptor = descriptor.trim();
+            if ["-x", "x.x", "0.x", "x.0", "x,x", "0,x", "x,0", "Inf", "NaN"]
+                .contains(&descriptor)
+            {
+                return Ok(None);
+            }
+            let (base, radix, tweaks) =
+                parse_descriptor(descriptor).ok_or_else(invalid)?;
+            (base, radix, tweaks, body.trim_start())
+        }
+        None => (implied_base.ok_or_else(invalid)?, 10, 0, text),
+    };
+    let body = body.strip_prefix('\'').unwrap_or(body);
+
+    // The divisor is the largest power of the radix that is at most the base
+    // value, lowered by one power for each `>` in the descriptor.
+    let mut exponent = base.checked_ilog(radix).unwrap_or(0);
+    exponent = exponent.checked_sub(tweaks).ok_or_else(invalid)?;
+    let divisor = radix.checked_pow(exponent).ok_or_else(invalid)?;
+
+    let body = parse_parts(body, text, names)?;
+    let has_remainder =
+        substitutions(&body).any(|(operand, _)| matches!(operand, Operand::Remainder));
+    Ok(Some(Rule { base, divisor, has_remainder, body }))
+}
+
+/// Parses the descriptor of a rule for non-negative integers, such as `100`,
+/// `100/20`, or `100>`, into its base value, radix, and number of `>`.
+fn parse_descriptor(descriptor: &str) -> Option<(u64, u64, u32)> {
+    let without_tweaks = descriptor.trim_end_matches('>');
+    let tweaks = (descriptor.len() - without_tweaks.len()) as u32;
+    let (base, radix) = match without_tweaks.split_once('/') {
+        Some((base, radix)) => (base, radix.parse().ok().filter(|&radix| radix >= 2)?),
+        None => (without_tweaks, 10),
+    };
+    let base = base.replace(',', "");
+    if base.is_empty() || !base.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    Some((base.parse().ok()?, radix, tweaks))
+}
+
+/// Parses the body of a rule, or a bracketed part of it.
+fn parse_parts(body: &str, rule: &str, names: &[&str]) -> Result<Vec<Part>, RbnfError> {
+    let invalid = || RbnfError::InvalidRule(rule.into());
+    let unsupported = || RbnfError::Unsupported(rule.into());
+    let mut parts = vec![];
+    let mut rest = body;
+    while !rest.is_empty() {
+        let part;
+        if let Some(after) = rest.strip_prefix('[') {
+            let (inner, after) = after.split_once(']').ok_or_else(invalid)?;
+            if inner.contains('[') {
+                return Err(invalid());
+            }
+            part = Part::Optional(parse_parts(inner, rule, names)?);
+            rest = after;
+        } else if rest.starts_with(']') {
+            return Err(invalid());
+        } else if rest.starts_with("$(") || rest.starts_with("<<<") {
+            return Err(unsupported());
+        } else if let Some(after) = rest.strip_prefix(">>>") {
+            part = Part::Substitution(Operand::Remainder, Target::Preceding);
+            rest = after;
+        } else if let Some(token @ ('<' | '>' | '=')) = rest.chars().next() {
+            let (inner, after) = rest[1..].split_once(token).ok_or_else(invalid)?;
+            let target = if inner.is_empty() {
+                Target::Current
+            } else if let Some(name) = inner.strip_prefix('%') {
+                let name = name.trim_start_matches('%');
+                let index = names.iter().position(|&n| n == name);
+                Target::Set(index.ok_or_else(|| RbnfError::UnknownRuleSet(name.into()))?)
+            } else if inner.bytes().all(|b| matches!(b, b'#' | b',' | b'0')) {
+                Target::Decimal { grouped: inner.contains(',') }
+            } else {
+                return Err(unsupported());
+            };
+            let operand = match token {
+                '<' => Operand::Quotient,
+                '>' => Operand::Remainder,
+                _ => Operand::Whole,
+            };
+            if matches!((operand, target), (Operand::Whole, Target::Current)) {
+                return Err(invalid());
+            }
+            part = Part::Substitution(operand, target);
+            rest = after;
+        } else {
+            let end = rest
+                .find(['[', ']', '<', '>', '=', '$'])
+                .map(|end| if end == 0 { 1 } else { end })
+                .unwrap_or(rest.len());
+            let (text, after) = rest.split_at(end);
+            if let Some(Part::Text(previous)) = parts.last_mut() {
+                previous.push_str(text);
+            } else {
+                parts.push(Part::Text(text.into()));
+            }
+            rest = after;
+            continue;
+        }
+        parts.push(part);
+    }
+    Ok(parts)
+}
+
+/// Returns an iterator over the substitutions of the body of a rule,
+/// including those in brackets.
+fn substitutions(body: &[Part]) -> impl Iterator<Item = (Operand, Target)> + '_ {
+    body.iter()
+        .flat_map(|part| match part {
+            Part::Optional(parts) => parts.as_slice(),
+            part => std::slice::from_ref(part),
+        })
+        .filter_map(|part| match *part {
+            Part::Substitution(operand, target) => Some((operand, target)),
+            _ => None,
+        })
+}
+
+/// Writes a number with Western Arabic digits, optionally grouping them by
+/// three with commas.
+fn write_decimal(n: u64, grouped: bool, out: &mut String) {
+    let digits = n.to_string();
+    for (i, digit) in digits.chars().enumerate() {
+        if grouped && i > 0 && (digits.len() - i).is_multiple_of(3) {
+            out.push(',');
+        }
+        out.push(digit);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Case, Gender, RbnfError, RuleSets, SpelloutOptions};
+    use crate::numeral_systems::{NumberForm, RepresentationError};
+
+    /// An excerpt of the English rule sets from CLDR.
+    const ENGLISH: &str = "
+        %spellout-numbering:
+            -x: minus >>;
+            x.x: =#,##0.#=;
+            0: =%spellout-cardinal=;
+        %spellout-cardinal:
+            -x: minus >>;
+            x.x: << point >>;
+            Inf: infinity;
+            NaN: not a number;
+            0: zero;
+            1: one;
+            2: two;
+            3: three;
+            4: four;
+            5: five;
+            6: six;
+            7: seven;
+            8: eight;
+            9: nine;
+            10: ten;
+            11: eleven;
+            12: twelve;
+            13: thirteen;
+            14: fourteen;
+            15: fifteen;
+            16: sixteen;
+            17: seventeen;
+            18: eighteen;
+            19: nineteen;
+            20: twenty[->>];
+            30: thirty[->>];
+            40: forty[->>];
+            50: fifty[->>];
+            60: sixty[->>];
+            70: seventy[->>];
+            80: eighty[->>];
+            90: ninety[->>];
+            100: << hundred[ >>];
+            1000: << thousand[ >>];
+            1000000: << million[ >>];
+            1000000000000000000: =#,##0=;
+        %%th:
+            0: th;
+            1: ' =%spellout-ordinal=;
+        %spellout-ordinal:
+            -x: minus >>;
+            x.x: =#,##0.#=;
+            0: zeroth;
+            1: first;
+            2: second;
+            3: third;
+            4: fourth;
+            5: fifth;
+            6: sixth;
+            7: seventh;
+            8: eighth;
+            9: ninth;
+            10: tenth;
+            11: eleventh;
+            12: twelfth;
+            13: =%spellout-numbering=th;
+            20: twentieth;
+            21: twenty->>;
+            30: thirtieth;
+            31: thirty->>;
+            100: <%spellout-numbering< hundred>%%th>;
+            1000: <%spellout-numbering< thousand>%%th>;
+    ";
+
+    #[test]
+    fn test_english() {
+        let sets = RuleSets::parse(ENGLISH).unwrap();
+        let names: Vec<_> = sets.iter().map(|set| set.name()).collect();
+        assert_eq!(
+            names,
+            ["spellout-numbering", "spellout-cardinal", "spellout-ordinal"]
+        );
+        assert!(sets.get("th").is_none());
+
+        let cardinal = sets.get("%spellout-cardinal").unwrap();
+        let represent = |n| cardinal.represent(n).unwrap();
+        assert_eq!(represent(0), "zero");
+        assert_eq!(represent(7), "seven");
+        assert_eq!(represent(20), "twenty");
+        assert_eq!(represent(42), "forty-two");
+        assert_eq!(represent(100), "one hundred");
+        assert_eq!(represent(999), "nine hundred ninety-nine");
+        assert_eq!(represent(2_000_017), "two million seventeen");
+        assert_eq!(represent(u64::MAX), "18,446,744,073,709,551,615");
+
+        let ordinal = sets.get("spellout-ordinal").unwrap();
+        let represent = |n| ordinal.represent(n).unwrap();
+        assert_eq!(represent(3), "third");
+        assert_eq!(represent(14), "fourteenth");
+        assert_eq!(represent(22), "twenty-second");
+        assert_eq!(represent(100), "one hundredth");
+        assert_eq!(represent(103), "one hundred third");
+        assert_eq!(represent(2_000), "two thousandth");
+    }
+
+    #[test]
+    fn test_spellout() {
+        let sets = RuleSets::parse(ENGLISH).unwrap();
+        let spellout = |form| sets.spellout(form).unwrap().name();
+        assert_eq!(spellout(NumberForm::Cardinal), "spellout-cardinal");
+        assert_eq!(spellout(NumberForm::Ordinal), "spellout-ordinal");
+
+        let sets = RuleSets::parse(
+            "%spellout-cardinal-masculine: 0: zéro; un; deux;
+             %spellout-cardinal-feminine: 0: zéro; une; deux;
+             %spellout-ordinals: 1: premier;",
+        )
+        .unwrap();
+        let cardinal = sets.spellout(NumberForm::Cardinal).unwrap();
+        assert_eq!(cardinal.name(), "spellout-cardinal-masculine");
+        assert_eq!(cardinal.represent(1).unwrap(), "un");
+        assert!(sets.spellout(NumberForm::Ordinal).is_none());
+    }
+
+    #[test]
+    fn test_spellout_with() {
+        let sets = RuleSets::parse(
+            "%spellout-cardinal-masculine: 0: ноль; один; два;
+             %spellout-cardinal-feminine: 0: ноль; одна; две;
+             %spellout-cardinal-masculine-genitive: 0: ноля; одного; двух;
+             %spellout-ordinal: 1: erste;
+             %spellout-ordinal-r: 1: erster;",
+        )
+        .unwrap();
+        let spellout = |form, gender, case| {
+            let mut options = SpelloutOptions::new(form);
+            options.gender = gender;
+            options.case = case;
+            sets.spellout_with(options)
+        };
+        let represent = |form, gender, case| {
+            spellout(form, gender, case).unwrap().represent(1).unwrap()
+        };
+        let cardinal = NumberForm::Cardinal;
+        assert_eq!(represent(cardinal, None, Case::Nominative), "один");
+        assert_eq!(represent(cardinal, Some(Gender::Feminine), Case::Nominative), "одна");
+        assert_eq!(
+            represent(cardinal, Some(Gender::Masculine), Case::Genitive),
+            "одного"
+        );
+        // Forms that do not exist are not replaced with other ones.
+        assert!(spellout(cardinal, Some(Gender::Feminine), Case::Genitive).is_none());
+        assert!(spellout(cardinal, Some(Gender::Neuter), Case::Nominative).is_none());
+        assert!(spellout(cardinal, Some(Gender::Neuter), Case::Dative).is_none());
+        assert!(spellout(cardinal, None, Case::Genitive).is_none());
+        // Genders and cases are ignored where no rule set distinguishes them.
+        let ordinal = NumberForm::Ordinal;
+        assert_eq!(represent(ordinal, Some(Gender::Feminine), Case::Nominative), "erste");
+        assert_eq!(represent(ordinal, Some(Gender::Masculine), Case::Dative), "erste");
+        assert_eq!(SpelloutOptions::default(), SpelloutOptions::new(cardinal));
+    }
+
+    #[test]
+    fn test_rule_selection() {
+        // With the rollback rule, multiples of the divisor use the previous
+        // rule.
+        let sets = RuleSets::parse("%x: 0: =#,##0=; 15: <<x>>;").unwrap();
+        let x = sets.get("x").unwrap();
+        assert_eq!(x.represent(14).unwrap(), "14");
+        assert_eq!(x.represent(20).unwrap(), "20");
+        assert_eq!(x.represent(21).unwrap(), "2x1");
+
+        // Radices and `>` change the divisor.
+        let sets = RuleSets::parse("%x: 0: =0=; 20/20: <<*>>; %y: 0: =0=; 100>: <<*>>;")
+            .unwrap();
+        assert_eq!(sets.get("x").unwrap().represent(45).unwrap(), "2*5");
+        assert_eq!(sets.get("y").unwrap().represent(456).unwrap(), "45*6");
+
+        // `>>>` uses the previous rule.
+        let sets = RuleSets::parse("%x: 0: =0=; 10: (>>>); 20: [>>>];").unwrap();
+        assert_eq!(sets.get("x").unwrap().represent(25).unwrap(), "(5)");
+
+        let sets =
+            RuleSets::parse("%x: 1: one; %y: 3: three; %z: 0: =%z2=; %%z2: 0: =%z=;")
+                .unwrap();
+        assert_eq!(sets.get("x").unwrap().represent(0), Err(RepresentationError::Zero));
+        assert_eq!(
+            sets.get("y").unwrap().represent(2),
+            Err(RepresentationError::TooLarge)
+        );
+        assert_eq!(
+            sets.get("z").unwrap().represent(1),
+            Err(RepresentationError::TooLarge)
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(RuleSets::parse("0: zero;").unwrap_err(), RbnfError::MissingName);
+        assert_eq!(
+            RuleSets::parse("%x: 0: =%y=;").unwrap_err(),
+            RbnfError::UnknownRuleSet("y".into()),
+        );
+        assert_eq!(
+            RuleSets::parse("%x: 0: zero; 0: nil;").unwrap_err(),
+            RbnfError::InvalidRule("0: nil".into()),
+        );
+        assert_eq!(
+            RuleSets::parse("%x: 0: [zero;").unwrap_err(),
+            RbnfError::InvalidRule("0: [zero".into()),
+        );
+        assert_eq!(
+            RuleSets::parse("%x: 0: ==;").unwrap_err(),
+            RbnfError::InvalidRule("0: ==".into()),
+        );
+        assert_eq!(
+            RuleSets::parse("%x: 0: >>>;").unwrap_err(),
+            RbnfError::InvalidRule("0: >>>".into()),
+        );
+        assert_eq!(
+            RuleSets::parse("%x: 1: $(cardinal,one{day}other{days})$;").unwrap_err(),
+            RbnfError::Unsupported("1: $(cardinal,one{day}other{days})$".into()),
+        );
+    }
+}
@@ -0,0 +1,209 @@
+//! A global registry of numeral systems defined at runtime.
+//!
+//! Registered systems live for the rest of the program. Neither lookups nor
+//! registrations take a lock: the registry is an append-only list of leaked
+//! nodes, so formatting from many threads at once never contends.
+
+use std::iter::FusedIterator;
+use std::sync::OnceLock;
+
+use super::{NameError, NamedNumeralSystem, NumeralSystem};
+use crate::Error;
+
+/// A registered numeral system.
+#[derive(Debug)]
+struct Node {
+    name: &'static str,
+    system: NumeralSystem<'static>,
+    next: Link,
+}
+
+/// A link to the next registered numeral system, if there is one.
+type Link = OnceLock<&'static Node>;
+
+/// The first registered numeral system.
+static HEAD: Link = OnceLock::new();
+
+/// Registers a numeral system under a name, for the rest of the program.
+///
+/// Fails if the name belongs to a named numeral system, even one whose cargo
+/// feature is disabled, or if it is already registered.
+///
+/// ## Example
+///
+/// ```
+/// use codex::numeral_systems::{self, NumeralSystem};
+///
+/// numeral_systems::register("binary", NumeralSystem::Positional(&["0", "1"])).unwrap();
+/// let binary = numeral_systems::lookup_registered("binary").unwrap();
+/// assert_eq!(binary.represent(5).unwrap().to_string(), "101");
+/// ```
+pub fn register(
+    name: &'static str,
+    system: NumeralSystem<'static>,
+) -> Result<(), RegistrationError> {
+    if NamedNumeralSystem::lookup(name) != Err(NameError::Unknown) {
+        return Err(RegistrationError::Reserved);
+    }
+    let mut node = None;
+    let mut link = &HEAD;
+    loop {
+        let next = match link.get() {
+            Some(&next) => next,
+            None => {
+                let new = *node.get_or_insert_with(|| -> &'static Node {
+                    Box::leak(Box::new(Node { name, system, next: OnceLock::new() }))
+                });
+                // If another thread appended a node first, it is checked
+                // below, so a name is never registered twice.
+                let next = *link.get_or_init(|| new);
+                if std::ptr::eq(next, new) {
+                    return Ok(());
+                }
+                next
+            }
+        };
+        if next.name == name {
+            return Err(RegistrationError::Taken);
+        }
+        link = &next.next;
+    }
+}
+
+/// Returns the numeral system registered under a name, if any.
+///
+/// This never blocks.
+pub fn lookup_registered(name: &str) -> Option<NumeralSystem<'static>> {
+    registered().find(|&(n, _)| n == name).map(|(_, system)| system)
+}
+
+/// Returns the named or registered numeral system with the given name, if
+/// any.
+///
+/// Named numeral systems take precedence, although registering a numeral
+/// system under the name of a named numeral system is not possible anyway.
+pub fn find(name: &str) -> Option<NumeralSystem<'static>> {
+    NamedNumeralSystem::from_name(name)
+        .map(NamedNumeralSystem::system)
+        .or_else(|| lookup_registered(name))
+}
+
+/// Returns the named or registered numeral system with the given name.
+///
+/// This is the same as [`find`], except that a name whose numeral system
+/// exists but belongs to a disabled cargo feature is reported as such.
+///
+/// ## Example
+///
+/// ```
+/// use codex::Error;
+/// use codex::numeral_systems::resolve;
+///
+/// assert!(resolve("roman").is_ok());
+/// assert!(matches!(resolve("unknown"), Err(Error::UnknownName(name)) if name == "unknown"));
+/// ```
+pub fn resolve(name: &str) -> Result<NumeralSystem<'static>, Error> {
+    match NamedNumeralSystem::lookup(name) {
+        Ok(named) => Ok(named.system()),
+        Err(NameError::Disabled { feature }) => {
+            Err(Error::Unsupported { feature: Some(feature) })
+        }
+        Err(NameError::Unknown) => {
+            lookup_registered(name).ok_or_else(|| Error::UnknownName(name.into()))
+        }
+    }
+}
+
+/// Returns an iterator over the names of the named numeral systems, followed
+/// by those of the registered numeral systems.
+pub fn names() -> impl Iterator<Item = &'static str> {
+    NamedNumeralSystem::iter()
+        .map(NamedNumeralSystem::name)
+        .chain(registered().map(|(name, _)| name))
+}
+
+/// Returns an iterator over the registered numeral systems and their names,
+/// in the order in which they were registered.
+///
+/// This never blocks. Systems registered while iterating may or may not be
+/// yielded.
+pub fn registered() -> Registered {
+    Registered { next: HEAD.get().copied() }
+}
+
+/// An iterator over the registered numeral systems.
+///
+/// This is created by [`registered`].
+#[derive(Debug, Clone)]
+pub struct Registered {
+    next: Option<&'static Node>,
+}
+
+impl Iterator for Registered {
+    type Item = (&'static str, NumeralSystem<'static>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.next?;
+        self.next = node.next.get().copied();
+        Some((node.name, node.system))
+    }
+}
+
+impl FusedIterator for Registered {}
+
+/// A reason why a numeral system cannot be registered.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum RegistrationError {
+    /// The name belongs to a named numeral system.
+    Reserved,
+    /// A numeral system is already registered under this name.
+    Taken,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        RegistrationError, find, lookup_registered, names, register, registered,
+    };
+    use crate::numeral_systems::NumeralSystem;
+
+    #[test]
+    fn test_register() {
+        let ternary = NumeralSystem::Positional(&["0", "1", "2"]);
+        assert_eq!(register("test-ternary", ternary), Ok(()));
+        assert_eq!(register("test-ternary", ternary), Err(RegistrationError::Taken));
+        assert_eq!(register("roman", ternary), Err(RegistrationError::Reserved));
+        let found = lookup_registered("test-ternary").unwrap();
+        assert_eq!(found.represent(5).unwrap().to_string(), "12");
+        assert!(lookup_registered("test-unknown").is_none());
+        assert!(registered().any(|(name, _)| name == "test-ternary"));
+        assert!(find("test-ternary").is_some());
+        assert!(find("roman").is_some());
+        assert!(names().any(|name| name == "test-ternary"));
+    }
+
+    /// Makes sure lookups see systems registered from other threads.
+    #[test]
+    fn test_register_concurrently() {
+        const NAMES: [&str; 8] = [
+            "test-0", "test-1", "test-2", "test-3", "test-4", "test-5", "test-6",
+            "test-7",
+        ];
+        std::thread::scope(|scope| {
+            for name in NAMES {
+                scope.spawn(move || {
+                    register(name, NumeralSystem::Symbolic(&["*"])).unwrap();
+                    assert!(lookup_registered(name).is_some());
+                });
+                scope.spawn(|| {
+                    for _ in 0..100 {
+                        registered().for_each(drop);
+                    }
+                });
+            }
+        });
+        for name in NAMES {
+            assert!(lookup_registered(name).is_some());
+        }
+    }
+}
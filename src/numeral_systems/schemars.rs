@@ -0,0 +1,71 @@
+//! Implementations of [`JsonSchema`] for numeral systems.
+//!
+//! In configuration files, numeral systems are referred to by name, so their
+//! schemas are enumerations of names.
+
+use std::borrow::Cow;
+
+use ::schemars::{JsonSchema, Schema, SchemaGenerator, json_schema};
+
+use super::{NamedNumeralSystem, NumeralSystem};
+
+/// The [names](NamedNumeralSystem::name) of the named numeral systems.
+impl JsonSchema for NamedNumeralSystem {
+    fn schema_name() -> Cow<'static, str> {
+        "NamedNumeralSystem".into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        "codex::numeral_systems::NamedNumeralSystem".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        let names: Vec<_> =
+            NamedNumeralSystem::iter().map(NamedNumeralSystem::name).collect();
+        json_schema!({
+            "type": "string",
+            "enum": names,
+        })
+    }
+}
+
+/// The [names](super::names) of the named numeral systems, followed by those
+/// of the numeral systems registered when the schema is generated.
+impl JsonSchema for NumeralSystem<'_> {
+    fn schema_name() -> Cow<'static, str> {
+        "NumeralSystem".into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        "codex::numeral_systems::NumeralSystem".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        let names: Vec<_> = super::names().collect();
+        json_schema!({
+            "type": "string",
+            "enum": names,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::schemars::schema_for;
+
+    use crate::numeral_systems::{NamedNumeralSystem, NumeralSystem, register};
+
+    #[test]
+    fn test_schemas() {
+        let schema = schema_for!(NamedNumeralSystem);
+        let names = schema.get("enum").unwrap().as_array().unwrap();
+        assert_eq!(names.len(), NamedNumeralSystem::iter().count());
+        assert_eq!(names[0], "arabic");
+
+        register("test-schema", NumeralSystem::Positional(&["0", "1"])).unwrap();
+        let schema = schema_for!(NumeralSystem);
+        let names = schema.get("enum").unwrap().as_array().unwrap();
+        assert!(names.iter().any(|name| name == "roman"));
+        assert!(names.iter().any(|name| name == "test-schema"));
+    }
+}
@@ -0,0 +1,143 @@
+//! Implementations of [`Serialize`] and [`Deserialize`] for numbering state
+//! and styles.
+//!
+//! Counter states are serialized as structures with the numbers of their
+//! levels and of the deeper levels kept by
+//! [`RestartPolicy::Continue`](super::RestartPolicy::Continue), like their
+//! [binary encoding](CounterState::to_bytes), and presets as text, in the
+//! format of their [`Display`](std::fmt::Display) implementation.
+
+use std::fmt::{self, Formatter};
+
+use ::serde::de::{Error, MapAccess, SeqAccess, Unexpected, Visitor};
+use ::serde::ser::SerializeStruct;
+use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::{CounterState, Preset};
+
+/// The fields of serialized counter states.
+const FIELDS: &[&str] = &["levels", "kept"];
+
+/// A structure with the numbers of the levels, from the outermost to the
+/// innermost, as `levels`, and those of the deeper levels kept by
+/// [`RestartPolicy::Continue`](super::RestartPolicy::Continue) as `kept`.
+impl Serialize for CounterState {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("CounterState", FIELDS.len())?;
+        state.serialize_field("levels", self.levels())?;
+        state.serialize_field("kept", self.kept())?;
+        state.end()
+    }
+}
+
+/// The `kept` field may be omitted.
+impl<'de> Deserialize<'de> for CounterState {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_struct("CounterState", FIELDS, CounterStateVisitor)
+    }
+}
+
+/// Deserializes a counter state from a structure, as a map or a sequence.
+struct CounterStateVisitor;
+
+impl<'de> Visitor<'de> for CounterStateVisitor {
+    type Value = CounterState;
+
+    fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("a counter state")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let levels = seq
+            .next_element()?
+            .ok_or_else(|| A::Error::invalid_length(0, &self))?;
+        let kept: Vec<u64> = seq.next_element()?.unwrap_or_default();
+        Ok(CounterState::from_parts(levels, &kept))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut levels = None;
+        let mut kept: Option<Vec<u64>> = None;
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "levels" if levels.is_some() => {
+                    return Err(A::Error::duplicate_field("levels"));
+                }
+                "kept" if kept.is_some() => {
+                    return Err(A::Error::duplicate_field("kept"));
+                }
+                "levels" => levels = Some(map.next_value()?),
+                "kept" => kept = Some(map.next_value()?),
+                _ => return Err(A::Error::unknown_field(&key, FIELDS)),
+            }
+        }
+        let levels = levels.ok_or_else(|| A::Error::missing_field("levels"))?;
+        Ok(CounterState::from_parts(levels, &kept.unwrap_or_default()))
+    }
+}
+
+/// The preset as `name: pattern` or `name: pattern | fallback`.
+impl Serialize for Preset {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Preset {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse()
+            .map_err(|_| D::Error::invalid_value(Unexpected::Str(&s), &"a preset"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::serde::Deserialize;
+    use ::serde::de::value::{Error, MapDeserializer, SeqDeserializer, StrDeserializer};
+
+    use crate::numeral_systems::{CounterState, Preset, RestartPolicy};
+
+    #[test]
+    fn test_deserialize() {
+        let fields = |fields: Vec<(&'static str, Vec<u64>)>| {
+            CounterState::deserialize(MapDeserializer::<_, Error>::new(
+                fields.into_iter(),
+            ))
+        };
+        assert_eq!(
+            fields(vec![("levels", vec![1, 2])]),
+            Ok(CounterState::from_levels(vec![1, 2])),
+        );
+        assert!(fields(vec![("kept", vec![1])]).is_err());
+        assert!(fields(vec![("levels", vec![1]), ("depth", vec![1])]).is_err());
+        assert!(fields(vec![("levels", vec![1]), ("levels", vec![1])]).is_err());
+
+        // The deeper levels kept by `RestartPolicy::Continue` are restored,
+        // so that the state continues numbering them.
+        let mut state = CounterState::new().with_restart(RestartPolicy::Continue);
+        state.step(2);
+        state.step(2);
+        state.step(1);
+        assert_eq!(state.kept(), [2]);
+        let mut restored = fields(vec![("levels", vec![1]), ("kept", vec![2])])
+            .unwrap()
+            .with_restart(RestartPolicy::Continue);
+        assert_eq!(restored, state);
+        let sequence =
+            SeqDeserializer::<_, Error>::new([vec![1u64], vec![2]].into_iter());
+        assert_eq!(
+            CounterState::deserialize(sequence)
+                .unwrap()
+                .with_restart(RestartPolicy::Continue),
+            state,
+        );
+        restored.step(2);
+        assert_eq!(restored.levels(), [1, 3]);
+
+        let text = "legal: {arabic}.{arabic} | roman";
+        let preset = Preset::deserialize(StrDeserializer::<Error>::new(text)).unwrap();
+        assert_eq!(preset.to_string(), text);
+        assert!(Preset::deserialize(StrDeserializer::<Error>::new("legal")).is_err());
+    }
+}
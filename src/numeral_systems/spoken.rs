@@ -0,0 +1,91 @@
+//! Spoken forms of numerals, as accessible alternatives to numeral glyphs.
+
+use super::numeral_value;
+use super::rbnf::RuleSet;
+
+/// Returns the spoken form of a numeral of any named numeral system, as its
+/// value in Arabic numerals, if it has one.
+///
+/// Documents exported to HTML or PDF/UA can attach this form to decorative
+/// numerals, such as `aria-label="14"` for "XIV", so that screen readers
+/// read the number in the language of the reader rather than letter by
+/// letter. The value is read with [`numeral_value`]. To spell the number out
+/// instead, use [`spoken_numeral_with`].
+///
+/// ## Example
+///
+/// ```
+/// use codex::numeral_systems::spoken_numeral;
+///
+/// assert_eq!(spoken_numeral("XIV").as_deref(), Some("14"));
+/// # #[cfg(feature = "symbols")]
+/// assert_eq!(spoken_numeral("④").as_deref(), Some("4"));
+/// assert_eq!(spoken_numeral("?"), None);
+/// ```
+pub fn spoken_numeral(numeral: &str) -> Option<String> {
+    numeral_value(numeral).map(|value| value.to_string())
+}
+
+/// Returns the spoken form of a numeral of any named numeral system, spelled
+/// out with a rule set, if it has a value that the rule set can spell out.
+///
+/// This is the same as [`spoken_numeral`], except that the value is
+/// represented with a spell-out rule set, such as the
+/// [cardinal](super::rbnf::RuleSets::spellout) one of a language.
+///
+/// ## Example
+///
+/// ```
+/// use codex::numeral_systems::rbnf::RuleSets;
+/// use codex::numeral_systems::spoken_numeral_with;
+///
+/// let rule_sets = RuleSets::parse(
+///     "%spellout-cardinal: 0: zero; one; two; three; four;
+///      10: ten; eleven; twelve; thirteen; >>teen;",
+/// )
+/// .unwrap();
+/// let cardinal = rule_sets.get("spellout-cardinal").unwrap();
+/// assert_eq!(spoken_numeral_with("XIV", cardinal).as_deref(), Some("fourteen"));
+/// ```
+pub fn spoken_numeral_with(numeral: &str, rule_set: RuleSet) -> Option<String> {
+    rule_set.represent(numeral_value(numeral)?).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{spoken_numeral, spoken_numeral_with};
+    use crate::numeral_systems::rbnf::RuleSets;
+
+    #[test]
+    fn test_spoken_numeral() {
+        assert_eq!(spoken_numeral("14").as_deref(), Some("14"));
+        assert_eq!(spoken_numeral("XIV").as_deref(), Some("14"));
+        assert_eq!(spoken_numeral("ⅹⅳ").as_deref(), Some("14"));
+        assert_eq!(spoken_numeral("c").as_deref(), Some("100"));
+        assert_eq!(spoken_numeral("b").as_deref(), Some("2"));
+        #[cfg(feature = "symbols")]
+        {
+            assert_eq!(spoken_numeral("④").as_deref(), Some("4"));
+            assert_eq!(spoken_numeral("⓵").as_deref(), Some("1"));
+        }
+        #[cfg(feature = "cjk")]
+        assert_eq!(spoken_numeral("十四").as_deref(), Some("14"));
+        assert_eq!(spoken_numeral(""), None);
+        assert_eq!(spoken_numeral("?"), None);
+    }
+
+    #[test]
+    fn test_spoken_numeral_with() {
+        let sets = RuleSets::parse(
+            "%spellout-cardinal:
+                1: one; two; three; four; five; six; seven; eight; nine;
+                10: ten; eleven; twelve; thirteen; fourteen;",
+        )
+        .unwrap();
+        let cardinal = sets.get("spellout-cardinal").unwrap();
+        assert_eq!(spoken_numeral_with("XIV", cardinal).as_deref(), Some("fourteen"));
+        assert_eq!(spoken_numeral_with("3", cardinal).as_deref(), Some("three"));
+        assert_eq!(spoken_numeral_with("0", cardinal), None);
+        assert_eq!(spoken_numeral_with("?", cardinal), None);
+    }
+}
@@ -0,0 +1,62 @@
+//! Python bindings for numeral systems, through PyO3.
+//!
+//! Numeral systems are referred to by name: either the name of a
+//! [`NamedNumeralSystem`](numeral_systems::NamedNumeralSystem) or that of a
+//! [registered](numeral_systems::register) numeral system.
+//!
+//! To build a Python extension module, enable both this feature and the
+//! `extension-module` feature of PyO3, for example with
+//! `maturin build --features python,pyo3/extension-module`.
+
+use pyo3::exceptions::{PyKeyError, PyValueError};
+use pyo3::prelude::*;
+
+use crate::numeral_systems::{self, NumeralSystem, ParseError, RepresentationError};
+
+/// Human-friendly notation for Unicode symbols.
+#[pymodule]
+fn codex(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(format, m)?)?;
+    m.add_function(wrap_pyfunction!(parse, m)?)?;
+    m.add_function(wrap_pyfunction!(systems, m)?)?;
+    Ok(())
+}
+
+/// Represents a number in the numeral system with the given name.
+#[pyfunction]
+fn format(system: &str, number: u64) -> PyResult<String> {
+    match find(system)?.represent(number) {
+        Ok(r) => Ok(r.to_string()),
+        Err(RepresentationError::Zero) => {
+            Err(PyValueError::new_err(format!("`{system}` cannot represent zero")))
+        }
+        Err(RepresentationError::TooLarge) => {
+            Err(PyValueError::new_err(format!("{number} is too large for `{system}`")))
+        }
+    }
+}
+
+/// Parses a number represented in the numeral system with the given name.
+#[pyfunction]
+fn parse(system: &str, numeral: &str) -> PyResult<u64> {
+    find(system)?.parse(numeral).map_err(|err| match err {
+        ParseError::Invalid => PyValueError::new_err(format!(
+            "`{numeral}` is not a valid `{system}` numeral"
+        )),
+        ParseError::TooLarge => {
+            PyValueError::new_err(format!("`{numeral}` is too large"))
+        }
+    })
+}
+
+/// Returns the names of the available numeral systems.
+#[pyfunction]
+fn systems() -> Vec<&'static str> {
+    numeral_systems::names().collect()
+}
+
+/// Returns the numeral system with the given name.
+fn find(name: &str) -> PyResult<NumeralSystem<'static>> {
+    numeral_systems::find(name)
+        .ok_or_else(|| PyKeyError::new_err(format!("unknown numeral system `{name}`")))
+}
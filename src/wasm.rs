@@ -0,0 +1,50 @@
+//! JavaScript bindings for numeral systems, through `wasm-bindgen`.
+//!
+//! Numeral systems are referred to by name: either the name of a
+//! [`NamedNumeralSystem`](numeral_systems::NamedNumeralSystem) or that of a
+//! [registered](numeral_systems::register) numeral system.
+//!
+//! To build a WebAssembly module exposing them, run
+//! `cargo rustc --release --target wasm32-unknown-unknown --features wasm --crate-type cdylib`,
+//! and then `wasm-bindgen` on the result.
+
+use wasm_bindgen::prelude::*;
+
+use crate::numeral_systems::{self, NumeralSystem, ParseError, RepresentationError};
+
+/// Represents a number in the numeral system with the given name.
+#[wasm_bindgen]
+pub fn format(system: &str, number: u64) -> Result<String, JsError> {
+    match find(system)?.represent(number) {
+        Ok(r) => Ok(r.to_string()),
+        Err(RepresentationError::Zero) => {
+            Err(JsError::new(&format!("`{system}` cannot represent zero")))
+        }
+        Err(RepresentationError::TooLarge) => {
+            Err(JsError::new(&format!("{number} is too large for `{system}`")))
+        }
+    }
+}
+
+/// Parses a number represented in the numeral system with the given name.
+#[wasm_bindgen]
+pub fn parse(system: &str, numeral: &str) -> Result<u64, JsError> {
+    find(system)?.parse(numeral).map_err(|err| match err {
+        ParseError::Invalid => {
+            JsError::new(&format!("`{numeral}` is not a valid `{system}` numeral"))
+        }
+        ParseError::TooLarge => JsError::new(&format!("`{numeral}` is too large")),
+    })
+}
+
+/// Returns the names of the available numeral systems.
+#[wasm_bindgen]
+pub fn systems() -> Vec<String> {
+    numeral_systems::names().map(String::from).collect()
+}
+
+/// Returns the numeral system with the given name.
+fn find(name: &str) -> Result<NumeralSystem<'static>, JsError> {
+    numeral_systems::find(name)
+        .ok_or_else(|| JsError::new(&format!("unknown numeral system `{name}`")))
+}